@@ -61,6 +61,36 @@ contract 9c {
     }
 }
 
+#[test]
+fn parser_error_recovery_missing_semicolon() {
+    let src = r#"contract c {
+    function f() public {
+        uint a = foo()
+        a = 2;
+        a = bar()
+        return;
+    }
+}
+"#;
+
+    // each missing ';' is reported once, and parsing resumes with the next statement
+    let errors = crate::parse(src, 0).unwrap_err();
+
+    assert_eq!(errors.len(), 2);
+
+    assert_eq!(errors[0].loc, File(0, 70, 71));
+    assert!(errors[0]
+        .message
+        .starts_with("unrecognised token 'a', expected"));
+    assert!(errors[0].message.contains(r#"";""#));
+
+    assert_eq!(errors[1].loc, File(0, 103, 109));
+    assert!(errors[1]
+        .message
+        .starts_with("unrecognised token 'return', expected"));
+    assert!(errors[1].message.contains(r#"";""#));
+}
+
 #[test]
 fn parse_test() {
     let src = r#"/// @title Foo
@@ -0,0 +1,361 @@
+// SPDX-License-Identifier: Apache-2.0
+
+//! Borsh encoding and decoding of values, the way Solang lays out function arguments and
+//! return values on Solana. This can be used for writing tests against contracts compiled
+//! with Solang, without having to generate Rust types from the Anchor IDL.
+
+use anchor_syn::idl::types::{IdlType, IdlTypeDefinition, IdlTypeDefinitionTy};
+use num_bigint::{BigInt, Sign};
+use num_traits::{One, ToPrimitive};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A Borsh encoded value. Integers carry their width in bits, e.g. `uint256` is
+/// `BorshToken::Uint { width: 256, value }`.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub enum BorshToken {
+    Address([u8; 32]),
+    Int { width: u16, value: BigInt },
+    Uint { width: u16, value: BigInt },
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    Bool(bool),
+    String(String),
+    FixedArray(Vec<BorshToken>),
+    Array(Vec<BorshToken>),
+    Tuple(Vec<BorshToken>),
+}
+
+/// Error from encoding or decoding a [`BorshToken`]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BorshTokenError {
+    /// The value does not fit into an integer of the given width
+    IntegerOverflow { width: u16, value: BigInt },
+    /// Only integers of up to 256 bits are supported
+    UnsupportedWidth(u16),
+    /// The data ends before the value is complete
+    UnexpectedEnd,
+    /// A string is not valid UTF-8
+    InvalidUtf8,
+    /// The IDL refers to a type which is not in the list of custom types
+    UnknownType(String),
+    /// The IDL type cannot be produced by Solidity, e.g. floating point numbers
+    UnsupportedType,
+}
+
+impl fmt::Display for BorshTokenError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BorshTokenError::IntegerOverflow { width, value } => {
+                write!(f, "value {value} does not fit into {width} bits")
+            }
+            BorshTokenError::UnsupportedWidth(width) => {
+                write!(f, "integer width of {width} bits is not supported")
+            }
+            BorshTokenError::UnexpectedEnd => write!(f, "unexpected end of data"),
+            BorshTokenError::InvalidUtf8 => write!(f, "string is not valid utf-8"),
+            BorshTokenError::UnknownType(name) => write!(f, "type '{name}' not found"),
+            BorshTokenError::UnsupportedType => write!(f, "type not available in Solidity"),
+        }
+    }
+}
+
+impl std::error::Error for BorshTokenError {}
+
+impl BorshToken {
+    /// Encode the token into the buffer
+    pub fn encode(&self, buffer: &mut Vec<u8>) -> Result<(), BorshTokenError> {
+        match self {
+            BorshToken::Address(data) => {
+                buffer.extend_from_slice(data);
+            }
+            BorshToken::Uint { width, value } => {
+                encode_integer(*width, value, false, buffer)?;
+            }
+            BorshToken::Int { width, value } => {
+                encode_integer(*width, value, true, buffer)?;
+            }
+            BorshToken::FixedBytes(data) => {
+                buffer.extend_from_slice(data);
+            }
+            BorshToken::Bytes(data) => {
+                buffer.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                buffer.extend_from_slice(data);
+            }
+            BorshToken::Bool(value) => {
+                buffer.push(*value as u8);
+            }
+            BorshToken::String(data) => {
+                buffer.extend_from_slice(&(data.len() as u32).to_le_bytes());
+                buffer.extend_from_slice(data.as_bytes());
+            }
+            BorshToken::Tuple(data) | BorshToken::FixedArray(data) => {
+                for item in data {
+                    item.encode(buffer)?;
+                }
+            }
+            BorshToken::Array(arr) => {
+                buffer.extend_from_slice(&(arr.len() as u32).to_le_bytes());
+                for item in arr {
+                    item.encode(buffer)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            BorshToken::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_array(self) -> Option<Vec<BorshToken>> {
+        match self {
+            BorshToken::Array(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the bytes of a `bytesN`, an address or a fixed array of `uint8`
+    pub fn into_fixed_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            BorshToken::FixedBytes(value) => Some(value),
+            BorshToken::FixedArray(vec) => vec
+                .into_iter()
+                .map(|elem| match elem {
+                    BorshToken::Uint { width: 8, value } => value.to_u8(),
+                    _ => None,
+                })
+                .collect(),
+            BorshToken::Address(value) => Some(value.to_vec()),
+            _ => None,
+        }
+    }
+
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self {
+            BorshToken::Bytes(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_bigint(self) -> Option<BigInt> {
+        match self {
+            BorshToken::Uint { value, .. } | BorshToken::Int { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn unwrap_tuple(self) -> Vec<BorshToken> {
+        match self {
+            BorshToken::Tuple(vec) => vec,
+            _ => panic!("This is not a tuple"),
+        }
+    }
+
+    /// Create a fixed array of `uint8` from the given bytes
+    pub fn uint8_fixed_array(vec: Vec<u8>) -> BorshToken {
+        BorshToken::FixedArray(
+            vec.into_iter()
+                .map(|item| BorshToken::Uint {
+                    width: 8,
+                    value: BigInt::from(item),
+                })
+                .collect(),
+        )
+    }
+}
+
+/// The number of bytes an integer of the given width occupies. Integers are rounded up to the
+/// next power of two, like the Anchor IDL does.
+fn integer_byte_width(width: u16) -> Result<usize, BorshTokenError> {
+    match width {
+        1..=8 => Ok(1),
+        9..=16 => Ok(2),
+        17..=32 => Ok(4),
+        33..=64 => Ok(8),
+        65..=128 => Ok(16),
+        129..=256 => Ok(32),
+        _ => Err(BorshTokenError::UnsupportedWidth(width)),
+    }
+}
+
+/// Encode an integer as little endian two's complement
+fn encode_integer(
+    width: u16,
+    value: &BigInt,
+    signed: bool,
+    buffer: &mut Vec<u8>,
+) -> Result<(), BorshTokenError> {
+    let byte_width = integer_byte_width(width)?;
+
+    let fits = if signed {
+        let limit = BigInt::one() << (width - 1);
+
+        value >= &-&limit && value < &limit
+    } else {
+        value.sign() != Sign::Minus && value.bits() <= width as u64
+    };
+
+    if !fits {
+        return Err(BorshTokenError::IntegerOverflow {
+            width,
+            value: value.clone(),
+        });
+    }
+
+    let mut bytes = value.to_signed_bytes_le();
+
+    bytes.resize(
+        byte_width,
+        if value.sign() == Sign::Minus { 0xff } else { 0 },
+    );
+
+    buffer.extend_from_slice(&bytes);
+
+    Ok(())
+}
+
+/// Encode the arguments of a function
+pub fn encode_arguments(args: &[BorshToken]) -> Result<Vec<u8>, BorshTokenError> {
+    let mut encoded = Vec::new();
+
+    for item in args {
+        item.encode(&mut encoded)?;
+    }
+
+    Ok(encoded)
+}
+
+/// Read the given number of bytes at the offset, and advance the offset
+fn read<'a>(data: &'a [u8], offset: &mut usize, len: usize) -> Result<&'a [u8], BorshTokenError> {
+    let bytes = offset
+        .checked_add(len)
+        .and_then(|end| data.get(*offset..end))
+        .ok_or(BorshTokenError::UnexpectedEnd)?;
+
+    *offset += len;
+
+    Ok(bytes)
+}
+
+/// Read a length prefix
+fn read_length(data: &[u8], offset: &mut usize) -> Result<usize, BorshTokenError> {
+    let bytes = read(data, offset, 4)?;
+
+    Ok(u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+}
+
+/// Decode a value of the given IDL type at the offset, and advance the offset past it
+pub fn decode_at_offset(
+    data: &[u8],
+    offset: &mut usize,
+    ty: &IdlType,
+    custom_types: &[IdlTypeDefinition],
+) -> Result<BorshToken, BorshTokenError> {
+    let token = match ty {
+        IdlType::PublicKey => BorshToken::Address(read(data, offset, 32)?.try_into().unwrap()),
+        IdlType::U8
+        | IdlType::U16
+        | IdlType::U32
+        | IdlType::U64
+        | IdlType::U128
+        | IdlType::U256 => {
+            let width = idl_integer_width(ty);
+            let bytes = read(data, offset, width as usize / 8)?;
+
+            BorshToken::Uint {
+                width,
+                value: BigInt::from_bytes_le(Sign::Plus, bytes),
+            }
+        }
+        IdlType::I8
+        | IdlType::I16
+        | IdlType::I32
+        | IdlType::I64
+        | IdlType::I128
+        | IdlType::I256 => {
+            let width = idl_integer_width(ty);
+            let bytes = read(data, offset, width as usize / 8)?;
+
+            BorshToken::Int {
+                width,
+                value: BigInt::from_signed_bytes_le(bytes),
+            }
+        }
+        IdlType::Bool => BorshToken::Bool(read(data, offset, 1)?[0] == 1),
+        IdlType::String => {
+            let len = read_length(data, offset)?;
+            let bytes = read(data, offset, len)?;
+
+            BorshToken::String(
+                std::str::from_utf8(bytes)
+                    .map_err(|_| BorshTokenError::InvalidUtf8)?
+                    .to_string(),
+            )
+        }
+        IdlType::Bytes => {
+            let len = read_length(data, offset)?;
+
+            BorshToken::Bytes(read(data, offset, len)?.to_vec())
+        }
+        IdlType::Array(ty, len) => BorshToken::FixedArray(
+            (0..*len)
+                .map(|_| decode_at_offset(data, offset, ty, custom_types))
+                .collect::<Result<_, _>>()?,
+        ),
+        IdlType::Vec(ty) => {
+            let len = read_length(data, offset)?;
+
+            BorshToken::Array(
+                (0..len)
+                    .map(|_| decode_at_offset(data, offset, ty, custom_types))
+                    .collect::<Result<_, _>>()?,
+            )
+        }
+        IdlType::Defined(name) => {
+            let Some(def) = custom_types.iter().find(|item| &item.name == name) else {
+                return Err(BorshTokenError::UnknownType(name.clone()));
+            };
+
+            match &def.ty {
+                IdlTypeDefinitionTy::Enum { .. } => BorshToken::Uint {
+                    width: 8,
+                    value: BigInt::from(read(data, offset, 1)?[0]),
+                },
+                IdlTypeDefinitionTy::Struct { fields } => BorshToken::Tuple(
+                    fields
+                        .iter()
+                        .map(|field| decode_at_offset(data, offset, &field.ty, custom_types))
+                        .collect::<Result<_, _>>()?,
+                ),
+                IdlTypeDefinitionTy::Alias { value } => {
+                    decode_at_offset(data, offset, value, custom_types)?
+                }
+            }
+        }
+        IdlType::Option(_)
+        | IdlType::F32
+        | IdlType::F64
+        | IdlType::Generic(..)
+        | IdlType::DefinedWithTypeArgs { .. }
+        | IdlType::GenericLenArray(..) => return Err(BorshTokenError::UnsupportedType),
+    };
+
+    Ok(token)
+}
+
+fn idl_integer_width(ty: &IdlType) -> u16 {
+    match ty {
+        IdlType::U8 | IdlType::I8 => 8,
+        IdlType::U16 | IdlType::I16 => 16,
+        IdlType::U32 | IdlType::I32 => 32,
+        IdlType::U64 | IdlType::I64 => 64,
+        IdlType::U128 | IdlType::I128 => 128,
+        IdlType::U256 | IdlType::I256 => 256,
+        _ => unreachable!("not an integer"),
+    }
+}
@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 // ethereum style ABIs
-use crate::sema::ast::{Namespace, Parameter, StructType, Type};
+use crate::sema::ast::{Namespace, Parameter, Statement, StructType, Type};
+use crate::sema::Recurse;
 use serde::Serialize;
 use solang_parser::pt;
+use std::collections::BTreeSet;
 
 #[derive(Serialize)]
 #[allow(clippy::upper_case_acronyms)]
@@ -37,6 +39,104 @@ pub struct ABI {
     pub anonymous: Option<bool>,
 }
 
+/// The shape of the Ethereum ABI output
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AbiFormat {
+    /// JSON array as produced by solc
+    #[default]
+    Solc,
+    /// JSON array without the `internalType` fields, as produced by ethers
+    Ethers,
+    /// Human readable signatures, e.g. `function balanceOf(address owner) view returns (uint256)`
+    Human,
+}
+
+impl AbiFormat {
+    /// Serialize the ABI in this format
+    pub fn serialize(self, abi: &[ABI]) -> String {
+        match self {
+            AbiFormat::Solc => serde_json::to_string(abi).unwrap(),
+            AbiFormat::Ethers => {
+                let mut value = serde_json::to_value(abi).unwrap();
+
+                remove_internal_types(&mut value);
+
+                serde_json::to_string(&value).unwrap()
+            }
+            AbiFormat::Human => {
+                let signatures: Vec<String> = abi.iter().map(human_readable).collect();
+
+                serde_json::to_string_pretty(&signatures).unwrap()
+            }
+        }
+    }
+}
+
+fn remove_internal_types(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(entries) => entries.iter_mut().for_each(remove_internal_types),
+        serde_json::Value::Object(fields) => {
+            fields.remove("internalType");
+            fields.values_mut().for_each(remove_internal_types);
+        }
+        _ => (),
+    }
+}
+
+/// Format an ABI entry as a human readable signature, in the form accepted by ethers
+fn human_readable(abi: &ABI) -> String {
+    fn param(param: &ABIParam) -> String {
+        let mut s = if param.components.is_empty() {
+            param.ty.clone()
+        } else {
+            // e.g. tuple[] becomes tuple(uint256 a, bool b)[]
+            format!(
+                "tuple({}){}",
+                params(&param.components),
+                param.ty.trim_start_matches("tuple")
+            )
+        };
+
+        if param.indexed {
+            s.push_str(" indexed");
+        }
+
+        if !param.name.is_empty() {
+            s.push(' ');
+            s.push_str(&param.name);
+        }
+
+        s
+    }
+
+    fn params(params: &[ABIParam]) -> String {
+        params.iter().map(param).collect::<Vec<String>>().join(", ")
+    }
+
+    let inputs = abi.inputs.as_deref().map(params).unwrap_or_default();
+
+    let mut s = match abi.ty.as_str() {
+        "function" | "event" | "error" => format!("{} {}({})", abi.ty, abi.name, inputs),
+        "constructor" => format!("constructor({inputs})"),
+        _ => format!("{}() external", abi.ty),
+    };
+
+    if abi.anonymous == Some(true) {
+        s.push_str(" anonymous");
+    }
+
+    if !abi.mutability.is_empty() && abi.mutability != "nonpayable" {
+        s.push(' ');
+        s.push_str(&abi.mutability);
+    }
+
+    if let Some(outputs) = abi.outputs.as_deref().filter(|outputs| !outputs.is_empty()) {
+        s.push_str(&format!(" returns ({})", params(outputs)));
+    }
+
+    s
+}
+
 #[allow(clippy::trivially_copy_pass_by_ref)]
 fn is_false(boolean: &bool) -> bool {
     !(*boolean)
@@ -134,5 +234,68 @@ pub fn gen_abi(contract_no: usize, ns: &Namespace) -> Vec<ABI> {
                     }
                 }),
         )
+        .chain(
+            contract_errors(contract_no, ns)
+                .into_iter()
+                .map(|error_no| {
+                    let error = &ns.errors[error_no];
+
+                    ABI {
+                        name: error.name.to_owned(),
+                        mutability: String::new(),
+                        inputs: Some(
+                            error
+                                .fields
+                                .iter()
+                                .map(|p| parameter_to_abi(p, ns))
+                                .collect(),
+                        ),
+                        outputs: None,
+                        ty: "error".to_owned(),
+                        anonymous: None,
+                    }
+                }),
+        )
+        .collect()
+}
+
+/// The custom errors a contract can revert with: the errors declared in the contract or any of
+/// its bases, and any error used in a revert statement in its functions.
+fn contract_errors(contract_no: usize, ns: &Namespace) -> BTreeSet<usize> {
+    let bases = ns.contract_bases(contract_no);
+
+    let mut errors: BTreeSet<usize> = ns
+        .errors
+        .iter()
+        .enumerate()
+        .filter(|(_, error)| matches!(error.contract, Some(c) if bases.contains(&c)))
+        .map(|(error_no, _)| error_no)
+        .collect();
+
+    for function_no in ns.contracts[contract_no].all_functions.keys() {
+        for stmt in &ns.functions[*function_no].body {
+            stmt.recurse(&mut errors, |stmt, errors| {
+                if let Statement::Revert {
+                    error_no: Some(error_no),
+                    ..
+                } = stmt
+                {
+                    errors.insert(*error_no);
+                }
+
+                true
+            });
+        }
+    }
+
+    errors
+}
+
+/// The ABI of the constructors of a contract. Deployment tooling uses the inputs to know
+/// which ABI encoded arguments must be appended to the contract code.
+pub fn gen_constructor_abi(contract_no: usize, ns: &Namespace) -> Vec<ABI> {
+    gen_abi(contract_no, ns)
+        .into_iter()
+        .filter(|abi| abi.ty == "constructor")
         .collect()
 }
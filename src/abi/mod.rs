@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::file_resolver::FileResolver;
 use crate::sema::ast::Namespace;
 use crate::Target;
 
 pub mod anchor;
+pub mod borsh;
 pub mod ethereum;
 pub mod polkadot;
 mod tests;
@@ -15,6 +17,9 @@ pub fn generate_abi(
     verbose: bool,
     default_authors: &[String],
     version: &str,
+    resolver: &FileResolver,
+    include_sources: bool,
+    abi_format: ethereum::AbiFormat,
 ) -> (String, &'static str) {
     match ns.target {
         Target::Polkadot { .. } => {
@@ -25,7 +30,15 @@ pub fn generate_abi(
                 );
             }
 
-            let metadata = polkadot::metadata(contract_no, code, ns, default_authors, version);
+            let metadata = polkadot::metadata(
+                contract_no,
+                code,
+                ns,
+                default_authors,
+                version,
+                resolver,
+                include_sources,
+            );
 
             (serde_json::to_string_pretty(&metadata).unwrap(), "contract")
         }
@@ -51,7 +64,7 @@ pub fn generate_abi(
 
             let abi = ethereum::gen_abi(contract_no, ns);
 
-            (serde_json::to_string(&abi).unwrap(), "abi")
+            (abi_format.serialize(&abi), "abi")
         }
     }
 }
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 use contract_metadata::{
     CodeHash, Compiler, Contract, ContractMetadata, Language, Source, SourceCompiler,
-    SourceLanguage, SourceWasm,
+    SourceLanguage, SourceWasm, User,
 };
 use ink_env::hash::{Blake2x256, CryptoHash};
 use ink_metadata::{
@@ -10,7 +10,7 @@ use ink_metadata::{
     MessageParamSpec, MessageSpec, ReturnTypeSpec, TypeSpec,
 };
 
-use serde_json::Value;
+use serde_json::{json, Map, Value};
 
 use num_bigint::BigInt;
 use num_traits::ToPrimitive;
@@ -24,6 +24,7 @@ use solang_parser::pt;
 use crate::{
     codegen::polkadot::SCRATCH_SIZE,
     codegen::revert::{SolidityError, ERROR_SELECTOR, PANIC_SELECTOR},
+    file_resolver::FileResolver,
     sema::{
         ast::{self, ArrayLength, EventDecl, Function},
         tags::render,
@@ -565,13 +566,17 @@ fn tags(contract_no: usize, tagname: &str, ns: &ast::Namespace) -> Vec<String> {
         .collect()
 }
 
-/// Generate the metadata for Substrate 4.0
+/// Generate the metadata for Substrate 4.0. The `user` section lists the target and the Solidity
+/// source files with the hash of their contents. If `include_sources` is set, the contents of the
+/// source files are embedded too.
 pub fn metadata(
     contract_no: usize,
     code: &[u8],
     ns: &ast::Namespace,
     default_authors: &[String],
     contract_version: &str,
+    resolver: &FileResolver,
+    include_sources: bool,
 ) -> Value {
     let hash = blake2_rfc::blake2b::blake2b(32, &[], code);
     let version = Version::parse(env!("CARGO_PKG_VERSION")).unwrap();
@@ -606,5 +611,44 @@ pub fn metadata(
     let project_json = serde_json::to_value(gen_project(contract_no, ns)).unwrap();
     let abi = serde_json::from_value(project_json).unwrap();
 
-    serde_json::to_value(ContractMetadata::new(source, contract, None, None, abi)).unwrap()
+    let mut files = Map::new();
+
+    for file in &ns.files {
+        if let Some(contents) = file
+            .cache_no
+            .and_then(|cache_no| resolver.get_contents_of_file_no(cache_no))
+        {
+            let hash = blake2_rfc::blake2b::blake2b(32, &[], contents.as_bytes());
+
+            let mut source = Map::new();
+            source.insert(
+                "hash".to_owned(),
+                json!(format!("0x{}", hex::encode(hash.as_bytes()))),
+            );
+
+            if include_sources {
+                source.insert("content".to_owned(), json!(contents.as_ref()));
+            }
+
+            files.insert(
+                file.path.to_string_lossy().to_string(),
+                Value::Object(source),
+            );
+        }
+    }
+
+    let mut json = Map::new();
+    json.insert("target".to_owned(), json!(ns.target.to_string()));
+    json.insert("sources".to_owned(), Value::Object(files));
+
+    let user = User::new(json);
+
+    serde_json::to_value(ContractMetadata::new(
+        source,
+        contract,
+        None,
+        Some(user),
+        abi,
+    ))
+    .unwrap()
 }
@@ -3,6 +3,8 @@
 #![cfg(test)]
 
 use crate::abi::anchor::generate_anchor_idl;
+use crate::abi::borsh::{decode_at_offset, encode_arguments, BorshToken, BorshTokenError};
+use crate::abi::ethereum::{gen_abi, gen_constructor_abi, AbiFormat};
 use crate::codegen::{codegen, Options};
 use crate::file_resolver::FileResolver;
 use crate::sema::ast::Namespace;
@@ -11,6 +13,8 @@ use anchor_syn::idl::types::{
     IdlAccount, IdlAccountItem, IdlEnumVariant, IdlEvent, IdlEventField, IdlField, IdlType,
     IdlTypeDefinition, IdlTypeDefinitionTy,
 };
+use num_bigint::BigInt;
+use num_traits::One;
 use semver::Version;
 use serde_json::json;
 use std::ffi::OsStr;
@@ -2128,3 +2132,344 @@ fn idl_account(name: &str, is_mut: bool, is_signer: bool) -> IdlAccountItem {
         relations: vec![],
     })
 }
+
+#[test]
+fn constructor_abi() {
+    let src = r#"
+contract Token {
+    string name;
+    uint256 supply;
+
+    constructor(string memory _name, uint256 _supply) {
+        name = _name;
+        supply = _supply;
+    }
+
+    function getSupply() public view returns (uint256) {
+        return supply;
+    }
+}
+    "#;
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    let abi = gen_constructor_abi(0, &ns);
+
+    assert_eq!(
+        serde_json::to_value(&abi).unwrap(),
+        json!([{
+            "type": "constructor",
+            "inputs": [
+                { "name": "_name", "type": "string", "internalType": "string" },
+                { "name": "_supply", "type": "uint256", "internalType": "uint256" }
+            ],
+            "stateMutability": "nonpayable"
+        }])
+    );
+}
+
+#[test]
+fn ethereum_abi_custom_errors() {
+    let src = r#"
+error Unauthorized(address);
+
+contract Base {
+    error InsufficientBalance(uint256 available, uint256 required);
+}
+
+contract Token is Base {
+    function withdraw(uint256 amount) public view {
+        if (amount > 10) {
+            revert InsufficientBalance(10, amount);
+        }
+        revert Unauthorized(msg.sender);
+    }
+}
+    "#;
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    let errors = |contract_no| {
+        let abi = gen_abi(contract_no, &ns);
+
+        serde_json::to_value(
+            abi.iter()
+                .filter(|abi| abi.ty == "error")
+                .collect::<Vec<_>>(),
+        )
+        .unwrap()
+    };
+
+    assert_eq!(
+        errors(0),
+        json!([{
+            "name": "InsufficientBalance",
+            "type": "error",
+            "inputs": [
+                { "name": "available", "type": "uint256", "internalType": "uint256" },
+                { "name": "required", "type": "uint256", "internalType": "uint256" }
+            ]
+        }])
+    );
+
+    // errors of base contracts are listed once
+    assert_eq!(
+        errors(1),
+        json!([{
+            "name": "Unauthorized",
+            "type": "error",
+            "inputs": [
+                { "name": "", "type": "address", "internalType": "address" }
+            ]
+        }, {
+            "name": "InsufficientBalance",
+            "type": "error",
+            "inputs": [
+                { "name": "available", "type": "uint256", "internalType": "uint256" },
+                { "name": "required", "type": "uint256", "internalType": "uint256" }
+            ]
+        }])
+    );
+}
+
+#[test]
+fn ethereum_abi_formats() {
+    let src = r#"
+contract Token {
+    struct Pair {
+        uint256 a;
+        bool b;
+    }
+
+    event Transfer(address indexed from, address indexed to, uint256 amount);
+
+    error Unauthorized(address caller);
+
+    constructor(string memory name) {}
+
+    function balanceOf(address owner) public view returns (uint256) {
+        return owner.balance;
+    }
+
+    function transfer(address to, uint256 amount) public returns (bool) {
+        if (amount == 0) {
+            revert Unauthorized(msg.sender);
+        }
+        emit Transfer(msg.sender, to, amount);
+        return true;
+    }
+
+    function pairs(Pair[] memory p) public pure returns (uint256) {
+        return p.length;
+    }
+
+    receive() external payable {}
+}
+    "#;
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    let abi = gen_abi(0, &ns);
+
+    let solc: serde_json::Value = serde_json::from_str(&AbiFormat::Solc.serialize(&abi)).unwrap();
+
+    assert_eq!(solc, serde_json::to_value(&abi).unwrap());
+    assert!(solc.is_array());
+    assert_eq!(solc[1]["inputs"][0]["internalType"], "address");
+
+    let ethers = AbiFormat::Ethers.serialize(&abi);
+
+    assert!(!ethers.contains("internalType"));
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&ethers).unwrap()[1]["inputs"][0],
+        json!({ "name": "owner", "type": "address" })
+    );
+
+    let human: Vec<String> = serde_json::from_str(&AbiFormat::Human.serialize(&abi)).unwrap();
+
+    assert_eq!(
+        human,
+        vec![
+            "constructor(string name)",
+            "function balanceOf(address owner) view returns (uint256)",
+            "function transfer(address to, uint256 amount) returns (bool)",
+            "function pairs(tuple(uint256 a, bool b)[] p) pure returns (uint256)",
+            "receive() external payable",
+            "event Transfer(address indexed from, address indexed to, uint256 amount)",
+            "error Unauthorized(address caller)",
+        ]
+    );
+}
+
+#[test]
+fn borsh_integer_round_trip() {
+    let max = |bits: u16| (BigInt::one() << bits) - 1;
+    let min = |bits: u16| -(BigInt::one() << (bits - 1));
+
+    let cases = [
+        (
+            BorshToken::Uint {
+                width: 8,
+                value: max(8),
+            },
+            IdlType::U8,
+            1,
+        ),
+        (
+            BorshToken::Int {
+                width: 8,
+                value: min(8),
+            },
+            IdlType::I8,
+            1,
+        ),
+        (
+            BorshToken::Uint {
+                width: 64,
+                value: max(64),
+            },
+            IdlType::U64,
+            8,
+        ),
+        (
+            BorshToken::Int {
+                width: 64,
+                value: BigInt::from(-2),
+            },
+            IdlType::I64,
+            8,
+        ),
+        (
+            BorshToken::Uint {
+                width: 256,
+                value: max(256),
+            },
+            IdlType::U256,
+            32,
+        ),
+        (
+            BorshToken::Int {
+                width: 256,
+                value: min(256),
+            },
+            IdlType::I256,
+            32,
+        ),
+        (
+            BorshToken::Int {
+                width: 256,
+                value: max(255),
+            },
+            IdlType::I256,
+            32,
+        ),
+    ];
+
+    for (token, ty, len) in cases {
+        let encoded = encode_arguments(&[token.clone()]).unwrap();
+
+        assert_eq!(encoded.len(), len);
+
+        let mut offset = 0;
+
+        assert_eq!(decode_at_offset(&encoded, &mut offset, &ty, &[]), Ok(token));
+        assert_eq!(offset, len);
+    }
+
+    assert_eq!(
+        encode_arguments(&[BorshToken::Uint {
+            width: 8,
+            value: BigInt::from(256)
+        }]),
+        Err(BorshTokenError::IntegerOverflow {
+            width: 8,
+            value: BigInt::from(256)
+        })
+    );
+
+    assert_eq!(
+        encode_arguments(&[BorshToken::Int {
+            width: 64,
+            value: max(63) + 1
+        }]),
+        Err(BorshTokenError::IntegerOverflow {
+            width: 64,
+            value: max(63) + 1
+        })
+    );
+
+    assert_eq!(
+        decode_at_offset(&[0; 31], &mut 0, &IdlType::U256, &[]),
+        Err(BorshTokenError::UnexpectedEnd)
+    );
+}
+
+#[test]
+fn borsh_dynamic_round_trip() {
+    let token = BorshToken::Tuple(vec![
+        BorshToken::String("solang".into()),
+        BorshToken::Bytes(vec![1, 2, 3]),
+        BorshToken::Array(vec![
+            BorshToken::Uint {
+                width: 16,
+                value: BigInt::from(500),
+            },
+            BorshToken::Uint {
+                width: 16,
+                value: BigInt::from(7),
+            },
+        ]),
+        BorshToken::FixedArray(vec![BorshToken::Bool(true), BorshToken::Bool(false)]),
+    ]);
+
+    let encoded = encode_arguments(&[token.clone()]).unwrap();
+
+    assert_eq!(
+        encoded,
+        [
+            6, 0, 0, 0, b's', b'o', b'l', b'a', b'n', b'g', 3, 0, 0, 0, 1, 2, 3, 2, 0, 0, 0, 0xf4,
+            1, 7, 0, 1, 0
+        ]
+    );
+
+    let types = vec![IdlTypeDefinition {
+        name: "s".into(),
+        docs: None,
+        generics: None,
+        ty: IdlTypeDefinitionTy::Struct {
+            fields: vec![
+                IdlField {
+                    name: "a".into(),
+                    docs: None,
+                    ty: IdlType::String,
+                },
+                IdlField {
+                    name: "b".into(),
+                    docs: None,
+                    ty: IdlType::Bytes,
+                },
+                IdlField {
+                    name: "c".into(),
+                    docs: None,
+                    ty: IdlType::Vec(Box::new(IdlType::U16)),
+                },
+                IdlField {
+                    name: "d".into(),
+                    docs: None,
+                    ty: IdlType::Array(Box::new(IdlType::Bool), 2),
+                },
+            ],
+        },
+    }];
+
+    let mut offset = 0;
+
+    assert_eq!(
+        decode_at_offset(&encoded, &mut offset, &IdlType::Defined("s".into()), &types),
+        Ok(token)
+    );
+    assert_eq!(offset, encoded.len());
+}
@@ -154,6 +154,15 @@ impl Compile {
                         .get_many::<(String, PathBuf)>("IMPORTMAP")
                         .map(|import_map| import_map.cloned().collect())
                 }
+                "EXCLUDE" => {
+                    self.package.exclude = matches
+                        .get_many::<String>("EXCLUDE")
+                        .map(|patterns| patterns.map(String::from).collect())
+                }
+                "STD-JSON-INPUT" => {
+                    self.package.standard_json_input =
+                        matches.get_one::<PathBuf>("STD-JSON-INPUT").cloned()
+                }
                 "AUTHOR" => {
                     self.package.authors = matches
                         .get_many::<String>("AUTHOR")
@@ -163,6 +172,14 @@ impl Compile {
 
                 // CompilerOutput args
                 "EMIT" => self.compiler_output.emit = matches.get_one::<String>("EMIT").cloned(),
+                "ASMFLAVOR" => {
+                    self.compiler_output.asm_flavor =
+                        matches.get_one::<String>("ASMFLAVOR").cloned()
+                }
+                "ABIFORMAT" => {
+                    self.compiler_output.abi_format =
+                        matches.get_one::<String>("ABIFORMAT").cloned()
+                }
                 "OUTPUT" => {
                     self.compiler_output.output_directory =
                         matches.get_one::<String>("OUTPUT").cloned()
@@ -171,6 +188,45 @@ impl Compile {
                     self.compiler_output.output_meta =
                         matches.get_one::<String>("OUTPUTMETA").cloned()
                 }
+                "REPORTJSON" => {
+                    self.compiler_output.report_json =
+                        matches.get_one::<String>("REPORTJSON").cloned()
+                }
+                "MAXERRORS" => {
+                    self.compiler_output.max_errors = matches.get_one::<u64>("MAXERRORS").copied()
+                }
+                "INCLUDESOURCE" => {
+                    self.compiler_output.include_source_in_metadata =
+                        *matches.get_one::<bool>("INCLUDESOURCE").unwrap()
+                }
+                "MAXCOMPLEXITY" => {
+                    self.compiler_output.max_cyclomatic_complexity =
+                        matches.get_one::<u64>("MAXCOMPLEXITY").copied()
+                }
+                "MAXCALLDEPTH" => {
+                    self.compiler_output.max_call_depth =
+                        matches.get_one::<u64>("MAXCALLDEPTH").copied()
+                }
+                "WARNEMPTYCATCH" => {
+                    self.compiler_output.warn_empty_catch =
+                        *matches.get_one::<bool>("WARNEMPTYCATCH").unwrap()
+                }
+                "NOWARNTXORIGIN" => {
+                    self.compiler_output.no_warn_tx_origin =
+                        *matches.get_one::<bool>("NOWARNTXORIGIN").unwrap()
+                }
+                "STOPAFTER" => {
+                    self.compiler_output.stop_after =
+                        matches.get_one::<String>("STOPAFTER").cloned()
+                }
+                "TRACERESOLUTION" => {
+                    self.compiler_output.trace_resolution =
+                        *matches.get_one::<bool>("TRACERESOLUTION").unwrap()
+                }
+                "TIMEPASSES" => {
+                    self.compiler_output.time_passes =
+                        *matches.get_one::<bool>("TIMEPASSES").unwrap()
+                }
                 "STD-JSON" => {
                     self.compiler_output.std_json_output =
                         *matches.get_one::<bool>("STD-JSON").unwrap()
@@ -217,6 +273,11 @@ impl Compile {
                         .get_one::<bool>("COMMONSUBEXPRESSIONELIMINATION")
                         .unwrap()
                 }
+                "UNUSEDVARIABLEELIMINATION" => {
+                    self.optimizations.unused_variable_elimination = *matches
+                        .get_one::<bool>("UNUSEDVARIABLEELIMINATION")
+                        .unwrap()
+                }
                 "OPT" => self.optimizations.opt_level = matches.get_one::<String>("OPT").cloned(),
 
                 "TARGET" => self.target_arg.name = matches.get_one::<String>("TARGET").cloned(),
@@ -238,10 +299,18 @@ impl Compile {
 
 #[derive(Args, Deserialize, Default, Debug, PartialEq)]
 pub struct CompilerOutput {
-    #[arg(name = "EMIT", help = "Emit compiler state at early stage", long = "emit", num_args = 1, value_parser = ["ast-dot", "cfg", "llvm-ir", "llvm-bc", "object", "asm"])]
+    #[arg(name = "EMIT", help = "Emit compiler state at early stage", long = "emit", num_args = 1, value_parser = ["ast-dot", "ast-json", "call-graph", "cfg", "cfg-json", "llvm-ir", "llvm-bc", "object", "asm", "constructor-abi", "metadata", "deps"])]
     #[serde(deserialize_with = "deserialize_emit", default)]
     pub emit: Option<String>,
 
+    #[arg(name = "ASMFLAVOR", help = "Flavor of assembly emitted by --emit asm", long = "asm-flavor", num_args = 1, value_parser = ["llvm", "wat", "compact", "annotated"], requires = "EMIT")]
+    #[serde(default)]
+    pub asm_flavor: Option<String>,
+
+    #[arg(name = "ABIFORMAT", help = "Format of the Ethereum ABI output", long = "output-abi-format", num_args = 1, value_parser = ["solc", "ethers", "human"])]
+    #[serde(default)]
+    pub abi_format: Option<String>,
+
     #[arg(name = "STD-JSON",help = "mimic solidity json output on stdout", conflicts_with_all = ["VERBOSE", "OUTPUT", "EMIT"], action = ArgAction::SetTrue, long = "standard-json")]
     #[serde(default)]
     pub std_json_output: bool,
@@ -254,6 +323,46 @@ pub struct CompilerOutput {
     #[serde(default)]
     pub output_meta: Option<String>,
 
+    #[arg(name = "REPORTJSON", help = "Write a JSON report of the emitted artifacts with their sha256 hashes", long = "report-json", num_args = 1, value_parser = ValueParser::string(), conflicts_with = "STD-JSON")]
+    #[serde(default)]
+    pub report_json: Option<String>,
+
+    #[arg(name = "MAXERRORS", help = "Stop after this many errors have been found", long = "max-errors", num_args = 1, value_parser = value_parser!(u64).range(1..))]
+    #[serde(default)]
+    pub max_errors: Option<u64>,
+
+    #[arg(name = "INCLUDESOURCE", help = "Embed the source files in the metadata", action = ArgAction::SetTrue, long = "include-source-in-metadata")]
+    #[serde(default)]
+    pub include_source_in_metadata: bool,
+
+    #[arg(name = "MAXCOMPLEXITY", help = "Warn about functions with a cyclomatic complexity above this value", long = "max-cyclomatic-complexity", num_args = 1, value_parser = value_parser!(u64).range(1..))]
+    #[serde(default)]
+    pub max_cyclomatic_complexity: Option<u64>,
+
+    #[arg(name = "MAXCALLDEPTH", help = "Warn about recursive functions which may exceed this call depth", long = "max-call-depth", num_args = 1, value_parser = value_parser!(u64).range(1..))]
+    #[serde(default)]
+    pub max_call_depth: Option<u64>,
+
+    #[arg(name = "WARNEMPTYCATCH", help = "Warn about catch clauses with an empty body, which ignore all errors", action = ArgAction::SetTrue, long = "warn-empty-catch")]
+    #[serde(default)]
+    pub warn_empty_catch: bool,
+
+    #[arg(name = "NOWARNTXORIGIN", help = "Do not warn about comparing tx.origin for authorization", action = ArgAction::SetTrue, long = "no-warn-tx-origin")]
+    #[serde(default)]
+    pub no_warn_tx_origin: bool,
+
+    #[arg(name = "STOPAFTER", help = "Stop compilation after the given stage", long = "stop-after", num_args = 1, value_parser = ["parse", "resolve", "codegen"], conflicts_with = "STD-JSON")]
+    #[serde(default)]
+    pub stop_after: Option<String>,
+
+    #[arg(name = "TRACERESOLUTION", help = "Print a trace of semantic analysis to stderr", action = ArgAction::SetTrue, long = "trace-resolution")]
+    #[serde(default)]
+    pub trace_resolution: bool,
+
+    #[arg(name = "TIMEPASSES", help = "Print the time spent in each compiler pass to stderr", action = ArgAction::SetTrue, long = "time-passes")]
+    #[serde(default)]
+    pub time_passes: bool,
+
     #[arg(name = "VERBOSE" ,help = "show debug messages", short = 'v', action = ArgAction::SetTrue, long = "verbose")]
     #[serde(default)]
     pub verbose: bool,
@@ -296,6 +405,9 @@ pub struct DocPackage {
 
     #[arg(name = "IMPORTMAP", help = "Map directory to search for solidity files [format: map=path]",value_parser = ValueParser::new(parse_import_map), action = ArgAction::Append, long = "importmap", short = 'm', num_args = 1)]
     pub import_map: Option<Vec<(String, PathBuf)>>,
+
+    #[arg(name = "EXCLUDE", help = "Skip input files matching glob pattern", value_parser = ValueParser::new(parse_glob), action = ArgAction::Append, long = "exclude", num_args = 1)]
+    pub exclude: Option<Vec<String>>,
 }
 
 #[derive(Args, Deserialize, Debug, PartialEq)]
@@ -314,6 +426,14 @@ pub struct CompilePackage {
     #[serde(deserialize_with = "deserialize_inline_table", default)]
     pub import_map: Option<Vec<(String, PathBuf)>>,
 
+    #[arg(name = "EXCLUDE", help = "Skip input files matching glob pattern", value_parser = ValueParser::new(parse_glob), action = ArgAction::Append, long = "exclude", num_args = 1)]
+    #[serde(default)]
+    pub exclude: Option<Vec<String>>,
+
+    #[arg(name = "STD-JSON-INPUT", help = "Read the sources and remappings from a Solidity standard JSON input file, and write standard JSON output", long = "standard-json-input", num_args = 1, value_parser = ValueParser::path_buf(), conflicts_with = "INPUT")]
+    #[serde(default)]
+    pub standard_json_input: Option<PathBuf>,
+
     #[arg(name = "AUTHOR", help = "specify contracts authors", long = "contract-authors", value_delimiter = ',', action = ArgAction::Append)]
     #[serde(default)]
     pub authors: Option<Vec<String>>,
@@ -378,6 +498,13 @@ pub struct Optimizations {
     )]
     pub common_subexpression_elimination: bool,
 
+    #[arg(name = "UNUSEDVARIABLEELIMINATION", help = "Disable unused variable elimination", long = "no-unused-variable-elimination", action = ArgAction::SetFalse, display_order = 6)]
+    #[serde(
+        default = "default_true",
+        rename(deserialize = "unused-variable-elimination")
+    )]
+    pub unused_variable_elimination: bool,
+
     #[arg(name = "OPT", help = "Set llvm optimizer level ", short = 'O', default_value = "default", value_parser = ["none", "less", "default", "aggressive"], num_args = 1)]
     #[serde(rename(deserialize = "llvm-IR-optimization-level"))]
     pub opt_level: Option<String>,
@@ -468,6 +595,7 @@ pub trait PackageTrait {
     fn get_input(&self) -> &Vec<PathBuf>;
     fn get_import_path(&self) -> &Option<Vec<PathBuf>>;
     fn get_import_map(&self) -> &Option<Vec<(String, PathBuf)>>;
+    fn get_exclude(&self) -> &Option<Vec<String>>;
 }
 
 impl PackageTrait for CompilePackage {
@@ -489,6 +617,10 @@ impl PackageTrait for CompilePackage {
     fn get_import_map(&self) -> &Option<Vec<(String, PathBuf)>> {
         &self.import_map
     }
+
+    fn get_exclude(&self) -> &Option<Vec<String>> {
+        &self.exclude
+    }
 }
 
 impl PackageTrait for DocPackage {
@@ -503,6 +635,32 @@ impl PackageTrait for DocPackage {
     fn get_import_map(&self) -> &Option<Vec<(String, PathBuf)>> {
         &self.import_map
     }
+
+    fn get_exclude(&self) -> &Option<Vec<String>> {
+        &self.exclude
+    }
+}
+
+/// Return the input files, without those which match any of the `--exclude` glob patterns
+pub fn input_files<T: PackageTrait>(package: &T) -> Vec<&PathBuf> {
+    let patterns = package
+        .get_exclude()
+        .iter()
+        .flatten()
+        .map(|pattern| match glob::Pattern::new(pattern) {
+            Ok(pattern) => pattern,
+            Err(err) => {
+                eprintln!("error: invalid exclude pattern '{pattern}': {err}");
+                exit(1);
+            }
+        })
+        .collect::<Vec<_>>();
+
+    package
+        .get_input()
+        .iter()
+        .filter(|path| !patterns.iter().any(|pattern| pattern.matches_path(path)))
+        .collect()
 }
 
 pub fn imports_arg<T: PackageTrait>(package: &T) -> FileResolver {
@@ -564,10 +722,13 @@ pub fn options_arg(debug: &DebugFeatures, optimizations: &Optimizations) -> Opti
         strength_reduce: optimizations.strength_reduce,
         vector_to_slice: optimizations.vector_to_slice,
         common_subexpression_elimination: optimizations.common_subexpression_elimination,
+        unused_variable_elimination: optimizations.unused_variable_elimination,
         generate_debug_information: debug.generate_debug_info,
         opt_level,
         log_runtime_errors: debug.log_runtime_errors && !debug.release,
         log_prints: debug.log_prints && !debug.release,
+        max_cyclomatic_complexity: None,
+        max_call_depth: None,
         #[cfg(feature = "wasm_opt")]
         wasm_opt: optimizations.wasm_opt_passes.or(if debug.release {
             Some(OptimizationPasses::Z)
@@ -588,6 +749,13 @@ fn parse_import_map(map: &str) -> Result<(String, PathBuf), String> {
     }
 }
 
+fn parse_glob(pattern: &str) -> Result<String, String> {
+    match glob::Pattern::new(pattern) {
+        Ok(_) => Ok(pattern.to_owned()),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
 fn parse_version(version: &str) -> Result<String, String> {
     match Version::parse(version) {
         Ok(version) => Ok(version.to_string()),
@@ -648,10 +816,10 @@ where
     match str {
         Some(value) => {
             match value.as_str() {
-                "ast-dot"|"cfg"|"llvm-ir"|"llvm-bc"|"object"|"asm" =>
+                "ast-dot"|"ast-json"|"call-graph"|"cfg"|"cfg-json"|"llvm-ir"|"llvm-bc"|"object"|"asm"|"constructor-abi"|"metadata"|"deps" =>
                     Ok(Some(value))
                 ,
-                _ => Err(serde::de::Error::custom("Invalid option for `emit`. Valid options are: `ast-dot`, `cfg`, `llvm-ir`, `llvm-bc`, `object`, `asm`"))
+                _ => Err(serde::de::Error::custom("Invalid option for `emit`. Valid options are: `ast-dot`, `ast-json`, `call-graph`, `cfg`, `cfg-json`, `llvm-ir`, `llvm-bc`, `object`, `asm`, `constructor-abi`, `metadata`, `deps`"))
             }
         }
         None => Ok(None),
@@ -15,7 +15,7 @@ mod tests {
 
     #[test]
     fn parse_compile_options() {
-        let mut command: Vec<&str> = "solang compile flipper.sol --target polkadot --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse -O aggressive".split(' ').collect();
+        let mut command: Vec<&str> = "solang compile flipper.sol --target polkadot --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse --no-unused-variable-elimination -O aggressive".split(' ').collect();
         let mut cli = Cli::parse_from(command);
 
         if let Commands::Compile(compile_args) = cli.command {
@@ -31,6 +31,7 @@ mod tests {
             assert!(!compile_args.optimizations.dead_storage);
             assert!(!compile_args.optimizations.vector_to_slice);
             assert!(!compile_args.optimizations.strength_reduce);
+            assert!(!compile_args.optimizations.unused_variable_elimination);
             assert_eq!(compile_args.optimizations.opt_level.unwrap(), "aggressive");
         }
 
@@ -111,6 +112,7 @@ mod tests {
         strength-reduce = false
         vector-to-slice = false
         common-subexpression-elimination = true
+        unused-variable-elimination = false
         llvm-IR-optimization-level = "aggressive""#;
 
         let opt: cli::Optimizations = toml::from_str(opt_toml).unwrap();
@@ -120,6 +122,7 @@ mod tests {
         assert!(!opt.constant_folding);
         assert!(!opt.strength_reduce);
         assert!(!opt.vector_to_slice);
+        assert!(!opt.unused_variable_elimination);
         assert_eq!(opt.opt_level.unwrap(), "aggressive");
     }
 
@@ -184,14 +187,28 @@ mod tests {
                     contracts: Some(vec!["flipper".to_owned()]),
                     import_path: Some(vec![]),
                     import_map: Some(vec![]),
+                    exclude: None,
+                    standard_json_input: None,
                     authors: None,
                     version: Some("0.1.0".to_string())
                 },
                 compiler_output: cli::CompilerOutput {
                     emit: None,
+                    asm_flavor: None,
+                    abi_format: None,
                     std_json_output: false,
                     output_directory: None,
                     output_meta: None,
+                    report_json: None,
+                    max_errors: None,
+                    include_source_in_metadata: false,
+                    max_cyclomatic_complexity: None,
+                    max_call_depth: None,
+                    warn_empty_catch: false,
+                    no_warn_tx_origin: false,
+                    stop_after: None,
+                    trace_resolution: false,
+                    time_passes: false,
                     verbose: false
                 },
                 target_arg: cli::CompileTargetArg {
@@ -211,6 +228,7 @@ mod tests {
                     strength_reduce: true,
                     vector_to_slice: true,
                     common_subexpression_elimination: true,
+                    unused_variable_elimination: true,
                     opt_level: Some("aggressive".to_owned()),
                     #[cfg(feature = "wasm_opt")]
                     wasm_opt_passes: None
@@ -218,7 +236,7 @@ mod tests {
             }
         );
 
-        let command = "solang compile flipper.sol sesa.sol --config-file solang.toml --contract-authors not_sesa --target polkadot --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse -O aggressive".split(' ');
+        let command = "solang compile flipper.sol sesa.sol --config-file solang.toml --contract-authors not_sesa --target polkadot --value-length=31 --address-length=33 --no-dead-storage --no-constant-folding --no-strength-reduce --no-vector-to-slice --no-cse --no-unused-variable-elimination -O aggressive".split(' ');
 
         let matches = Cli::command().get_matches_from(command);
 
@@ -238,14 +256,28 @@ mod tests {
                     contracts: Some(vec!["flipper".to_owned()]),
                     import_path: Some(vec![]),
                     import_map: Some(vec![]),
+                    exclude: None,
+                    standard_json_input: None,
                     authors: Some(vec!["not_sesa".to_owned()]),
                     version: Some("0.1.0".to_string())
                 },
                 compiler_output: cli::CompilerOutput {
                     emit: None,
+                    asm_flavor: None,
+                    abi_format: None,
                     std_json_output: false,
                     output_directory: None,
                     output_meta: None,
+                    report_json: None,
+                    max_errors: None,
+                    include_source_in_metadata: false,
+                    max_cyclomatic_complexity: None,
+                    max_call_depth: None,
+                    warn_empty_catch: false,
+                    no_warn_tx_origin: false,
+                    stop_after: None,
+                    trace_resolution: false,
+                    time_passes: false,
                     verbose: false
                 },
                 target_arg: cli::CompileTargetArg {
@@ -265,6 +297,7 @@ mod tests {
                     strength_reduce: false,
                     vector_to_slice: false,
                     common_subexpression_elimination: false,
+                    unused_variable_elimination: false,
                     opt_level: Some("aggressive".to_owned()),
                     #[cfg(feature = "wasm_opt")]
                     wasm_opt_passes: None
@@ -7,11 +7,13 @@ use rust_lapper::{Interval, Lapper};
 use serde_json::Value;
 use solang::{
     codegen::{self, codegen, Expression},
-    file_resolver::FileResolver,
+    file_resolver::{FileResolver, ParseCache},
     parse_and_resolve,
     sema::{
         ast::{self, RetrieveType, StructType, Type},
-        builtin::{get_prototype, BUILTIN_FUNCTIONS, BUILTIN_METHODS, BUILTIN_VARIABLE},
+        builtin::{
+            get_prototype, is_reserved, BUILTIN_FUNCTIONS, BUILTIN_METHODS, BUILTIN_VARIABLE,
+        },
         builtin_structs::BUILTIN_STRUCTS,
         symtable,
         tags::render,
@@ -20,9 +22,10 @@ use solang::{
 };
 use solang_parser::pt;
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{hash_map::DefaultHasher, HashMap, HashSet},
     ffi::OsString,
-    path::PathBuf,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
 };
 use tokio::sync::Mutex;
 use tower_lsp::{
@@ -37,12 +40,12 @@ use tower_lsp::{
         DiagnosticSeverity, DidChangeConfigurationParams, DidChangeTextDocumentParams,
         DidChangeWatchedFilesParams, DidChangeWorkspaceFoldersParams, DidCloseTextDocumentParams,
         DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentFormattingParams,
-        ExecuteCommandOptions, ExecuteCommandParams, GotoDefinitionParams, GotoDefinitionResponse,
-        Hover, HoverContents, HoverParams, HoverProviderCapability,
-        ImplementationProviderCapability, InitializeParams, InitializeResult, InitializedParams,
-        Location, MarkedString, MessageType, OneOf, Position, Range, ReferenceParams, RenameParams,
-        ServerCapabilities, SignatureHelpOptions, TextDocumentContentChangeEvent,
-        TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit,
+        DocumentSymbol, DocumentSymbolParams, DocumentSymbolResponse, ExecuteCommandOptions,
+        ExecuteCommandParams, GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverContents,
+        HoverParams, HoverProviderCapability, ImplementationProviderCapability, InitializeParams,
+        InitializeResult, InitializedParams, Location, MarkedString, MessageType, OneOf, Position,
+        Range, ReferenceParams, RenameParams, ServerCapabilities, SignatureHelpOptions, SymbolKind,
+        TextDocumentContentChangeEvent, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit,
         TypeDefinitionProviderCapability, Url, WorkspaceEdit, WorkspaceFoldersServerCapabilities,
         WorkspaceServerCapabilities,
     },
@@ -120,6 +123,8 @@ type Types = HashMap<DefinitionIndex, DefinitionIndex>;
 type Declarations = HashMap<DefinitionIndex, Vec<DefinitionIndex>>;
 /// Stores all the fields, variants, methods etc. defined for a code object
 type Properties = HashMap<DefinitionIndex, HashMap<String, Option<DefinitionIndex>>>;
+/// Stores the content hash of every file in the namespace of an analysed file
+type Analyzed = HashMap<PathBuf, HashMap<PathBuf, u64>>;
 
 /// Stores information used by language server for every opened file
 #[derive(Default)]
@@ -128,6 +133,33 @@ struct Files {
     text_buffers: HashMap<PathBuf, String>,
 }
 
+impl Files {
+    /// Replace the caches of the files in the namespace with the newly built ones.
+    ///
+    /// While the user is typing, e.g. `x.` before a completion request, the file often
+    /// does not parse. The cache built from such a file is empty, so keep the cache from
+    /// the last time the file did parse, with only the line offsets updated.
+    fn update_caches(
+        &mut self,
+        ns: &ast::Namespace,
+        file_caches: Vec<FileCache>,
+        parse_errors: bool,
+    ) {
+        for (file_no, (f, c)) in ns.files.iter().zip(file_caches).enumerate() {
+            if f.cache_no.is_some() {
+                if parse_errors && file_no == ns.top_file_no() {
+                    if let Some(cache) = self.caches.get_mut(&f.path) {
+                        cache.file = f.clone();
+                        continue;
+                    }
+                }
+
+                self.caches.insert(f.path.clone(), c);
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 struct FileCache {
     file: ast::File,
@@ -150,6 +182,8 @@ struct FileCache {
 /// * `declarations` maps the `DefinitionIndex` of a `Contract` method to a list of methods that it overrides. The overridden methods belong to the parent `Contract`s
 /// * `implementations` maps the `DefinitionIndex` of a `Contract` to the `DefinitionIndex`s of methods defined as part of the `Contract`.
 /// * `properties` maps the `DefinitionIndex` of a code objects to the name and type of fields, variants or methods defined in the code object.
+/// * `analyzed` maps each file which was analysed to the content hashes of all the files in its namespace, i.e. the file itself and everything it imports.
+/// * `parse_cache` holds the parse trees of the analysed files and their imports, so that files which have not changed are not parsed again.
 #[derive(Default)]
 struct GlobalCache {
     definitions: Definitions,
@@ -157,6 +191,8 @@ struct GlobalCache {
     declarations: Declarations,
     implementations: Implementations,
     properties: Properties,
+    analyzed: Analyzed,
+    parse_cache: ParseCache,
 }
 
 impl GlobalCache {
@@ -166,6 +202,7 @@ impl GlobalCache {
         self.declarations.extend(other.declarations);
         self.implementations.extend(other.implementations);
         self.properties.extend(other.properties);
+        self.analyzed.extend(other.analyzed);
     }
 }
 
@@ -258,8 +295,13 @@ impl SolangServer {
 
             let os_str = path.file_name().unwrap();
 
+            let parse_cache = self.global_cache.lock().await.parse_cache.clone();
+            resolver.set_parse_cache(parse_cache);
+
             let mut ns = parse_and_resolve(os_str, &mut resolver, self.target);
 
+            let hashes = namespace_hashes(&ns, &resolver);
+
             // codegen all the contracts; some additional errors/warnings will be detected here
             codegen(&mut ns, &Default::default());
 
@@ -311,20 +353,70 @@ impl SolangServer {
 
             let (file_caches, global_cache) = Builder::new(&ns).build();
 
-            let mut files = self.files.lock().await;
-            for (f, c) in ns.files.iter().zip(file_caches.into_iter()) {
-                if f.cache_no.is_some() {
-                    files.caches.insert(f.path.clone(), c);
-                }
-            }
+            let parse_errors = has_parse_errors(&ns);
+
+            self.files
+                .lock()
+                .await
+                .update_caches(&ns, file_caches, parse_errors);
 
             let mut gc = self.global_cache.lock().await;
             gc.extend(global_cache);
 
+            // A file which does not parse has no imports, so keep the dependencies from the last
+            // time it did parse. Otherwise, the imports may have changed so replace them.
+            let top_path = &ns.files[ns.top_file_no()].path;
+            let analyzed = gc.analyzed.entry(path).or_default();
+
+            if parse_errors && !analyzed.is_empty() {
+                analyzed.extend(
+                    hashes
+                        .into_iter()
+                        .filter(|(file_path, _)| file_path == top_path),
+                );
+            } else {
+                *analyzed = hashes;
+            }
+
             res.await;
         }
     }
 
+    /// Re-run semantic analysis for the files affected by a change to the given file: the file
+    /// itself and the files which import it, directly or indirectly. Files whose namespace has
+    /// the same contents as the last time it was analysed are not analysed again.
+    async fn reanalyze(&self, uri: Url) {
+        let path = match uri.to_file_path() {
+            Ok(path) => path,
+            Err(_) => {
+                self.client
+                    .log_message(MessageType::ERROR, format!("received invalid URI: {uri}"))
+                    .await;
+                return;
+            }
+        };
+
+        let affected = {
+            let files = self.files.lock().await;
+            let gc = self.global_cache.lock().await;
+
+            affected_files(&gc.analyzed, &path, |file_path| {
+                match files.text_buffers.get(file_path) {
+                    Some(contents) => Some(content_hash(contents)),
+                    None => std::fs::read_to_string(file_path)
+                        .ok()
+                        .map(|contents| content_hash(&contents)),
+                }
+            })
+        };
+
+        for file_path in affected {
+            if let Ok(uri) = Url::from_file_path(&file_path) {
+                self.parse_file(uri).await;
+            }
+        }
+    }
+
     /// Common code for goto_{definitions, implementations, declarations, type_definitions}
     async fn get_reference_from_params(
         &self,
@@ -801,6 +893,7 @@ impl<'a> Builder<'a> {
                 ty,
                 left,
                 right,
+                ..
             } => {
                 self.hovers.push((
                     loc.file_no(),
@@ -2006,6 +2099,8 @@ impl<'a> Builder<'a> {
             declarations: self.declarations,
             implementations: self.implementations,
             properties: self.properties,
+            analyzed: HashMap::new(),
+            parse_cache: ParseCache::default(),
         };
 
         (file_caches, global_cache)
@@ -2096,6 +2191,7 @@ impl LanguageServer for SolangServer {
                 references_provider: Some(OneOf::Left(true)),
                 rename_provider: Some(OneOf::Left(true)),
                 document_formatting_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
                 ..ServerCapabilities::default()
             },
         })
@@ -2152,7 +2248,7 @@ impl LanguageServer for SolangServer {
                     .await
                     .text_buffers
                     .insert(path, params.text_document.text);
-                self.parse_file(uri).await;
+                self.reanalyze(uri).await;
             }
             Err(_) => {
                 self.client
@@ -2173,7 +2269,7 @@ impl LanguageServer for SolangServer {
                         .into_iter()
                         .fold(text_buf.clone(), update_file_contents);
                 }
-                self.parse_file(uri).await;
+                self.reanalyze(uri).await;
             }
             Err(_) => {
                 self.client
@@ -2194,7 +2290,7 @@ impl LanguageServer for SolangServer {
             }
         }
 
-        self.parse_file(uri).await;
+        self.reanalyze(uri).await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
@@ -2204,6 +2300,7 @@ impl LanguageServer for SolangServer {
             let mut files = self.files.lock().await;
             files.caches.remove(&path);
             files.text_buffers.remove(&path);
+            self.global_cache.lock().await.analyzed.remove(&path);
         }
 
         self.client.publish_diagnostics(uri, vec![], None).await;
@@ -2675,6 +2772,8 @@ impl LanguageServer for SolangServer {
     ///
     /// ### Edge cases
     /// * Returns `Err` when an invalid file path is received.
+    /// * Returns `Err` when a local variable or parameter would clash with a builtin or with another
+    ///   symbol in scope after renaming.
     /// * Returns `Ok(None)` when the definition of code object is not found in user code.
     async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
         // fetch the `DefinitionIndex` of the code object in question
@@ -2690,9 +2789,18 @@ impl LanguageServer for SolangServer {
         // the new name of the code object
         let new_text = params.new_name;
 
+        let caches = &self.files.lock().await.caches;
+
+        if let Some(message) = rename_conflict(caches, &reference, &new_text) {
+            return Err(Error {
+                code: ErrorCode::InvalidParams,
+                message: message.into(),
+                data: None,
+            });
+        }
+
         // create `TextEdit` instances that represent the changes to be made for every occurrence of the old symbol
         // these `TextEdit` objects are then grouped into separate list per source file to which they belong
-        let caches = &self.files.lock().await.caches;
         let ws = caches
             .iter()
             .map(|(p, cache)| {
@@ -2783,6 +2891,330 @@ impl LanguageServer for SolangServer {
 
         Ok(Some(vec![text_edit]))
     }
+
+    /// Called when the client requests the outline of a file.
+    ///
+    /// Returns the contracts, interfaces and libraries defined in the file, with their functions,
+    /// modifiers, events, state variables and structs nested below them. Items defined at file scope,
+    /// like free functions, are returned at the top level.
+    ///
+    /// ### Arguments
+    /// * `DocumentSymbolParams` provides the path of the file for which the outline is requested.
+    ///
+    /// ### Edge cases
+    /// * Returns `Err` when
+    ///     * an invalid file path is received.
+    ///     * reading the file fails.
+    /// * Returns `Ok(None)` when the file does not parse.
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let uri = params.text_document.uri;
+        let path = uri.to_file_path().map_err(|_| Error {
+            code: ErrorCode::InvalidRequest,
+            message: format!("Received invalid URI: {uri}").into(),
+            data: None,
+        })?;
+
+        // use the contents of the editor if the file is open, since it may not be saved yet
+        let buffer = self.files.lock().await.text_buffers.get(&path).cloned();
+
+        let source = match buffer {
+            Some(source) => source,
+            None => std::fs::read_to_string(&path).map_err(|err| Error {
+                code: ErrorCode::InternalError,
+                message: format!("Failed to read file: {uri}").into(),
+                data: Some(Value::String(format!("{:?}", err))),
+            })?,
+        };
+
+        let Ok((source_unit, _)) = solang_parser::parse(&source, 0) else {
+            return Ok(None);
+        };
+
+        let file = ast::File::new(path, &source, 0, None);
+
+        let symbols = source_unit
+            .0
+            .iter()
+            .filter_map(|part| source_unit_part_symbol(part, &file))
+            .collect();
+
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
+}
+
+/// Create the outline entry for an item defined at file scope
+fn source_unit_part_symbol(part: &pt::SourceUnitPart, file: &ast::File) -> Option<DocumentSymbol> {
+    match part {
+        pt::SourceUnitPart::ContractDefinition(contract) => {
+            let kind = match contract.ty {
+                pt::ContractTy::Interface(_) => SymbolKind::INTERFACE,
+                pt::ContractTy::Library(_) => SymbolKind::MODULE,
+                _ => SymbolKind::CLASS,
+            };
+
+            let children = contract
+                .parts
+                .iter()
+                .filter_map(|part| contract_part_symbol(part, file))
+                .collect();
+
+            let name = contract.name.as_ref()?;
+
+            Some(make_document_symbol(
+                &name.name,
+                kind,
+                &contract.loc,
+                &name.loc,
+                Some(children),
+                file,
+            ))
+        }
+        pt::SourceUnitPart::FunctionDefinition(func) => function_symbol(func, file),
+        pt::SourceUnitPart::StructDefinition(def) => struct_symbol(def, file),
+        pt::SourceUnitPart::EnumDefinition(def) => enum_symbol(def, file),
+        pt::SourceUnitPart::EventDefinition(def) => event_symbol(def, file),
+        pt::SourceUnitPart::VariableDefinition(def) => {
+            variable_symbol(def, SymbolKind::CONSTANT, file)
+        }
+        _ => None,
+    }
+}
+
+/// Create the outline entry for an item defined in a contract
+fn contract_part_symbol(part: &pt::ContractPart, file: &ast::File) -> Option<DocumentSymbol> {
+    match part {
+        pt::ContractPart::FunctionDefinition(func) => function_symbol(func, file),
+        pt::ContractPart::StructDefinition(def) => struct_symbol(def, file),
+        pt::ContractPart::EnumDefinition(def) => enum_symbol(def, file),
+        pt::ContractPart::EventDefinition(def) => event_symbol(def, file),
+        pt::ContractPart::VariableDefinition(def) => variable_symbol(def, SymbolKind::FIELD, file),
+        _ => None,
+    }
+}
+
+fn function_symbol(func: &pt::FunctionDefinition, file: &ast::File) -> Option<DocumentSymbol> {
+    // constructors, fallback and receive functions have no name
+    let (name, selection_loc) = match &func.name {
+        Some(name) => (name.name.as_str(), &name.loc),
+        None => (func.ty.as_str(), &func.loc_prototype),
+    };
+
+    let kind = match func.ty {
+        pt::FunctionTy::Constructor => SymbolKind::CONSTRUCTOR,
+        _ => SymbolKind::FUNCTION,
+    };
+
+    Some(make_document_symbol(
+        name,
+        kind,
+        &func.loc,
+        selection_loc,
+        None,
+        file,
+    ))
+}
+
+fn struct_symbol(def: &pt::StructDefinition, file: &ast::File) -> Option<DocumentSymbol> {
+    let name = def.name.as_ref()?;
+
+    let fields = def
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let id = field.name.as_ref()?;
+
+            Some(make_document_symbol(
+                &id.name,
+                SymbolKind::FIELD,
+                &field.loc,
+                &id.loc,
+                None,
+                file,
+            ))
+        })
+        .collect();
+
+    Some(make_document_symbol(
+        &name.name,
+        SymbolKind::STRUCT,
+        &def.loc,
+        &name.loc,
+        Some(fields),
+        file,
+    ))
+}
+
+fn enum_symbol(def: &pt::EnumDefinition, file: &ast::File) -> Option<DocumentSymbol> {
+    let name = def.name.as_ref()?;
+
+    let values = def
+        .values
+        .iter()
+        .flatten()
+        .map(|id| {
+            make_document_symbol(
+                &id.name,
+                SymbolKind::ENUM_MEMBER,
+                &id.loc,
+                &id.loc,
+                None,
+                file,
+            )
+        })
+        .collect();
+
+    Some(make_document_symbol(
+        &name.name,
+        SymbolKind::ENUM,
+        &def.loc,
+        &name.loc,
+        Some(values),
+        file,
+    ))
+}
+
+fn event_symbol(def: &pt::EventDefinition, file: &ast::File) -> Option<DocumentSymbol> {
+    let name = def.name.as_ref()?;
+
+    Some(make_document_symbol(
+        &name.name,
+        SymbolKind::EVENT,
+        &def.loc,
+        &name.loc,
+        None,
+        file,
+    ))
+}
+
+fn variable_symbol(
+    def: &pt::VariableDefinition,
+    kind: SymbolKind,
+    file: &ast::File,
+) -> Option<DocumentSymbol> {
+    let name = def.name.as_ref()?;
+
+    Some(make_document_symbol(
+        &name.name, kind, &def.loc, &name.loc, None, file,
+    ))
+}
+
+// `DocumentSymbol::deprecated` is deprecated in favour of `tags`, but the field must still be set
+#[allow(deprecated)]
+fn make_document_symbol(
+    name: &str,
+    kind: SymbolKind,
+    loc: &pt::Loc,
+    selection_loc: &pt::Loc,
+    children: Option<Vec<DocumentSymbol>>,
+    file: &ast::File,
+) -> DocumentSymbol {
+    DocumentSymbol {
+        name: name.to_string(),
+        detail: None,
+        kind,
+        tags: None,
+        deprecated: None,
+        range: loc_to_range(loc, file),
+        selection_range: loc_to_range(selection_loc, file),
+        children,
+    }
+}
+
+/// Does the top file of the namespace have parser errors, i.e. no symbols could be resolved
+fn has_parse_errors(ns: &ast::Namespace) -> bool {
+    ns.diagnostics.iter().any(|diag| {
+        diag.ty == ast::ErrorType::ParserError && diag.loc.try_file_no() == Some(ns.top_file_no())
+    })
+}
+
+fn content_hash(contents: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// The content hash of every source file in the namespace
+fn namespace_hashes(ns: &ast::Namespace, resolver: &FileResolver) -> HashMap<PathBuf, u64> {
+    ns.files
+        .iter()
+        .filter_map(|file| {
+            let contents = resolver.get_contents_of_file_no(file.cache_no?)?;
+
+            Some((file.path.clone(), content_hash(&contents)))
+        })
+        .collect()
+}
+
+/// The files which should be analysed again after the given file changed. These are the file
+/// itself and the analysed files which have it in their namespace, unless none of the files in
+/// their namespace changed since they were last analysed.
+fn affected_files(
+    analyzed: &Analyzed,
+    path: &Path,
+    current_hash: impl Fn(&Path) -> Option<u64>,
+) -> Vec<PathBuf> {
+    let mut affected = Vec::new();
+
+    if !analyzed.contains_key(path) {
+        affected.push(path.to_path_buf());
+    }
+
+    for (file_path, hashes) in analyzed {
+        if !hashes.contains_key(path) {
+            continue;
+        }
+
+        if hashes
+            .iter()
+            .any(|(dep, hash)| current_hash(dep) != Some(*hash))
+        {
+            affected.push(file_path.clone());
+        }
+    }
+
+    // analyse the changed file first, so its diagnostics are published first
+    affected.sort_by_key(|file_path| file_path != path);
+
+    affected
+}
+
+/// Check whether renaming a variable to `new_name` would clash with another declaration.
+/// Local variables and parameters are only referenced from within their function, so the scopes
+/// enclosing those references are all the places where a new name could clash. Like
+/// `Namespace::check_shadowing`, the new name must not be a builtin, and must not be declared in
+/// any enclosing scope (which includes contract members) or at file level.
+fn rename_conflict(
+    caches: &HashMap<PathBuf, FileCache>,
+    reference: &DefinitionIndex,
+    new_name: &str,
+) -> Option<String> {
+    if !matches!(reference.def_type, DefinitionType::Variable(_)) {
+        return None;
+    }
+
+    if is_reserved(new_name) {
+        return Some(format!("'{new_name}' is the name of a builtin"));
+    }
+
+    let cache = caches.get(&reference.def_path)?;
+
+    let clash = cache
+        .references
+        .iter()
+        .filter(|r| r.val == *reference)
+        .flat_map(|r| cache.scopes.find(r.start, r.stop))
+        .flat_map(|scope| scope.val.iter())
+        .chain(cache.top_level_code_objects.iter())
+        .any(|(name, _)| name == new_name);
+
+    if clash {
+        Some(format!("'{new_name}' is already declared in this scope"))
+    } else {
+        None
+    }
 }
 
 /// Calculate the line and column from the Loc offset received from the parser
@@ -2901,6 +3333,149 @@ fn update_file_contents(
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::ffi::OsStr;
+
+    #[test]
+    fn parse_errors_keep_cache() {
+        let analyze = |src: &str| {
+            let mut resolver = FileResolver::default();
+            resolver.set_file_contents("test.sol", src.into());
+            parse_and_resolve(OsStr::new("test.sol"), &mut resolver, Target::EVM)
+        };
+
+        let mut files = Files::default();
+
+        let ns = analyze("contract c { function f() public { int x; x += 1; } }");
+        assert!(!has_parse_errors(&ns));
+
+        let (file_caches, _) = Builder::new(&ns).build();
+        files.update_caches(&ns, file_caches, false);
+
+        let path = ns.files[ns.top_file_no()].path.clone();
+        let hovers = files.caches[&path].hovers.len();
+        let references = files.caches[&path].references.len();
+        assert!(hovers > 0);
+        assert!(references > 0);
+
+        // the user is typing on a new line, so the file does not parse
+        let ns = analyze("contract c { function f() public { int x;\n x. } }");
+        assert!(has_parse_errors(&ns));
+
+        let (file_caches, _) = Builder::new(&ns).build();
+        files.update_caches(&ns, file_caches, true);
+
+        // the symbols from before the edit are kept, but the line offsets are updated
+        let cache = &files.caches[&path];
+        assert!(cache.top_level_code_objects.contains_key("c"));
+        assert_eq!(cache.hovers.len(), hovers);
+        assert_eq!(cache.references.len(), references);
+        assert_eq!(cache.file.line_starts.len(), 1);
+
+        // once the file parses again, the cache is replaced
+        let ns = analyze("contract d {}");
+        assert!(!has_parse_errors(&ns));
+
+        let (file_caches, _) = Builder::new(&ns).build();
+        files.update_caches(&ns, file_caches, false);
+
+        let cache = &files.caches[&path];
+        assert!(cache.top_level_code_objects.contains_key("d"));
+        assert!(!cache.top_level_code_objects.contains_key("c"));
+    }
+
+    #[test]
+    fn reanalyze_affected_files() {
+        let mut resolver = FileResolver::default();
+        resolver.set_file_contents("lib.sol", "struct S { int f; }".into());
+        resolver.set_file_contents("main.sol", "import \"lib.sol\"; contract c { S s; }".into());
+        resolver.set_file_contents("other.sol", "contract d {}".into());
+
+        let mut analyzed = Analyzed::new();
+        let mut paths = Vec::new();
+
+        for name in ["main.sol", "other.sol"] {
+            let ns = parse_and_resolve(OsStr::new(name), &mut resolver, Target::EVM);
+            let path = ns.files[ns.top_file_no()].path.clone();
+            analyzed.insert(path.clone(), namespace_hashes(&ns, &resolver));
+            paths.push(path);
+        }
+
+        let main = paths[0].clone();
+        let lib = analyzed[&main]
+            .keys()
+            .find(|path| **path != main)
+            .unwrap()
+            .clone();
+
+        let current: HashMap<PathBuf, u64> = analyzed
+            .values()
+            .flatten()
+            .map(|(path, hash)| (path.clone(), *hash))
+            .collect();
+        let unchanged = |path: &Path| current.get(path).copied();
+
+        // nothing changed
+        assert!(affected_files(&analyzed, &lib, unchanged).is_empty());
+
+        // the importing file must be analysed again, but not the unrelated file
+        let changed = |path: &Path| {
+            if path == lib {
+                Some(content_hash("struct S { int f; int g; }"))
+            } else {
+                unchanged(path)
+            }
+        };
+        assert_eq!(affected_files(&analyzed, &lib, changed), vec![main.clone()]);
+
+        // a file which was never analysed is analysed
+        let new = PathBuf::from("new.sol");
+        assert_eq!(affected_files(&analyzed, &new, unchanged), vec![new]);
+
+        // once the import is removed, changes to lib.sol no longer affect main.sol
+        resolver.set_file_contents("main.sol", "contract c { }".into());
+        let ns = parse_and_resolve(OsStr::new("main.sol"), &mut resolver, Target::EVM);
+        analyzed.insert(main, namespace_hashes(&ns, &resolver));
+        assert!(affected_files(&analyzed, &lib, changed).is_empty());
+    }
+
+    #[test]
+    fn rename_local_conflicts() {
+        let src =
+            "contract c { int y; function f(int a) public returns (int) { int b = a; return b; } }";
+        let mut resolver = FileResolver::default();
+        resolver.set_file_contents("rename.sol", src.into());
+
+        let ns = parse_and_resolve(OsStr::new("rename.sol"), &mut resolver, Target::EVM);
+        let (file_caches, _) = Builder::new(&ns).build();
+
+        let caches: HashMap<PathBuf, FileCache> = ns
+            .files
+            .iter()
+            .map(|f| f.path.clone())
+            .zip(file_caches)
+            .collect();
+
+        let path = &ns.files[ns.top_file_no()].path;
+        let offset = src.find("a)").unwrap();
+        let reference = caches[path]
+            .references
+            .find(offset, offset + 1)
+            .next()
+            .unwrap()
+            .val
+            .clone();
+        assert!(matches!(reference.def_type, DefinitionType::Variable(_)));
+
+        // local variable, state variable, function, contract and builtin
+        for name in ["b", "y", "f", "c", "require"] {
+            assert!(
+                rename_conflict(&caches, &reference, name).is_some(),
+                "{name}"
+            );
+        }
+
+        assert_eq!(rename_conflict(&caches, &reference, "z"), None);
+    }
 
     #[test]
     fn without_range() {
@@ -3,15 +3,19 @@
 use clap::{Command, CommandFactory, FromArgMatches};
 
 use clap_complete::generate;
-use cli::PackageTrait;
 use itertools::Itertools;
+use serde_json::json;
+use sha2::{Digest, Sha256};
 use solang::{
     abi,
     codegen::{codegen, Options},
     emit::Generate,
     file_resolver::FileResolver,
-    sema::{ast::Namespace, file::PathDisplay},
-    standard_json::{EwasmContract, JsonContract, JsonResult},
+    sema::{
+        ast::{self, Namespace},
+        file::PathDisplay,
+    },
+    standard_json::{EwasmContract, JsonContract, JsonInput, JsonResult},
 };
 use std::{
     collections::{HashMap, HashSet},
@@ -20,11 +24,21 @@ use std::{
     io::prelude::*,
     path::{Path, PathBuf},
     process::exit,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::span;
+use tracing_subscriber::{
+    filter::Targets,
+    fmt::format::FmtSpan,
+    layer::{Context, Layer},
+    prelude::*,
+    registry::LookupSpan,
 };
 
 use crate::cli::{
-    imports_arg, options_arg, target_arg, Cli, Commands, Compile, CompilerOutput, Doc, New,
-    ShellComplete,
+    imports_arg, input_files, options_arg, target_arg, Cli, Commands, Compile, CompilerOutput, Doc,
+    New, ShellComplete,
 };
 
 mod cli;
@@ -42,7 +56,7 @@ fn main() {
         Commands::Doc(doc_args) => doc(doc_args),
         Commands::Compile(compile_args) => {
             // Read config from configuration file. If extra args exist, only overwrite the fields that the user explicitly provides.
-            let config = if let Some(conf_file) = &compile_args.configuration_file {
+            let mut config = if let Some(conf_file) = &compile_args.configuration_file {
                 if PathBuf::from(conf_file).exists() {
                     eprintln!("info: reading default config from toml file");
                     let debug = matches.subcommand_matches("compile").unwrap();
@@ -56,6 +70,12 @@ fn main() {
             } else {
                 compile_args
             };
+
+            // standard json input implies standard json output
+            if config.package.standard_json_input.is_some() {
+                config.compiler_output.std_json_output = true;
+            }
+
             compile(&config)
         }
         Commands::ShellComplete(shell_args) => shell_complete(Cli::command(), shell_args),
@@ -128,7 +148,7 @@ fn doc(doc_args: Doc) {
     let mut success = true;
     let mut files = Vec::new();
 
-    for filename in doc_args.package.input {
+    for filename in input_files(&doc_args.package) {
         let ns = solang::parse_and_resolve(filename.as_os_str(), &mut resolver, target);
 
         ns.print_diagnostics(&resolver, verbose);
@@ -158,6 +178,13 @@ fn doc(doc_args: Doc) {
 fn compile(compile_args: &Compile) {
     let target = target_arg(&compile_args.target_arg);
 
+    if compile_args.compiler_output.asm_flavor.is_some()
+        && compile_args.compiler_output.emit.as_deref() != Some("asm")
+    {
+        eprintln!("error: --asm-flavor can only be used with --emit asm");
+        exit(1);
+    }
+
     let mut json = JsonResult {
         errors: Vec::new(),
         target: target.to_string(),
@@ -171,7 +198,51 @@ fn compile(compile_args: &Compile) {
 
     let mut resolver = imports_arg(&compile_args.package);
 
-    let opt = options_arg(&compile_args.debug_features, &compile_args.optimizations);
+    let inputs: Vec<PathBuf> = match &compile_args.package.standard_json_input {
+        Some(path) => standard_json_input(path, &mut resolver),
+        None => input_files(&compile_args.package)
+            .into_iter()
+            .map(|filename| filename.canonicalize().unwrap_or_else(|_| filename.clone()))
+            .collect(),
+    };
+
+    let mut opt = options_arg(&compile_args.debug_features, &compile_args.optimizations);
+
+    // the annotations of the assembly come from the debug locations
+    if compile_args.compiler_output.asm_flavor.as_deref() == Some("annotated") {
+        opt.generate_debug_information = true;
+    }
+
+    opt.max_cyclomatic_complexity = compile_args
+        .compiler_output
+        .max_cyclomatic_complexity
+        .map(|max| max as usize);
+    opt.max_call_depth = compile_args
+        .compiler_output
+        .max_call_depth
+        .map(|max| max as usize);
+
+    let pass_times = init_tracing(
+        compile_args.compiler_output.trace_resolution,
+        compile_args.compiler_output.time_passes,
+    );
+
+    let stop_after = compile_args.compiler_output.stop_after.as_deref();
+
+    if stop_after == Some("parse") {
+        let mut success = true;
+
+        for filename in &inputs {
+            success &= parse_file(
+                filename,
+                &mut resolver,
+                target,
+                compile_args.compiler_output.verbose,
+            );
+        }
+
+        finish(&pass_times, if success { 0 } else { 1 });
+    }
 
     let mut namespaces = Vec::new();
 
@@ -184,7 +255,7 @@ fn compile(compile_args: &Compile) {
         HashSet::new()
     };
 
-    for filename in compile_args.package.get_input() {
+    for filename in &inputs {
         // TODO: this could be parallelized using e.g. rayon
         let ns = process_file(
             filename,
@@ -197,7 +268,8 @@ fn compile(compile_args: &Compile) {
         namespaces.push(ns);
     }
 
-    let mut json_contracts = HashMap::new();
+    let mut artifacts = Vec::new();
+    let mut cfg_json = Vec::new();
 
     let std_json = compile_args.compiler_output.std_json_output;
 
@@ -214,8 +286,18 @@ fn compile(compile_args: &Compile) {
         }
     }
 
-    if let Some("ast-dot") = compile_args.compiler_output.emit.as_deref() {
-        exit(0);
+    if let Some("ast-dot" | "ast-json" | "call-graph" | "deps") =
+        compile_args.compiler_output.emit.as_deref()
+    {
+        finish(&pass_times, 0);
+    }
+
+    if stop_after == Some("resolve") {
+        for ns in &namespaces {
+            print_summary(ns);
+        }
+
+        finish(&pass_times, if errors { 1 } else { 0 });
     }
 
     // Ensure we have at least one contract
@@ -262,6 +344,8 @@ fn compile(compile_args: &Compile) {
         };
 
         for ns in &mut namespaces {
+            let mut json_contracts = HashMap::new();
+
             for contract_no in 0..ns.contracts.len() {
                 contract_results(
                     contract_no,
@@ -269,22 +353,39 @@ fn compile(compile_args: &Compile) {
                     ns,
                     &mut json_contracts,
                     &mut seen_contracts,
+                    &mut artifacts,
+                    &mut cfg_json,
+                    &resolver,
                     &opt,
                     &authors,
                     version,
                 );
             }
+
+            if !json_contracts.is_empty() {
+                let path = &ns.files[ns.top_file_no()].path;
+
+                json.contracts
+                    .insert(path.display().to_string(), json_contracts);
+            }
+        }
+
+        if let Some(report) = &compile_args.compiler_output.report_json {
+            write_report(Path::new(report), &artifacts);
+        }
+
+        // all the contracts of all the files are printed as a single JSON array
+        if let Some("cfg-json") = compile_args.compiler_output.emit.as_deref() {
+            println!("{}", serde_json::to_string_pretty(&cfg_json).unwrap());
         }
     }
 
     if std_json {
         println!("{}", serde_json::to_string(&json).unwrap());
-        exit(0);
+        finish(&pass_times, 0);
     }
 
-    if errors {
-        exit(1);
-    }
+    finish(&pass_times, if errors { 1 } else { 0 });
 }
 
 fn shell_complete(mut app: Command, args: ShellComplete) {
@@ -313,19 +414,30 @@ fn process_file(
 ) -> Namespace {
     let verbose = compiler_output.verbose;
 
-    let filepath = match filename.canonicalize() {
-        Ok(filename) => filename,
-        Err(_) => filename.to_path_buf(),
-    };
-
     // resolve phase
-    let mut ns = solang::parse_and_resolve(filepath.as_os_str(), resolver, target);
+    let max_errors = compiler_output.max_errors.map(|max| max as usize);
+    let mut ns = solang::parse_and_resolve_with_max_errors(
+        filename.as_os_str(),
+        resolver,
+        target,
+        max_errors,
+    );
+
+    if compiler_output.warn_empty_catch {
+        ns.check_empty_catch();
+    }
+
+    if compiler_output.no_warn_tx_origin {
+        ns.allow_tx_origin();
+    }
 
     // codegen all the contracts; some additional errors/warnings will be detected here
-    codegen(&mut ns, opt);
+    if compiler_output.stop_after.as_deref() != Some("resolve") {
+        codegen(&mut ns, opt);
+    }
 
     if let Some("ast-dot") = compiler_output.emit.as_deref() {
-        let stem = filepath.file_stem().unwrap().to_string_lossy();
+        let stem = filename.file_stem().unwrap().to_string_lossy();
         let dot_filename = output_file(compiler_output, &stem, "dot", false);
 
         if verbose {
@@ -342,15 +454,299 @@ fn process_file(
         }
     }
 
+    if let Some("ast-json") = compiler_output.emit.as_deref() {
+        let stem = filename.file_stem().unwrap().to_string_lossy();
+        let json_filename = output_file(compiler_output, &stem, "ast.json", false);
+
+        if verbose {
+            eprintln!("info: Saving AST {}", json_filename.display());
+        }
+
+        let json = serde_json::to_string_pretty(&ns.ast_to_json()).unwrap();
+
+        let mut file = create_file(&json_filename);
+
+        if let Err(err) = file.write_all(json.as_bytes()) {
+            eprintln!("{}: error: {}", json_filename.display(), err);
+            exit(1);
+        }
+    }
+
+    if let Some("deps") = compiler_output.emit.as_deref() {
+        write_depfile(filename, &ns, compiler_output);
+    }
+
+    if let Some("call-graph") = compiler_output.emit.as_deref() {
+        let stem = filename.file_stem().unwrap().to_string_lossy();
+        let json_filename = output_file(compiler_output, &stem, "callgraph.json", false);
+
+        if verbose {
+            eprintln!("info: Saving call graph {}", json_filename.display());
+        }
+
+        let json = serde_json::to_string_pretty(&ns.call_graph()).unwrap();
+
+        let mut file = create_file(&json_filename);
+
+        if let Err(err) = file.write_all(json.as_bytes()) {
+            eprintln!("{}: error: {}", json_filename.display(), err);
+            exit(1);
+        }
+    }
+
     ns
 }
 
+/// Install the tracing subscriber. With `trace_resolution`, the spans and events of semantic
+/// analysis are printed to stderr. With `time_passes`, the time spent in each compiler pass is
+/// collected, so it can be printed once compilation is done.
+fn init_tracing(trace_resolution: bool, time_passes: bool) -> Option<PassTimes> {
+    if !trace_resolution && !time_passes {
+        return None;
+    }
+
+    let trace = trace_resolution.then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_span_events(FmtSpan::NEW)
+            .without_time()
+            .with_filter(Targets::new().with_target("solang::sema", tracing::Level::TRACE))
+    });
+
+    let pass_times = time_passes.then(PassTimes::default);
+
+    let timer = pass_times.clone().map(|pass_times| {
+        pass_times.with_filter(Targets::new().with_target("solang::passes", tracing::Level::INFO))
+    });
+
+    tracing_subscriber::registry()
+        .with(trace)
+        .with(timer)
+        .init();
+
+    pass_times
+}
+
+/// Print the time spent in each pass, if requested, and exit
+fn finish(pass_times: &Option<PassTimes>, code: i32) -> ! {
+    if let Some(pass_times) = pass_times {
+        pass_times.print();
+    }
+
+    exit(code);
+}
+
+/// The time spent in each compiler pass, in the order in which the passes first ran
+#[derive(Clone, Default)]
+struct PassTimes(Arc<Mutex<Vec<(&'static str, Duration)>>>);
+
+/// Stored in the span of a pass while it runs
+struct PassTimer {
+    start: Instant,
+    nested: Duration,
+}
+
+impl PassTimes {
+    fn print(&self) {
+        let times = self.0.lock().unwrap();
+
+        eprintln!("info: time spent per pass:");
+
+        for (name, time) in times.iter() {
+            eprintln!("{:>12.3} ms  {name}", time.as_secs_f64() * 1000.0);
+        }
+
+        let total: Duration = times.iter().map(|(_, time)| *time).sum();
+
+        eprintln!("{:>12.3} ms  total", total.as_secs_f64() * 1000.0);
+    }
+}
+
+impl<S> Layer<S> for PassTimes
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let mut times = self.0.lock().unwrap();
+
+            if !times.iter().any(|(name, _)| *name == span.name()) {
+                times.push((span.name(), Duration::ZERO));
+            }
+
+            span.extensions_mut().insert(PassTimer {
+                start: Instant::now(),
+                nested: Duration::ZERO,
+            });
+        }
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        let span = match ctx.span(id) {
+            Some(span) => span,
+            None => return,
+        };
+
+        let timer = match span.extensions_mut().remove::<PassTimer>() {
+            Some(timer) => timer,
+            None => return,
+        };
+
+        let elapsed = timer.start.elapsed();
+
+        // the time spent in a nested pass only counts for the nested pass
+        if let Some(parent) = span.parent() {
+            if let Some(parent_timer) = parent.extensions_mut().get_mut::<PassTimer>() {
+                parent_timer.nested += elapsed;
+            }
+        }
+
+        let elapsed = elapsed.saturating_sub(timer.nested);
+        let mut times = self.0.lock().unwrap();
+
+        if let Some((_, time)) = times.iter_mut().find(|(name, _)| *name == span.name()) {
+            *time += elapsed;
+        }
+    }
+}
+
+/// Write a Makefile style depfile, which lists the source files that the contracts in the
+/// file were built from, including all the imported files.
+fn write_depfile(filename: &Path, ns: &Namespace, compiler_output: &CompilerOutput) {
+    let escape = |path: &Path| path.display().to_string().replace(' ', "\\ ");
+
+    let targets = ns
+        .contracts
+        .iter()
+        .filter(|contract| {
+            contract.instantiable && contract.loc.try_file_no() == Some(ns.top_file_no())
+        })
+        .map(|contract| {
+            let path = output_file(
+                compiler_output,
+                &contract.id.name,
+                ns.target.file_extension(),
+                false,
+            );
+
+            escape(&path)
+        })
+        .collect::<Vec<String>>();
+
+    if targets.is_empty() {
+        return;
+    }
+
+    let sources = ns
+        .source_files()
+        .into_iter()
+        .map(escape)
+        .collect::<Vec<String>>();
+
+    let stem = filename.file_stem().unwrap().to_string_lossy();
+    let dep_filename = output_file(compiler_output, &stem, "d", false);
+
+    if compiler_output.verbose {
+        eprintln!("info: Saving dependencies {}", dep_filename.display());
+    }
+
+    let mut file = create_file(&dep_filename);
+
+    if let Err(err) = writeln!(file, "{}: {}", targets.join(" "), sources.join(" ")) {
+        eprintln!("{}: error: {}", dep_filename.display(), err);
+        exit(1);
+    }
+}
+
+/// Read a Solidity standard JSON input file, and add its sources and remappings to the
+/// resolver. Returns the source files which should be compiled.
+fn standard_json_input(path: &Path, resolver: &mut FileResolver) -> Vec<PathBuf> {
+    let input: JsonInput = match fs::read_to_string(path)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| serde_json::from_str(&contents).map_err(|err| err.to_string()))
+    {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}: error: {}", path.display(), err);
+            exit(1);
+        }
+    };
+
+    if let Err(err) = input.add_to_resolver(resolver) {
+        eprintln!("{}: error: {}", path.display(), err);
+        exit(1);
+    }
+
+    input.sources.keys().map(PathBuf::from).collect()
+}
+
+/// Only parse the file and print the parse tree, for `--stop-after parse`. Imports are not
+/// followed. Returns false if the file could not be parsed.
+fn parse_file(
+    filename: &Path,
+    resolver: &mut FileResolver,
+    target: solang::Target,
+    verbose: bool,
+) -> bool {
+    let mut ns = Namespace::new(target);
+
+    let file = match resolver.resolve_file(None, filename.as_os_str()) {
+        Ok(file) => file,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return false;
+        }
+    };
+
+    let (contents, cache_no) = resolver.get_file_contents_and_number(&file.full_path);
+
+    ns.files.push(ast::File::new(
+        file.full_path.clone(),
+        &contents,
+        cache_no,
+        None,
+    ));
+
+    // the namespace may already have a builtin file, so use the file number of this file
+    match solang_parser::parse(&contents, ns.files.len() - 1) {
+        Ok((tree, _)) => {
+            println!("{tree:#?}");
+            true
+        }
+        Err(mut errors) => {
+            ns.diagnostics.append(&mut errors);
+            ns.print_diagnostics(resolver, verbose);
+            false
+        }
+    }
+}
+
+/// Print what was resolved in the top level file, for `--stop-after resolve`
+fn print_summary(ns: &Namespace) {
+    for contract in &ns.contracts {
+        if contract.loc.try_file_no() != Some(ns.top_file_no()) {
+            continue;
+        }
+
+        println!(
+            "{} {}: {} functions, {} variables",
+            contract.ty,
+            contract.id,
+            contract.functions.len(),
+            contract.variables.len()
+        );
+    }
+}
+
 fn contract_results(
     contract_no: usize,
     compiler_output: &CompilerOutput,
     ns: &mut Namespace,
     json_contracts: &mut HashMap<String, JsonContract>,
     seen_contracts: &mut HashMap<String, String>,
+    artifacts: &mut Vec<(String, PathBuf)>,
+    cfg_json: &mut Vec<serde_json::Value>,
+    resolver: &FileResolver,
     opt: &Options,
     default_authors: &[String],
     version: &str,
@@ -389,6 +785,36 @@ fn contract_results(
         return;
     }
 
+    if let Some("cfg-json") = compiler_output.emit.as_deref() {
+        cfg_json.push(resolved_contract.cfg_to_json(ns));
+        return;
+    }
+
+    if let Some("constructor-abi") = compiler_output.emit.as_deref() {
+        let abi_filename = output_file(
+            compiler_output,
+            &resolved_contract.id.name,
+            "constructor.json",
+            false,
+        );
+
+        if verbose {
+            eprintln!(
+                "info: Saving constructor ABI {} for contract {}",
+                abi_filename.display(),
+                resolved_contract.id
+            );
+        }
+
+        let abi = abi::ethereum::gen_constructor_abi(contract_no, ns);
+
+        let mut file = create_file(&abi_filename);
+        file.write_all(serde_json::to_string(&abi).unwrap().as_bytes())
+            .unwrap();
+        artifacts.push((resolved_contract.id.name.clone(), abi_filename));
+        return;
+    }
+
     if verbose {
         if ns.target == solang::Target::Solana {
             eprintln!(
@@ -407,7 +833,17 @@ fn contract_results(
 
     let binary = resolved_contract.binary(ns, &context, opt, contract_no);
 
-    if save_intermediates(&binary, compiler_output) {
+    // the source is used to annotate the assembly
+    let source = ns.files[resolved_contract.loc.file_no()]
+        .cache_no
+        .and_then(|cache_no| resolver.get_contents_of_file_no(cache_no));
+
+    if save_intermediates(
+        &binary,
+        compiler_output,
+        artifacts,
+        source.as_deref().unwrap_or_default(),
+    ) {
         return;
     }
 
@@ -433,27 +869,57 @@ fn contract_results(
             },
         );
     } else {
-        let bin_filename = output_file(
-            compiler_output,
-            &binary.name,
-            ns.target.file_extension(),
-            false,
-        );
-
-        if verbose {
-            eprintln!(
-                "info: Saving binary {} for contract {}",
-                bin_filename.display(),
-                binary.name
+        // with --emit metadata, only the metadata is written
+        let metadata_only = compiler_output.emit.as_deref() == Some("metadata");
+
+        if !metadata_only {
+            let bin_filename = output_file(
+                compiler_output,
+                &binary.name,
+                ns.target.file_extension(),
+                false,
             );
+
+            if verbose {
+                eprintln!(
+                    "info: Saving binary {} for contract {}",
+                    bin_filename.display(),
+                    binary.name
+                );
+            }
+
+            let mut file = create_file(&bin_filename);
+
+            file.write_all(&code).unwrap();
+            artifacts.push((binary.name.clone(), bin_filename));
         }
 
-        let mut file = create_file(&bin_filename);
+        let abi_format = match compiler_output.abi_format.as_deref() {
+            Some("ethers") => abi::ethereum::AbiFormat::Ethers,
+            Some("human") => abi::ethereum::AbiFormat::Human,
+            _ => abi::ethereum::AbiFormat::Solc,
+        };
+        let (metadata, meta_ext) = abi::generate_abi(
+            contract_no,
+            ns,
+            &code,
+            verbose,
+            default_authors,
+            version,
+            resolver,
+            compiler_output.include_source_in_metadata,
+            abi_format,
+        );
 
-        file.write_all(&code).unwrap();
+        // without an output directory, the metadata is printed
+        if metadata_only
+            && compiler_output.output_directory.is_none()
+            && compiler_output.output_meta.is_none()
+        {
+            println!("{metadata}");
+            return;
+        }
 
-        let (metadata, meta_ext) =
-            abi::generate_abi(contract_no, ns, &code, verbose, default_authors, version);
         let meta_filename = output_file(compiler_output, &binary.name, meta_ext, true);
 
         if verbose {
@@ -466,14 +932,18 @@ fn contract_results(
 
         let mut file = create_file(&meta_filename);
         file.write_all(metadata.as_bytes()).unwrap();
+        artifacts.push((binary.name.clone(), meta_filename));
     }
 }
 
 fn save_intermediates(
     binary: &solang::emit::binary::Binary,
     compiler_output: &CompilerOutput,
+    artifacts: &mut Vec<(String, PathBuf)>,
+    source: &str,
 ) -> bool {
     let verbose = compiler_output.verbose;
+    let flavor = compiler_output.asm_flavor.as_deref();
 
     match compiler_output.emit.as_deref() {
         Some("llvm-ir") => {
@@ -488,6 +958,7 @@ fn save_intermediates(
             }
 
             binary.dump_llvm(&llvm_filename).unwrap();
+            artifacts.push((binary.name.clone(), llvm_filename));
 
             true
         }
@@ -504,6 +975,7 @@ fn save_intermediates(
             }
 
             binary.bitcode(&bc_filename);
+            artifacts.push((binary.name.clone(), bc_filename));
 
             true
         }
@@ -529,6 +1001,55 @@ fn save_intermediates(
 
             let mut file = create_file(&obj_filename);
             file.write_all(&obj).unwrap();
+            artifacts.push((binary.name.clone(), obj_filename));
+            true
+        }
+        Some("asm") if matches!(flavor, Some("wat" | "compact")) => {
+            let wat = match binary.wat(flavor == Some("compact")) {
+                Ok(o) => o,
+                Err(s) => {
+                    println!("error: {s}");
+                    exit(1);
+                }
+            };
+
+            let wat_filename = output_file(compiler_output, &binary.name, "wat", false);
+
+            if verbose {
+                eprintln!(
+                    "info: Saving WebAssembly text {} for contract {}",
+                    wat_filename.display(),
+                    binary.name
+                );
+            }
+
+            let mut file = create_file(&wat_filename);
+            file.write_all(wat.as_bytes()).unwrap();
+            artifacts.push((binary.name.clone(), wat_filename));
+            true
+        }
+        Some("asm") if flavor == Some("annotated") => {
+            let asm = match binary.annotated_assembly(source) {
+                Ok(o) => o,
+                Err(s) => {
+                    println!("error: {s}");
+                    exit(1);
+                }
+            };
+
+            let asm_filename = output_file(compiler_output, &binary.name, "asm", false);
+
+            if verbose {
+                eprintln!(
+                    "info: Saving annotated Assembly {} for contract {}",
+                    asm_filename.display(),
+                    binary.name
+                );
+            }
+
+            let mut file = create_file(&asm_filename);
+            file.write_all(asm.as_bytes()).unwrap();
+            artifacts.push((binary.name.clone(), asm_filename));
             true
         }
         Some("asm") => {
@@ -552,14 +1073,50 @@ fn save_intermediates(
 
             let mut file = create_file(&obj_filename);
             file.write_all(&obj).unwrap();
+            artifacts.push((binary.name.clone(), obj_filename));
             true
         }
         Some("cfg") => true,
+        Some("cfg-json") => true,
         Some("ast-dot") => true,
+        Some("ast-json") => true,
+        Some("constructor-abi") => true,
         _ => false,
     }
 }
 
+/// Write a JSON report of the artifacts which were written, with their size and sha256 hash
+fn write_report(filename: &Path, artifacts: &[(String, PathBuf)]) {
+    let artifacts = artifacts
+        .iter()
+        .map(|(contract, path)| {
+            let contents = match fs::read(path) {
+                Ok(contents) => contents,
+                Err(err) => {
+                    eprintln!("error: cannot read '{}': {}", path.display(), err);
+                    exit(1);
+                }
+            };
+
+            json!({
+                "contract": contract,
+                "path": path.display().to_string(),
+                "size": contents.len(),
+                "sha256": hex::encode(Sha256::digest(&contents)),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let mut file = create_file(filename);
+
+    file.write_all(
+        serde_json::to_string_pretty(&json!({ "artifacts": artifacts }))
+            .unwrap()
+            .as_bytes(),
+    )
+    .unwrap();
+}
+
 fn create_file(path: &Path) -> File {
     if let Some(parent) = path.parent() {
         if let Err(err) = create_dir_all(parent) {
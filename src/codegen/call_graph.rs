@@ -0,0 +1,142 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::cfg::{ASTFunction, ControlFlowGraph, Instr, InternalCallTy};
+use crate::sema::ast::Namespace;
+use serde::Serialize;
+use solang_parser::pt;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Serialize)]
+pub struct CallGraphNode {
+    pub id: String,
+    pub contract: String,
+    pub name: String,
+    pub kind: String,
+}
+
+#[derive(Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CallGraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: &'static str,
+}
+
+#[derive(Serialize)]
+pub struct CallGraph {
+    pub nodes: Vec<CallGraphNode>,
+    pub edges: Vec<CallGraphEdge>,
+}
+
+impl Namespace {
+    /// Build the call graph of all the contracts, from the call instructions in their cfgs. This
+    /// means codegen must have been run. Each node is a function of a contract, identified by
+    /// the contract name and the function signature, e.g. `C.foo(uint256)`. Edges are either
+    /// `internal` calls, `external` calls or `constructor` calls when a contract is created.
+    pub fn call_graph(&self) -> CallGraph {
+        let mut nodes = BTreeMap::new();
+        let mut edges = BTreeSet::new();
+
+        for (contract_no, contract) in self.contracts.iter().enumerate() {
+            for cfg in &contract.cfg {
+                let from = match self.call_graph_node(contract_no, cfg) {
+                    Some(node) => node,
+                    None => continue,
+                };
+
+                for instr in cfg.blocks.iter().flat_map(|block| &block.instr) {
+                    let edge = match instr {
+                        Instr::Call {
+                            call: InternalCallTy::Static { cfg_no },
+                            ..
+                        } => self
+                            .call_graph_node(contract_no, &contract.cfg[*cfg_no])
+                            .map(|to| (to.id, "internal")),
+                        Instr::ExternalCall {
+                            contract_function_no: Some((contract_no, function_no)),
+                            ..
+                        } => Some((
+                            self.call_graph_id(*contract_no, Some(*function_no)),
+                            "external",
+                        )),
+                        Instr::Constructor {
+                            contract_no,
+                            constructor_no,
+                            ..
+                        } => Some((
+                            self.call_graph_id(*contract_no, *constructor_no),
+                            "constructor",
+                        )),
+                        _ => None,
+                    };
+
+                    if let Some((to, kind)) = edge {
+                        edges.insert(CallGraphEdge {
+                            from: from.id.clone(),
+                            to,
+                            kind,
+                        });
+                    }
+                }
+
+                nodes.insert(from.id.clone(), from);
+            }
+        }
+
+        CallGraph {
+            nodes: nodes.into_values().collect(),
+            edges: edges.into_iter().collect(),
+        }
+    }
+
+    /// The node for a cfg; only cfgs for functions and constructors are part of the call graph
+    fn call_graph_node(&self, contract_no: usize, cfg: &ControlFlowGraph) -> Option<CallGraphNode> {
+        let function_no = match cfg.function_no {
+            ASTFunction::SolidityFunction(function_no) => Some(function_no),
+            ASTFunction::None if cfg.ty == pt::FunctionTy::Constructor && !cfg.is_placeholder() => {
+                None
+            }
+            _ => return None,
+        };
+
+        // inherited functions belong to the contract which declares them
+        let declared_in = function_no
+            .and_then(|function_no| self.functions[function_no].contract_no)
+            .unwrap_or(contract_no);
+
+        Some(CallGraphNode {
+            id: self.call_graph_id(contract_no, function_no),
+            contract: self.contracts[declared_in].id.name.clone(),
+            name: function_no
+                .map(|function_no| self.functions[function_no].id.name.clone())
+                .unwrap_or_default(),
+            kind: cfg.ty.to_string(),
+        })
+    }
+
+    /// The identifier of a function in the call graph. If no function is given, this is the
+    /// default constructor of the contract. Free functions are identified by the contract they
+    /// are called from.
+    fn call_graph_id(&self, contract_no: usize, function_no: Option<usize>) -> String {
+        let function_no = match function_no {
+            Some(function_no) => function_no,
+            None => return format!("{}.constructor()", self.contracts[contract_no].id),
+        };
+
+        let func = &self.functions[function_no];
+        let contract = &self.contracts[func.contract_no.unwrap_or(contract_no)].id;
+
+        match func.ty {
+            pt::FunctionTy::Function => format!("{contract}.{}", func.signature),
+            pt::FunctionTy::Constructor => {
+                let params = func
+                    .signature
+                    .find('(')
+                    .map(|pos| &func.signature[pos..])
+                    .unwrap_or("()");
+
+                format!("{contract}.constructor{params}")
+            }
+            _ => format!("{contract}.{}", func.ty),
+        }
+    }
+}
@@ -214,6 +214,41 @@ pub enum ReturnCode {
 }
 
 impl Instr {
+    /// The name of the instruction variant, as used in the JSON output of the cfg
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Instr::Set { .. } => "Set",
+            Instr::Call { .. } => "Call",
+            Instr::Return { .. } => "Return",
+            Instr::Branch { .. } => "Branch",
+            Instr::BranchCond { .. } => "BranchCond",
+            Instr::Store { .. } => "Store",
+            Instr::AssertFailure { .. } => "AssertFailure",
+            Instr::Print { .. } => "Print",
+            Instr::LoadStorage { .. } => "LoadStorage",
+            Instr::ClearStorage { .. } => "ClearStorage",
+            Instr::SetStorage { .. } => "SetStorage",
+            Instr::SetStorageBytes { .. } => "SetStorageBytes",
+            Instr::PushStorage { .. } => "PushStorage",
+            Instr::PopStorage { .. } => "PopStorage",
+            Instr::PushMemory { .. } => "PushMemory",
+            Instr::PopMemory { .. } => "PopMemory",
+            Instr::Constructor { .. } => "Constructor",
+            Instr::ExternalCall { .. } => "ExternalCall",
+            Instr::ValueTransfer { .. } => "ValueTransfer",
+            Instr::SelfDestruct { .. } => "SelfDestruct",
+            Instr::EmitEvent { .. } => "EmitEvent",
+            Instr::WriteBuffer { .. } => "WriteBuffer",
+            Instr::MemCopy { .. } => "MemCopy",
+            Instr::Switch { .. } => "Switch",
+            Instr::Nop => "Nop",
+            Instr::ReturnData { .. } => "ReturnData",
+            Instr::ReturnCode { .. } => "ReturnCode",
+            Instr::Unimplemented { .. } => "Unimplemented",
+            Instr::AccountAccess { .. } => "AccountAccess",
+        }
+    }
+
     pub fn recurse_expressions<T>(
         &self,
         cx: &mut T,
@@ -561,6 +596,42 @@ impl ControlFlowGraph {
         self.current
     }
 
+    /// Calculate the cyclomatic complexity of the cfg, i.e. edges - nodes + 2. Only blocks
+    /// reachable from the entry block are counted. Every block which leaves the function
+    /// gets an edge to a single virtual exit node, so a function without any branches has
+    /// a complexity of 1.
+    pub fn cyclomatic_complexity(&self) -> usize {
+        if self.blocks.is_empty() {
+            return 0;
+        }
+
+        let mut visited = HashSet::new();
+        let mut todo = vec![0];
+        let mut edges = 0;
+        let mut exits = 0;
+
+        while let Some(block_no) = todo.pop() {
+            if !visited.insert(block_no) {
+                continue;
+            }
+
+            let successors = self.blocks[block_no].successors();
+
+            if successors.is_empty() {
+                exits += 1;
+            }
+
+            edges += successors.len();
+
+            todo.extend(successors);
+        }
+
+        // the virtual exit node adds one node and one edge per exiting block
+        let nodes = visited.len() + usize::from(exits > 0);
+
+        (edges + exits + 2).saturating_sub(nodes)
+    }
+
     /// Function to modify array length temp by inserting an add/sub instruction in the cfg right after a push/pop instruction.
     /// The operands of the add/sub instruction are the temp variable, and +/- 1.
     pub fn modify_temp_array_length(
@@ -1555,10 +1626,13 @@ pub fn optimize_and_check_cfg(
     func_no: ASTFunction,
     opt: &Options,
 ) {
-    reaching_definitions::find(cfg);
+    tracing::info_span!(target: "solang::passes", "reaching_definitions")
+        .in_scope(|| reaching_definitions::find(cfg));
     if func_no != ASTFunction::None {
         // If there are undefined variables, we raise an error and don't run optimizations
-        if undefined_variable::find_undefined_variables(cfg, ns, func_no) {
+        if tracing::info_span!(target: "solang::passes", "undefined_variable")
+            .in_scope(|| undefined_variable::find_undefined_variables(cfg, ns, func_no))
+        {
             return;
         }
     }
@@ -1566,20 +1640,25 @@ pub fn optimize_and_check_cfg(
     // constant folding generates diagnostics, so always run it. This means that the diagnostics
     // do not depend which passes are enabled. If the constant_folding is not enabled, run it
     // dry mode.
-    constant_folding::constant_folding(cfg, !opt.constant_folding, ns);
+    tracing::info_span!(target: "solang::passes", "constant_folding")
+        .in_scope(|| constant_folding::constant_folding(cfg, !opt.constant_folding, ns));
     if opt.vector_to_slice {
-        vector_to_slice::vector_to_slice(cfg, ns);
+        tracing::info_span!(target: "solang::passes", "vector_to_slice")
+            .in_scope(|| vector_to_slice::vector_to_slice(cfg, ns));
     }
     if opt.strength_reduce {
-        strength_reduce::strength_reduce(cfg, ns);
+        tracing::info_span!(target: "solang::passes", "strength_reduce")
+            .in_scope(|| strength_reduce::strength_reduce(cfg, ns));
     }
     if opt.dead_storage {
-        dead_storage::dead_storage(cfg, ns);
+        tracing::info_span!(target: "solang::passes", "dead_storage")
+            .in_scope(|| dead_storage::dead_storage(cfg, ns));
     }
 
     // If the function is a default constructor, there is nothing to optimize.
     if opt.common_subexpression_elimination && func_no != ASTFunction::None {
-        common_sub_expression_elimination(cfg, ns);
+        tracing::info_span!(target: "solang::passes", "common_subexpression_elimination")
+            .in_scope(|| common_sub_expression_elimination(cfg, ns));
     }
 }
 
@@ -0,0 +1,648 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::cfg::{ControlFlowGraph, Instr, InternalCallTy};
+use super::Expression;
+use crate::sema::ast::{
+    Contract, ExternalCallAccounts, Namespace, Parameter, StringLocation, Type,
+};
+use serde_json::{json, Value};
+
+impl Contract {
+    /// Serialize the CFGs of the contract to JSON. Every function lists its variables and basic
+    /// blocks; every instruction is an object with its kind and operands. Variables are referred
+    /// to by number, expressions are trees of objects, and branch targets are block numbers.
+    pub fn cfg_to_json(&self, ns: &Namespace) -> Value {
+        let params = |params: &[Parameter<Type>]| {
+            params
+                .iter()
+                .map(|p| {
+                    json!({
+                        "name": p.name_as_str(),
+                        "type": p.ty.to_string(ns),
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let functions = self
+            .cfg
+            .iter()
+            .filter(|cfg| !cfg.is_placeholder())
+            .map(|cfg| {
+                let vars = cfg
+                    .vars
+                    .iter()
+                    .map(|(var_no, var)| {
+                        json!({
+                            "var_no": var_no,
+                            "name": var.id.name,
+                            "ty": var.ty.to_string(ns),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                let blocks = cfg
+                    .blocks
+                    .iter()
+                    .enumerate()
+                    .map(|(block_no, block)| {
+                        let instructions = block
+                            .instr
+                            .iter()
+                            .map(|instr| cfg.instr_to_json(ns, instr))
+                            .collect::<Vec<_>>();
+
+                        json!({
+                            "id": block_no,
+                            "name": block.name,
+                            "instructions": instructions,
+                            "successors": block.successors(),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                json!({
+                    "name": cfg.name,
+                    "ty": cfg.ty.to_string(),
+                    "public": cfg.public,
+                    "selector": hex::encode(&cfg.selector),
+                    "nonpayable": cfg.nonpayable,
+                    "params": params(&cfg.params),
+                    "returns": params(&cfg.returns),
+                    "vars": vars,
+                    "blocks": blocks,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "contract": self.id.name,
+            "functions": functions,
+        })
+    }
+}
+
+impl ControlFlowGraph {
+    /// Serialize an instruction to a JSON object with its kind and operands
+    pub fn instr_to_json(&self, ns: &Namespace, instr: &Instr) -> Value {
+        let expr = |expr: &Expression| self.expr_to_json(ns, expr);
+        let exprs = |exprs: &[Expression]| exprs.iter().map(expr).collect::<Vec<_>>();
+        let opt_expr = |e: &Option<Expression>| e.as_ref().map(expr);
+        let accounts = |accounts: &ExternalCallAccounts<Expression>| match accounts {
+            ExternalCallAccounts::NoAccount => json!("NoAccount"),
+            ExternalCallAccounts::AbsentArgument => json!("AbsentArgument"),
+            ExternalCallAccounts::Present(accounts) => expr(accounts),
+        };
+
+        let value = match instr {
+            Instr::Set { res, expr: e, .. } => json!({
+                "res": res,
+                "expr": expr(e),
+            }),
+            Instr::Call {
+                res,
+                return_tys,
+                call,
+                args,
+            } => {
+                let call = match call {
+                    InternalCallTy::Static { cfg_no } => json!({
+                        "kind": "Static",
+                        "cfg_no": cfg_no,
+                    }),
+                    InternalCallTy::Dynamic(e) => json!({
+                        "kind": "Dynamic",
+                        "expr": expr(e),
+                    }),
+                    InternalCallTy::Builtin { ast_func_no } => json!({
+                        "kind": "Builtin",
+                        "function_no": ast_func_no,
+                    }),
+                };
+
+                json!({
+                    "res": res,
+                    "return_tys": return_tys.iter().map(|ty| ty.to_string(ns)).collect::<Vec<_>>(),
+                    "call": call,
+                    "args": exprs(args),
+                })
+            }
+            Instr::Return { value } => json!({
+                "value": exprs(value),
+            }),
+            Instr::Branch { block } => json!({
+                "block": block,
+            }),
+            Instr::BranchCond {
+                cond,
+                true_block,
+                false_block,
+            } => json!({
+                "cond": expr(cond),
+                "true_block": true_block,
+                "false_block": false_block,
+            }),
+            Instr::Store { dest, data } => json!({
+                "dest": expr(dest),
+                "data": expr(data),
+            }),
+            Instr::AssertFailure { encoded_args } => json!({
+                "encoded_args": opt_expr(encoded_args),
+            }),
+            Instr::Print { expr: e } => json!({
+                "expr": expr(e),
+            }),
+            Instr::LoadStorage { res, ty, storage } => json!({
+                "res": res,
+                "ty": ty.to_string(ns),
+                "storage": expr(storage),
+            }),
+            Instr::ClearStorage { ty, storage } => json!({
+                "ty": ty.to_string(ns),
+                "storage": expr(storage),
+            }),
+            Instr::SetStorage { ty, value, storage } => json!({
+                "ty": ty.to_string(ns),
+                "value": expr(value),
+                "storage": expr(storage),
+            }),
+            Instr::SetStorageBytes {
+                value,
+                storage,
+                offset,
+            } => json!({
+                "value": expr(value),
+                "storage": expr(storage),
+                "offset": expr(offset),
+            }),
+            Instr::PushStorage {
+                res,
+                ty,
+                value,
+                storage,
+            } => json!({
+                "res": res,
+                "ty": ty.to_string(ns),
+                "value": opt_expr(value),
+                "storage": expr(storage),
+            }),
+            Instr::PopStorage { res, ty, storage } => json!({
+                "res": res,
+                "ty": ty.to_string(ns),
+                "storage": expr(storage),
+            }),
+            Instr::PushMemory {
+                res,
+                ty,
+                array,
+                value,
+            } => json!({
+                "res": res,
+                "ty": ty.to_string(ns),
+                "array": array,
+                "value": expr(value),
+            }),
+            Instr::PopMemory { res, ty, array, .. } => json!({
+                "res": res,
+                "ty": ty.to_string(ns),
+                "array": array,
+            }),
+            Instr::Constructor {
+                success,
+                res,
+                contract_no,
+                constructor_no,
+                encoded_args,
+                value,
+                gas,
+                salt,
+                address,
+                seeds,
+                accounts: constructor_accounts,
+                ..
+            } => json!({
+                "success": success,
+                "res": res,
+                "contract_no": contract_no,
+                "constructor_no": constructor_no,
+                "encoded_args": expr(encoded_args),
+                "value": opt_expr(value),
+                "gas": expr(gas),
+                "salt": opt_expr(salt),
+                "address": opt_expr(address),
+                "seeds": opt_expr(seeds),
+                "accounts": accounts(constructor_accounts),
+            }),
+            Instr::ExternalCall {
+                success,
+                address,
+                accounts: call_accounts,
+                seeds,
+                payload,
+                value,
+                gas,
+                callty,
+                contract_function_no,
+                flags,
+                ..
+            } => json!({
+                "success": success,
+                "address": opt_expr(address),
+                "accounts": accounts(call_accounts),
+                "seeds": opt_expr(seeds),
+                "payload": expr(payload),
+                "value": expr(value),
+                "gas": expr(gas),
+                "callty": callty.to_string(),
+                "contract_function_no": contract_function_no,
+                "flags": opt_expr(flags),
+            }),
+            Instr::ValueTransfer {
+                success,
+                address,
+                value,
+            } => json!({
+                "success": success,
+                "address": expr(address),
+                "value": expr(value),
+            }),
+            Instr::SelfDestruct { recipient } => json!({
+                "recipient": expr(recipient),
+            }),
+            Instr::EmitEvent {
+                event_no,
+                data,
+                topics,
+            } => json!({
+                "event_no": event_no,
+                "data": expr(data),
+                "topics": exprs(topics),
+            }),
+            Instr::WriteBuffer { buf, offset, value } => json!({
+                "buf": expr(buf),
+                "offset": expr(offset),
+                "value": expr(value),
+            }),
+            Instr::MemCopy {
+                source,
+                destination,
+                bytes,
+            } => json!({
+                "source": expr(source),
+                "destination": expr(destination),
+                "bytes": expr(bytes),
+            }),
+            Instr::Switch {
+                cond,
+                cases,
+                default,
+            } => json!({
+                "cond": expr(cond),
+                "cases": cases
+                    .iter()
+                    .map(|(value, block)| json!({ "value": expr(value), "block": block }))
+                    .collect::<Vec<_>>(),
+                "default": default,
+            }),
+            Instr::Nop => json!({}),
+            Instr::ReturnData { data, data_len } => json!({
+                "data": expr(data),
+                "data_len": expr(data_len),
+            }),
+            Instr::ReturnCode { code } => json!({
+                "code": code.to_string(),
+            }),
+            Instr::Unimplemented { reachable } => json!({
+                "reachable": reachable,
+            }),
+            Instr::AccountAccess { var_no, name, .. } => json!({
+                "var_no": var_no,
+                "name": name,
+            }),
+        };
+
+        with_kind(instr.kind(), value)
+    }
+
+    /// Serialize an expression to a JSON object with its kind and operands
+    pub fn expr_to_json(&self, ns: &Namespace, expr: &Expression) -> Value {
+        let e = |expr: &Expression| self.expr_to_json(ns, expr);
+        let exprs = |exprs: &[Expression]| exprs.iter().map(e).collect::<Vec<_>>();
+        let string_location = |location: &StringLocation<Expression>| match location {
+            StringLocation::CompileTime(bs) => json!({ "bytes": hex::encode(bs) }),
+            StringLocation::RunTime(expr) => e(expr),
+        };
+
+        let value = match expr {
+            Expression::Add {
+                ty,
+                overflowing,
+                left,
+                right,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "overflowing": overflowing,
+                "left": e(left),
+                "right": e(right),
+            }),
+            Expression::Subtract {
+                ty,
+                overflowing,
+                left,
+                right,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "overflowing": overflowing,
+                "left": e(left),
+                "right": e(right),
+            }),
+            Expression::Multiply {
+                ty,
+                overflowing,
+                left,
+                right,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "overflowing": overflowing,
+                "left": e(left),
+                "right": e(right),
+            }),
+            Expression::SignedDivide {
+                ty,
+                overflowing,
+                left,
+                right,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "overflowing": overflowing,
+                "left": e(left),
+                "right": e(right),
+            }),
+            Expression::Power {
+                ty,
+                overflowing,
+                base,
+                exp,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "overflowing": overflowing,
+                "base": e(base),
+                "exp": e(exp),
+            }),
+            Expression::BitwiseAnd {
+                ty, left, right, ..
+            }
+            | Expression::BitwiseOr {
+                ty, left, right, ..
+            }
+            | Expression::BitwiseXor {
+                ty, left, right, ..
+            }
+            | Expression::UnsignedDivide {
+                ty, left, right, ..
+            }
+            | Expression::UnsignedModulo {
+                ty, left, right, ..
+            }
+            | Expression::SignedModulo {
+                ty, left, right, ..
+            }
+            | Expression::ShiftLeft {
+                ty, left, right, ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "left": e(left),
+                "right": e(right),
+            }),
+            Expression::ShiftRight {
+                ty,
+                left,
+                right,
+                signed,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "signed": signed,
+                "left": e(left),
+                "right": e(right),
+            }),
+            Expression::Equal { left, right, .. } | Expression::NotEqual { left, right, .. } => {
+                json!({
+                    "left": e(left),
+                    "right": e(right),
+                })
+            }
+            Expression::Less {
+                signed,
+                left,
+                right,
+                ..
+            }
+            | Expression::LessEqual {
+                signed,
+                left,
+                right,
+                ..
+            }
+            | Expression::More {
+                signed,
+                left,
+                right,
+                ..
+            }
+            | Expression::MoreEqual {
+                signed,
+                left,
+                right,
+                ..
+            } => json!({
+                "signed": signed,
+                "left": e(left),
+                "right": e(right),
+            }),
+            Expression::Not { expr: inner, .. } => json!({
+                "expr": e(inner),
+            }),
+            Expression::Negate {
+                ty,
+                overflowing,
+                expr: inner,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "overflowing": overflowing,
+                "expr": e(inner),
+            }),
+            Expression::BitwiseNot {
+                ty, expr: inner, ..
+            }
+            | Expression::Cast {
+                ty, expr: inner, ..
+            }
+            | Expression::GetRef {
+                ty, expr: inner, ..
+            }
+            | Expression::Load {
+                ty, expr: inner, ..
+            }
+            | Expression::SignExt {
+                ty, expr: inner, ..
+            }
+            | Expression::Trunc {
+                ty, expr: inner, ..
+            }
+            | Expression::ZeroExt {
+                ty, expr: inner, ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "expr": e(inner),
+            }),
+            Expression::BytesCast {
+                ty,
+                from,
+                expr: inner,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "from": from.to_string(ns),
+                "expr": e(inner),
+            }),
+            Expression::AllocDynamicBytes {
+                ty,
+                size,
+                initializer,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "size": e(size),
+                "initializer": initializer.as_ref().map(hex::encode),
+            }),
+            Expression::ArrayLiteral {
+                ty,
+                dimensions,
+                values,
+                ..
+            }
+            | Expression::ConstArrayLiteral {
+                ty,
+                dimensions,
+                values,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "dimensions": dimensions,
+                "values": exprs(values),
+            }),
+            Expression::StructLiteral { ty, values, .. } => json!({
+                "ty": ty.to_string(ns),
+                "values": exprs(values),
+            }),
+            Expression::BoolLiteral { value, .. } => json!({
+                "value": value,
+            }),
+            Expression::BytesLiteral { ty, value, .. } => json!({
+                "ty": ty.to_string(ns),
+                "value": hex::encode(value),
+            }),
+            Expression::NumberLiteral { ty, value, .. } => json!({
+                "ty": ty.to_string(ns),
+                "value": value.to_string(),
+            }),
+            Expression::RationalNumberLiteral { ty, rational, .. } => json!({
+                "ty": ty.to_string(ns),
+                "value": rational.to_string(),
+            }),
+            Expression::Builtin {
+                tys, kind, args, ..
+            } => json!({
+                "tys": tys.iter().map(|ty| ty.to_string(ns)).collect::<Vec<_>>(),
+                "builtin": format!("{kind:?}"),
+                "args": exprs(args),
+            }),
+            Expression::Keccak256 { ty, exprs: e2, .. } => json!({
+                "ty": ty.to_string(ns),
+                "exprs": exprs(e2),
+            }),
+            Expression::FormatString { args, .. } => json!({
+                "args": args
+                    .iter()
+                    .map(|(spec, arg)| json!({ "spec": spec.to_string(), "expr": e(arg) }))
+                    .collect::<Vec<_>>(),
+            }),
+            Expression::FunctionArg { ty, arg_no, .. } => json!({
+                "ty": ty.to_string(ns),
+                "arg_no": arg_no,
+            }),
+            Expression::InternalFunctionCfg { ty, cfg_no } => json!({
+                "ty": ty.to_string(ns),
+                "cfg_no": cfg_no,
+            }),
+            Expression::Variable { ty, var_no, .. } => json!({
+                "ty": ty.to_string(ns),
+                "var_no": var_no,
+            }),
+            Expression::StorageArrayLength {
+                ty, array, elem_ty, ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "elem_ty": elem_ty.to_string(ns),
+                "array": e(array),
+            }),
+            Expression::StringCompare { left, right, .. } => json!({
+                "left": string_location(left),
+                "right": string_location(right),
+            }),
+            Expression::StructMember {
+                ty,
+                expr: inner,
+                member,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "member": member,
+                "expr": e(inner),
+            }),
+            Expression::Subscript {
+                ty,
+                array_ty,
+                expr: inner,
+                index,
+                ..
+            } => json!({
+                "ty": ty.to_string(ns),
+                "array_ty": array_ty.to_string(ns),
+                "expr": e(inner),
+                "index": e(index),
+            }),
+            Expression::AdvancePointer {
+                pointer,
+                bytes_offset,
+            } => json!({
+                "pointer": e(pointer),
+                "bytes_offset": e(bytes_offset),
+            }),
+            Expression::Undefined { ty } => json!({
+                "ty": ty.to_string(ns),
+            }),
+            Expression::ReturnData { .. } => json!({}),
+            Expression::Poison => json!({}),
+        };
+
+        with_kind(expr.kind(), value)
+    }
+}
+
+/// Add the kind to the JSON object of an instruction or expression
+fn with_kind(kind: &str, mut value: Value) -> Value {
+    value
+        .as_object_mut()
+        .unwrap()
+        .insert("kind".into(), kind.into());
+
+    value
+}
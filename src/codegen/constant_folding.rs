@@ -2,17 +2,18 @@
 
 use super::cfg::{ControlFlowGraph, Instr};
 use super::reaching_definitions;
-use crate::codegen::{Builtin, Expression};
+use crate::codegen::{encoding::create_encoder, Builtin, Expression};
 use crate::sema::{
-    ast::{Diagnostic, Namespace, RetrieveType, StringLocation, Type},
+    ast::{ArrayLength, Diagnostic, Namespace, RetrieveType, StringLocation, Type},
     eval::overflow_diagnostic,
 };
 use num_bigint::{BigInt, Sign};
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{One, ToPrimitive, Zero};
 use ripemd::Ripemd160;
 use sha2::{Digest, Sha256};
 use solang_parser::pt;
 use solang_parser::pt::Loc;
+use std::cmp::Ordering;
 use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Rem, Shl, Shr, Sub};
 use tiny_keccak::{Hasher, Keccak};
 
@@ -28,12 +29,29 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
         for instr_no in 0..cfg.blocks[block_no].instr.len() {
             match &cfg.blocks[block_no].instr[instr_no] {
                 Instr::Set { loc, res, expr, .. } => {
-                    let (expr, expr_constant) = expression(expr, Some(&vars), cfg, ns);
+                    let (mut expr, expr_constant) = expression(expr, Some(&vars), cfg, ns);
 
                     if expr_constant {
                         ns.var_constants.insert(*loc, expr.clone());
                     }
 
+                    if !dry_run && is_constant_array_literal(&expr) && array_only_read(*res, cfg) {
+                        if let Expression::ArrayLiteral {
+                            loc,
+                            ty,
+                            dimensions,
+                            values,
+                        } = expr
+                        {
+                            expr = Expression::ConstArrayLiteral {
+                                loc,
+                                ty,
+                                dimensions,
+                                values,
+                            };
+                        }
+                    }
+
                     if !dry_run {
                         cfg.blocks[block_no].instr[instr_no] = Instr::Set {
                             loc: *loc,
@@ -80,7 +98,18 @@ pub fn constant_folding(cfg: &mut ControlFlowGraph, dry_run: bool, ns: &mut Name
                     let (cond, _) = expression(cond, Some(&vars), cfg, ns);
 
                     if !dry_run {
-                        if let Expression::BoolLiteral { value: cond, .. } = cond {
+                        if let Expression::BoolLiteral { loc, value: cond } = cond {
+                            // only report conditions which are in the source code
+                            if loc.try_file_no().is_some() {
+                                ns.diagnostics.push(Diagnostic::debug(
+                                    loc,
+                                    format!(
+                                        "condition is always {cond}, {} branch eliminated",
+                                        !cond
+                                    ),
+                                ));
+                            }
+
                             cfg.blocks[block_no].instr[instr_no] = Instr::Branch {
                                 block: if cond { *true_block } else { *false_block },
                             };
@@ -499,13 +528,14 @@ fn expression(
             ty,
             left,
             right,
-        } => divide(loc, ty, left, right, vars, cfg, ns, false),
+        } => divide(loc, ty, false, left, right, vars, cfg, ns, false),
         Expression::SignedDivide {
             loc,
             ty,
+            overflowing,
             left,
             right,
-        } => divide(loc, ty, left, right, vars, cfg, ns, true),
+        } => divide(loc, ty, *overflowing, left, right, vars, cfg, ns, true),
         Expression::SignedModulo {
             loc,
             ty,
@@ -766,6 +796,8 @@ fn add(
     ) = (&left.0, &right.0)
     {
         bigint_to_expression(loc, ty, left.add(right), overflowing, ns)
+    } else if let Some(expr) = reassociate(loc, ty, overflowing, &left.0, &right.0, false) {
+        (expr, left.1 && right.1)
     } else {
         (
             Expression::Add {
@@ -799,6 +831,8 @@ fn subtract(
     ) = (&left.0, &right.0)
     {
         bigint_to_expression(loc, ty, left.sub(right), overflowing, ns)
+    } else if let Some(expr) = reassociate(loc, ty, overflowing, &left.0, &right.0, true) {
+        (expr, left.1 && right.1)
     } else {
         (
             Expression::Subtract {
@@ -813,6 +847,114 @@ fn subtract(
     }
 }
 
+/// Fold the constants of `(x + c1) + c2` into `x + c3`, and likewise when either operation
+/// is a subtraction. With checked arithmetic this is only done when both constants have the
+/// same sign, so that the folded expression overflows exactly when the original one does.
+fn reassociate(
+    loc: &pt::Loc,
+    ty: &Type,
+    overflowing: bool,
+    left: &Expression,
+    right: &Expression,
+    subtract: bool,
+) -> Option<Expression> {
+    let outer = match right {
+        Expression::NumberLiteral { value, .. } if subtract => -value,
+        Expression::NumberLiteral { value, .. } => value.clone(),
+        _ => return None,
+    };
+
+    let (inner_overflowing, var, inner) = match left {
+        Expression::Add {
+            ty: inner_ty,
+            overflowing,
+            left,
+            right,
+            ..
+        } if inner_ty == ty => match (left.as_ref(), right.as_ref()) {
+            (var, Expression::NumberLiteral { value, .. })
+            | (Expression::NumberLiteral { value, .. }, var) => (*overflowing, var, value.clone()),
+            _ => return None,
+        },
+        Expression::Subtract {
+            ty: inner_ty,
+            overflowing,
+            left,
+            right,
+            ..
+        } if inner_ty == ty => match right.as_ref() {
+            Expression::NumberLiteral { value, .. } => (*overflowing, left.as_ref(), -value),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    if inner_overflowing != overflowing {
+        return None;
+    }
+
+    let total = &inner + &outer;
+    let fill = if total.sign() == Sign::Minus { 0xff } else { 0 };
+
+    let (value, subtract) = match ty {
+        // wrapping arithmetic, so the constant can simply be truncated to the type
+        Type::Uint(bits) if overflowing => {
+            let mut bs = total.to_signed_bytes_le();
+            bs.resize(*bits as usize / 8, fill);
+
+            (BigInt::from_bytes_le(Sign::Plus, &bs), false)
+        }
+        Type::Int(bits) if overflowing => {
+            let mut bs = total.to_signed_bytes_le();
+            bs.resize(*bits as usize / 8, fill);
+
+            (BigInt::from_signed_bytes_le(&bs), false)
+        }
+        _ if !inner.is_zero() && !outer.is_zero() && inner.sign() != outer.sign() => return None,
+        Type::Uint(bits) if total.bits() <= *bits as u64 => {
+            if total.sign() == Sign::Minus {
+                (-total, true)
+            } else {
+                (total, false)
+            }
+        }
+        Type::Int(bits) if total.bits() < *bits as u64 => {
+            if total.sign() == Sign::Minus {
+                (-total, true)
+            } else {
+                (total, false)
+            }
+        }
+        _ => return None,
+    };
+
+    let right = Expression::NumberLiteral {
+        loc: *loc,
+        ty: ty.clone(),
+        value,
+    };
+
+    let expr = if subtract {
+        Expression::Subtract {
+            loc: *loc,
+            ty: ty.clone(),
+            overflowing,
+            left: Box::new(var.clone()),
+            right: Box::new(right),
+        }
+    } else {
+        Expression::Add {
+            loc: *loc,
+            ty: ty.clone(),
+            overflowing,
+            left: Box::new(var.clone()),
+            right: Box::new(right),
+        }
+    };
+
+    Some(expr)
+}
+
 fn advance_pointer(
     pointer: &Expression,
     offset: &Expression,
@@ -1089,6 +1231,7 @@ fn power(
 fn divide(
     loc: &pt::Loc,
     ty: &Type,
+    overflowing: bool,
     left: &Expression,
     right: &Expression,
     vars: Option<&reaching_definitions::VarDefs>,
@@ -1104,6 +1247,23 @@ fn divide(
             ns.diagnostics
                 .push(Diagnostic::error(*loc, String::from("divide by zero")));
         } else if let Expression::NumberLiteral { value: left, .. } = &left.0 {
+            if let Type::Int(bits) = ty {
+                // the only signed division which overflows is the minimum value divided by -1;
+                // like SDIV, this wraps around to the minimum value again
+                let min = -(BigInt::one() << (*bits as usize - 1));
+
+                if signed && *left == min && *right == BigInt::from(-1) {
+                    if !overflowing {
+                        ns.diagnostics.push(Diagnostic::error(
+                            *loc,
+                            format!("division of {left} by -1 overflows type int{bits}"),
+                        ));
+                    }
+
+                    return bigint_to_expression(loc, ty, min, true, ns);
+                }
+            }
+
             return bigint_to_expression(loc, ty, left.div(right), false, ns);
         }
     }
@@ -1112,6 +1272,7 @@ fn divide(
             Expression::SignedDivide {
                 loc: *loc,
                 ty: ty.clone(),
+                overflowing,
                 left: Box::new(left.0),
                 right: Box::new(right.0),
             }
@@ -1360,63 +1521,77 @@ fn builtin_hash(
     cfg: &ControlFlowGraph,
     ns: &mut Namespace,
 ) -> (Expression, bool) {
-    let arg = expression(&args[0], vars, cfg, ns);
+    let args = args
+        .iter()
+        .map(|arg| expression(arg, vars, cfg, ns).0)
+        .collect::<Vec<Expression>>();
 
-    if let Expression::AllocDynamicBytes {
-        initializer: Some(bs),
-        ..
-    } = arg.0
-    {
-        let hash: Vec<u8> = match kind {
-            Builtin::Keccak256 => {
-                let mut hasher = Keccak::v256();
-                hasher.update(&bs);
-                let mut hash = [0u8; 32];
-                hasher.finalize(&mut hash);
-                hash.to_vec()
-            }
-            Builtin::Ripemd160 => {
-                let mut hasher = Ripemd160::new();
-                hasher.update(&bs);
-                let result = hasher.finalize();
-                result[..].to_vec()
-            }
-            Builtin::Blake2_256 => {
-                let hash = blake2_rfc::blake2b::blake2b(32, &[], &bs);
-                hash.as_bytes().to_vec()
-            }
-            Builtin::Blake2_128 => {
-                let hash = blake2_rfc::blake2b::blake2b(16, &[], &bs);
-                hash.as_bytes().to_vec()
-            }
-            Builtin::Sha256 => {
-                let mut hasher = Sha256::new();
-                hasher.update(&bs);
-                let result = hasher.finalize();
-                result[..].to_vec()
-            }
-            _ => unreachable!("not a hash"),
-        };
+    // the arguments are hashed as if they were packed encoded
+    let mut bs = Vec::new();
 
-        (
-            Expression::BytesLiteral {
-                loc: *loc,
-                ty: tys[0].clone(),
-                value: hash,
-            },
-            true,
-        )
-    } else {
-        (
-            Expression::Builtin {
-                loc: *loc,
-                tys: tys.to_owned(),
-                kind,
-                args: vec![arg.0],
+    for arg in &args {
+        match arg {
+            Expression::AllocDynamicBytes {
+                initializer: Some(value),
+                ..
+            }
+            | Expression::BytesLiteral { value, .. } => bs.extend_from_slice(value),
+            _ => match create_encoder(ns, true).const_encode(std::slice::from_ref(arg)) {
+                Some(value) => bs.extend_from_slice(&value),
+                None => {
+                    return (
+                        Expression::Builtin {
+                            loc: *loc,
+                            tys: tys.to_owned(),
+                            kind,
+                            args,
+                        },
+                        false,
+                    );
+                }
             },
-            false,
-        )
+        }
     }
+
+    let hash: Vec<u8> = match kind {
+        Builtin::Keccak256 => {
+            let mut hasher = Keccak::v256();
+            hasher.update(&bs);
+            let mut hash = [0u8; 32];
+            hasher.finalize(&mut hash);
+            hash.to_vec()
+        }
+        Builtin::Ripemd160 => {
+            let mut hasher = Ripemd160::new();
+            hasher.update(&bs);
+            let result = hasher.finalize();
+            result[..].to_vec()
+        }
+        Builtin::Blake2_256 => {
+            let hash = blake2_rfc::blake2b::blake2b(32, &[], &bs);
+            hash.as_bytes().to_vec()
+        }
+        Builtin::Blake2_128 => {
+            let hash = blake2_rfc::blake2b::blake2b(16, &[], &bs);
+            hash.as_bytes().to_vec()
+        }
+        Builtin::Sha256 => {
+            let mut hasher = Sha256::new();
+            hasher.update(&bs);
+            let result = hasher.finalize();
+            result[..].to_vec()
+        }
+        _ => unreachable!("not a hash"),
+    };
+
+    (
+        Expression::BytesLiteral {
+            loc: *loc,
+            ty: tys[0].clone(),
+            value: hash,
+        },
+        true,
+    )
 }
 
 fn keccak_256(
@@ -1552,6 +1727,82 @@ fn array_literal(
     )
 }
 
+/// Is this an array literal of which all the elements are literals, so that it can be
+/// emitted as a constant global rather than being built in memory
+fn is_constant_array_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::ArrayLiteral {
+            ty: Type::Array(_, dims),
+            values,
+            ..
+        } => {
+            !values.is_empty()
+                && dims.iter().all(|d| matches!(d, ArrayLength::Fixed(_)))
+                && values.iter().all(|v| match v {
+                    Expression::NumberLiteral { ty, .. } => {
+                        matches!(ty, Type::Int(_) | Type::Uint(_) | Type::Bytes(_))
+                    }
+                    Expression::BoolLiteral { .. } => true,
+                    _ => false,
+                })
+        }
+        _ => false,
+    }
+}
+
+struct ArrayUses {
+    var_no: usize,
+    uses: usize,
+    reads: usize,
+}
+
+/// Check that the array in the given variable is never written to, and that it does not
+/// escape, i.e. every use of the variable is a load of one of its elements. Only then can the
+/// array live in read-only memory.
+fn array_only_read(var_no: usize, cfg: &ControlFlowGraph) -> bool {
+    let mut array_uses = ArrayUses {
+        var_no,
+        uses: 0,
+        reads: 0,
+    };
+
+    for instr in cfg.blocks.iter().flat_map(|block| block.instr.iter()) {
+        if let Instr::PushMemory { array, .. } | Instr::PopMemory { array, .. } = instr {
+            if *array == var_no {
+                return false;
+            }
+        }
+
+        instr.recurse_expressions(&mut array_uses, |expr, array_uses| {
+            match expr {
+                Expression::Variable { var_no, .. } if *var_no == array_uses.var_no => {
+                    array_uses.uses += 1;
+                }
+                Expression::Load { ty, expr, .. }
+                    if !matches!(ty, Type::Array(..)) && is_element_of(expr, array_uses.var_no) =>
+                {
+                    array_uses.reads += 1;
+                }
+                _ => (),
+            }
+            true
+        });
+    }
+
+    array_uses.uses == array_uses.reads
+}
+
+/// Does the expression point to an element of the array in the given variable
+fn is_element_of(expr: &Expression, var_no: usize) -> bool {
+    match expr {
+        Expression::Subscript { expr, .. } => match expr.as_ref() {
+            Expression::Variable { var_no: array, .. } => *array == var_no,
+            expr => is_element_of(expr, var_no),
+        },
+        _ => false,
+    }
+}
+
 fn const_array_literal(
     loc: &pt::Loc,
     ty: &Type,
@@ -1587,6 +1838,26 @@ fn load(
 ) -> (Expression, bool) {
     let (expr, _) = expression(expr, vars, cfg, ns);
 
+    // A struct member is a pointer to the field, so a member of a struct literal is only
+    // folded once it is loaded
+    if let Expression::StructMember {
+        expr: strct,
+        member,
+        ..
+    } = &expr
+    {
+        if let Expression::StructLiteral { values, .. } = strct.as_ref() {
+            if let Some(
+                value @ (Expression::NumberLiteral { .. }
+                | Expression::BoolLiteral { .. }
+                | Expression::BytesLiteral { .. }),
+            ) = values.get(*member)
+            {
+                return (value.clone(), true);
+            }
+        }
+    }
+
     (
         Expression::Load {
             loc: *loc,
@@ -1667,6 +1938,36 @@ fn bytes_cast(
     }
 }
 
+/// Compare two number literals. The values are first brought into the range of their type,
+/// as either signed or unsigned, so that e.g. `-1` and `2**256 - 1` compare as the same `uint256`.
+fn compare_literals(left: &Expression, right: &Expression, signed: bool) -> Option<Ordering> {
+    let value = |expr: &Expression| {
+        let Expression::NumberLiteral { ty, value, .. } = expr else {
+            return None;
+        };
+
+        let bits = match ty {
+            Type::Int(bits) | Type::Uint(bits) => *bits,
+            _ => return Some(value.clone()),
+        };
+
+        let modulus = BigInt::one().shl(bits);
+        let mut value = value % &modulus;
+
+        if value.sign() == Sign::Minus {
+            value += &modulus;
+        }
+
+        if signed && value.bit(bits as u64 - 1) {
+            value -= modulus;
+        }
+
+        Some(value)
+    };
+
+    Some(value(left)?.cmp(&value(right)?))
+}
+
 fn more(
     loc: &pt::Loc,
     left: &Expression,
@@ -1679,6 +1980,16 @@ fn more(
     let left = expression(left, vars, cfg, ns);
     let right = expression(right, vars, cfg, ns);
 
+    if let Some(ordering) = compare_literals(&left.0, &right.0, signed) {
+        return (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: ordering.is_gt(),
+            },
+            true,
+        );
+    }
+
     (
         Expression::More {
             loc: *loc,
@@ -1702,6 +2013,16 @@ fn less(
     let left = expression(left, vars, cfg, ns);
     let right = expression(right, vars, cfg, ns);
 
+    if let Some(ordering) = compare_literals(&left.0, &right.0, signed) {
+        return (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: ordering.is_lt(),
+            },
+            true,
+        );
+    }
+
     (
         Expression::Less {
             loc: *loc,
@@ -1725,6 +2046,16 @@ fn more_equal(
     let left = expression(left, vars, cfg, ns);
     let right = expression(right, vars, cfg, ns);
 
+    if let Some(ordering) = compare_literals(&left.0, &right.0, signed) {
+        return (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: ordering.is_ge(),
+            },
+            true,
+        );
+    }
+
     (
         Expression::MoreEqual {
             loc: *loc,
@@ -1748,6 +2079,16 @@ fn less_equal(
     let left = expression(left, vars, cfg, ns);
     let right = expression(right, vars, cfg, ns);
 
+    if let Some(ordering) = compare_literals(&left.0, &right.0, signed) {
+        return (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: ordering.is_le(),
+            },
+            true,
+        );
+    }
+
     (
         Expression::LessEqual {
             loc: *loc,
@@ -1780,6 +2121,26 @@ fn equal(
             },
             true,
         )
+    } else if let (
+        Expression::BoolLiteral { value: l, .. },
+        Expression::BoolLiteral { value: r, .. },
+    ) = (&left.0, &right.0)
+    {
+        (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: l == r,
+            },
+            true,
+        )
+    } else if let Some(ordering) = compare_literals(&left.0, &right.0, false) {
+        (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: ordering.is_eq(),
+            },
+            true,
+        )
     } else {
         (
             Expression::Equal {
@@ -1813,6 +2174,26 @@ fn not_equal(
             },
             true,
         )
+    } else if let (
+        Expression::BoolLiteral { value: l, .. },
+        Expression::BoolLiteral { value: r, .. },
+    ) = (&left.0, &right.0)
+    {
+        (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: l != r,
+            },
+            true,
+        )
+    } else if let Some(ordering) = compare_literals(&left.0, &right.0, false) {
+        (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: ordering.is_ne(),
+            },
+            true,
+        )
     } else {
         (
             Expression::NotEqual {
@@ -1834,6 +2215,16 @@ fn not(
 ) -> (Expression, bool) {
     let expr = expression(expr, vars, cfg, ns);
 
+    if let Expression::BoolLiteral { value, .. } = expr.0 {
+        return (
+            Expression::BoolLiteral {
+                loc: *loc,
+                value: !value,
+            },
+            true,
+        );
+    }
+
     (
         Expression::Not {
             loc: *loc,
@@ -1941,6 +2332,33 @@ fn string_compare(
             right.clone()
         };
 
+        // Comparing against the empty string only needs to check the length
+        match (&left, &right) {
+            (StringLocation::RunTime(expr), StringLocation::CompileTime(empty))
+            | (StringLocation::CompileTime(empty), StringLocation::RunTime(expr))
+                if empty.is_empty() =>
+            {
+                return (
+                    Expression::Equal {
+                        loc: *loc,
+                        left: Box::new(Expression::Builtin {
+                            loc: *loc,
+                            tys: vec![Type::Uint(32)],
+                            kind: Builtin::ArrayLength,
+                            args: vec![*expr.clone()],
+                        }),
+                        right: Box::new(Expression::NumberLiteral {
+                            loc: *loc,
+                            ty: Type::Uint(32),
+                            value: BigInt::zero(),
+                        }),
+                    },
+                    false,
+                );
+            }
+            _ => (),
+        }
+
         (
             Expression::StringCompare {
                 loc: *loc,
@@ -2063,3 +2481,199 @@ fn builtin(
         false,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        codegen::{cfg::ControlFlowGraph, constant_folding::expression, Expression},
+        sema::ast::{Namespace, Type},
+        Target,
+    };
+    use num_bigint::BigInt;
+    use solang_parser::pt::Loc;
+
+    fn add(ty: Type, left: i64, right: i64) -> Expression {
+        let loc = Loc::Codegen;
+
+        Expression::Add {
+            loc,
+            ty: ty.clone(),
+            overflowing: false,
+            left: Box::new(Expression::NumberLiteral {
+                loc,
+                ty: ty.clone(),
+                value: BigInt::from(left),
+            }),
+            right: Box::new(Expression::NumberLiteral {
+                loc,
+                ty,
+                value: BigInt::from(right),
+            }),
+        }
+    }
+
+    fn binary(
+        build: fn(Loc, Type, Box<Expression>, Box<Expression>) -> Expression,
+        ty: Type,
+        left: BigInt,
+        right: BigInt,
+    ) -> Expression {
+        let loc = Loc::Codegen;
+
+        build(
+            loc,
+            ty.clone(),
+            Box::new(Expression::NumberLiteral {
+                loc,
+                ty: ty.clone(),
+                value: left,
+            }),
+            Box::new(Expression::NumberLiteral {
+                loc,
+                ty,
+                value: right,
+            }),
+        )
+    }
+
+    fn signed_divide(ty: Type, left: BigInt, right: BigInt) -> Expression {
+        binary(
+            |loc, ty, left, right| Expression::SignedDivide {
+                loc,
+                ty,
+                overflowing: false,
+                left,
+                right,
+            },
+            ty,
+            left,
+            right,
+        )
+    }
+
+    fn signed_modulo(ty: Type, left: BigInt, right: BigInt) -> Expression {
+        binary(
+            |loc, ty, left, right| Expression::SignedModulo {
+                loc,
+                ty,
+                left,
+                right,
+            },
+            ty,
+            left,
+            right,
+        )
+    }
+
+    #[test]
+    fn fold_signed_divide_and_modulo() {
+        let mut ns = Namespace::new(Target::EVM);
+        let cfg = ControlFlowGraph::placeholder();
+
+        let min = -(BigInt::from(1) << 255);
+
+        // truncates towards zero, like SDIV and SMOD
+        let (folded, _) = expression(
+            &signed_divide(Type::Int(256), BigInt::from(-7), BigInt::from(3)),
+            None,
+            &cfg,
+            &mut ns,
+        );
+        assert!(
+            matches!(folded, Expression::NumberLiteral { value, .. } if value == BigInt::from(-2))
+        );
+
+        let (folded, _) = expression(
+            &signed_modulo(Type::Int(256), BigInt::from(-7), BigInt::from(3)),
+            None,
+            &cfg,
+            &mut ns,
+        );
+        assert!(
+            matches!(folded, Expression::NumberLiteral { value, .. } if value == BigInt::from(-1))
+        );
+
+        let (folded, _) = expression(
+            &signed_divide(Type::Int(256), min.clone(), BigInt::from(1)),
+            None,
+            &cfg,
+            &mut ns,
+        );
+        assert!(matches!(folded, Expression::NumberLiteral { ref value, .. } if *value == min));
+
+        let (folded, _) = expression(
+            &signed_modulo(Type::Int(256), min.clone(), BigInt::from(-1)),
+            None,
+            &cfg,
+            &mut ns,
+        );
+        assert!(
+            matches!(folded, Expression::NumberLiteral { value, .. } if value == BigInt::from(0))
+        );
+
+        assert!(ns.diagnostics.is_empty());
+
+        // the minimum value divided by -1 overflows and wraps around
+        let (folded, _) = expression(
+            &signed_divide(Type::Int(256), min.clone(), BigInt::from(-1)),
+            None,
+            &cfg,
+            &mut ns,
+        );
+        assert!(matches!(folded, Expression::NumberLiteral { ref value, .. } if *value == min));
+        assert_eq!(ns.diagnostics.len(), 1);
+        assert_eq!(
+            ns.diagnostics.first_error(),
+            format!("division of {min} by -1 overflows type int256")
+        );
+
+        // in unchecked code, it wraps around without a diagnostic
+        let mut ns = Namespace::new(Target::EVM);
+
+        let (folded, _) = expression(
+            &binary(
+                |loc, ty, left, right| Expression::SignedDivide {
+                    loc,
+                    ty,
+                    overflowing: true,
+                    left,
+                    right,
+                },
+                Type::Int(256),
+                min.clone(),
+                BigInt::from(-1),
+            ),
+            None,
+            &cfg,
+            &mut ns,
+        );
+        assert!(matches!(folded, Expression::NumberLiteral { ref value, .. } if *value == min));
+        assert!(ns.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn fold_add() {
+        let mut ns = Namespace::new(Target::EVM);
+        let cfg = ControlFlowGraph::placeholder();
+
+        let (folded, pure) = expression(&add(Type::Int(16), 127, 6), None, &cfg, &mut ns);
+
+        assert!(pure);
+        assert!(
+            matches!(folded, Expression::NumberLiteral { value, .. } if value == BigInt::from(133))
+        );
+        assert!(ns.diagnostics.is_empty());
+
+        // the value does not fit, so it wraps and a diagnostic is given
+        let (folded, pure) = expression(&add(Type::Int(8), 127, 6), None, &cfg, &mut ns);
+
+        assert!(pure);
+        assert!(
+            matches!(folded, Expression::NumberLiteral { value, .. } if value == BigInt::from(-123))
+        );
+        assert_eq!(
+            ns.diagnostics.first_error(),
+            "value 133 does not fit into type int8 (valid range -128..=127)."
+        );
+    }
+}
@@ -6,8 +6,8 @@ use crate::codegen::vartable::Vartable;
 use crate::codegen::{Builtin, Expression};
 use crate::sema::ast::StructType;
 use crate::sema::ast::{Namespace, Type, Type::Uint};
+use num_bigint::Sign;
 use parity_scale_codec::Encode;
-use primitive_types::U256;
 use solang_parser::pt::Loc::Codegen;
 use std::collections::HashMap;
 
@@ -581,7 +581,6 @@ impl AbiEncoding for ScaleEncoding {
         self.packed_encoder
     }
 
-    /// TODO: This is used and tested for error data (Error and Panic) only.
     fn const_encode(&self, args: &[Expression]) -> Option<Vec<u8>> {
         let mut result = vec![];
         for arg in args {
@@ -590,31 +589,47 @@ impl AbiEncoding for ScaleEncoding {
                     initializer: Some(data),
                     ty: Type::String | Type::DynamicBytes,
                     ..
-                } => result.extend_from_slice(&data.encode()),
+                } => {
+                    if self.is_packed() {
+                        result.extend_from_slice(data);
+                    } else {
+                        result.extend_from_slice(&data.encode());
+                    }
+                }
                 Expression::AllocDynamicBytes {
                     initializer: Some(data),
                     ty: Type::Slice(inner),
                     ..
                 } if matches!(**inner, Type::Bytes(1)) => result.extend_from_slice(data),
+                Expression::BytesLiteral {
+                    ty: Type::Bytes(_),
+                    value,
+                    ..
+                } => result.extend_from_slice(value),
+                Expression::BoolLiteral { value, .. } => result.push(u8::from(*value)),
                 Expression::NumberLiteral {
-                    ty: Type::Bytes(4),
+                    ty: Type::Bytes(length),
                     value,
                     ..
                 } => {
                     let bytes = value.to_bytes_be().1;
-                    if bytes.len() < 4 {
-                        let buf = vec![0; 4 - bytes.len()];
-                        result.extend_from_slice(&buf);
+                    let length = *length as usize;
+                    if bytes.len() > length {
+                        return None;
                     }
+                    result.resize(result.len() + length - bytes.len(), 0);
                     result.extend_from_slice(&bytes[..]);
                 }
                 Expression::NumberLiteral {
-                    ty: Type::Uint(256),
+                    ty: Type::Uint(width) | Type::Int(width),
                     value,
                     ..
                 } => {
-                    let bytes = value.to_bytes_be().1;
-                    result.extend_from_slice(&U256::from_big_endian(&bytes).encode()[..]);
+                    // integers are sign or zero extended to the next power of two, like encode_int()
+                    let mut bytes = value.to_signed_bytes_le();
+                    let fill = if value.sign() == Sign::Minus { 0xff } else { 0 };
+                    bytes.resize(width.next_power_of_two() as usize / 8, fill);
+                    result.extend_from_slice(&bytes);
                 }
                 _ => return None,
             }
@@ -689,4 +704,37 @@ mod tests {
             assert_eq!(&encoder.const_encode(&[expr]).unwrap(), &value.encode());
         }
     }
+
+    #[test]
+    fn const_encode_int_bool() {
+        let encoder = ScaleEncoding::new(false);
+        let args = [
+            Expression::NumberLiteral {
+                loc: Default::default(),
+                ty: Type::Uint(8),
+                value: 1.into(),
+            },
+            Expression::BoolLiteral {
+                loc: Default::default(),
+                value: true,
+            },
+            Expression::NumberLiteral {
+                loc: Default::default(),
+                ty: Type::Int(16),
+                value: (-2).into(),
+            },
+            Expression::NumberLiteral {
+                loc: Default::default(),
+                ty: Type::Uint(24),
+                value: 5.into(),
+            },
+        ];
+
+        let mut expected = 1u8.encode();
+        expected.extend(true.encode());
+        expected.extend((-2i16).encode());
+        expected.extend(5u32.encode());
+
+        assert_eq!(encoder.const_encode(&args).unwrap(), expected);
+    }
 }
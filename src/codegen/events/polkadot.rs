@@ -9,7 +9,6 @@ use crate::codegen::expression::expression;
 use crate::codegen::vartable::Vartable;
 use crate::codegen::{Builtin, Expression, Options};
 use crate::sema::ast::{self, Function, Namespace, RetrieveType, Type};
-use ink_env::hash::{Blake2x256, CryptoHash};
 use solang_parser::pt;
 
 /// Implements [EventEmitter] to handle the emission of events on Polkadot.
@@ -25,10 +24,7 @@ pub(super) struct PolkadotEventEmitter<'a> {
 
 impl EventEmitter for PolkadotEventEmitter<'_> {
     fn selector(&self, _emitting_contract_no: usize) -> Vec<u8> {
-        let signature = self.ns.events[self.event_no].signature.as_bytes();
-        let mut buf = [0; 32];
-        <Blake2x256 as CryptoHash>::hash(signature, &mut buf);
-        buf.into()
+        self.ns.events[self.event_no].topic(self.ns)
     }
 
     fn emit(
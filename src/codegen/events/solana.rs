@@ -1,6 +1,5 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::abi::anchor::event_discriminator;
 use crate::codegen::cfg::{ControlFlowGraph, Instr};
 use crate::codegen::encoding::abi_encode;
 use crate::codegen::events::EventEmitter;
@@ -22,7 +21,7 @@ pub(super) struct SolanaEventEmitter<'a> {
 
 impl EventEmitter for SolanaEventEmitter<'_> {
     fn selector(&self, _: usize) -> Vec<u8> {
-        event_discriminator(&self.ns.events[self.event_no].id.name)
+        self.ns.events[self.event_no].topic(self.ns)
     }
 
     fn emit(
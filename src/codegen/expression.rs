@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use super::encoding::{abi_decode, abi_encode};
+use super::encoding::{abi_decode, abi_encode, create_encoder};
 use super::revert::{
     assert_failure, expr_assert, log_runtime_error, require, PanicCode, SolidityError,
 };
@@ -128,6 +128,7 @@ pub fn expression(
         ast::Expression::Divide {
             loc,
             ty,
+            unchecked,
             left,
             right,
         } => {
@@ -137,6 +138,7 @@ pub fn expression(
                 Expression::SignedDivide {
                     loc: *loc,
                     ty: ty.clone(),
+                    overflowing: *unchecked,
                     left: Box::new(l),
                     right: Box::new(r),
                 }
@@ -1725,6 +1727,23 @@ fn abi_encode_many(
         .map(|v| expression(v, cfg, contract_no, func, ns, vartab, opt))
         .collect::<Vec<Expression>>();
 
+    // if all the arguments are constant, the encoded bytes can be computed at compile time
+    if opt.constant_folding {
+        if let Some(bytes) = create_encoder(ns, false).const_encode(&args) {
+            return Expression::AllocDynamicBytes {
+                loc: *loc,
+                ty: Type::DynamicBytes,
+                size: Expression::NumberLiteral {
+                    loc: *loc,
+                    ty: Type::Uint(32),
+                    value: bytes.len().into(),
+                }
+                .into(),
+                initializer: Some(bytes),
+            };
+        }
+    }
+
     abi_encode(loc, args, ns, vartab, cfg, false).0
 }
 
@@ -1743,6 +1762,21 @@ fn abi_encode_packed(
         .map(|v| expression(v, cfg, contract_no, func, ns, vartab, opt))
         .collect::<Vec<Expression>>();
 
+    // if all the arguments are constant, the encoded bytes can be computed at compile time
+    if let Some(bytes) = create_encoder(ns, true).const_encode(&packed) {
+        return Expression::AllocDynamicBytes {
+            loc: *loc,
+            ty: Type::DynamicBytes,
+            size: Expression::NumberLiteral {
+                loc: *loc,
+                ty: Type::Uint(32),
+                value: bytes.len().into(),
+            }
+            .into(),
+            initializer: Some(bytes),
+        };
+    }
+
     let (encoded, _) = abi_encode(loc, packed, ns, vartab, cfg, true);
     encoded
 }
@@ -1758,6 +1792,22 @@ fn encode_many_with_selector(
     let mut encoder_args: Vec<Expression> = Vec::with_capacity(args.len() + 1);
     encoder_args.push(selector);
     encoder_args.append(&mut args);
+
+    // if the selector and all the arguments are constant, the encoded bytes can be computed at compile time
+    if let Some(bytes) = create_encoder(ns, false).const_encode(&encoder_args) {
+        return Expression::AllocDynamicBytes {
+            loc: *loc,
+            ty: Type::DynamicBytes,
+            size: Expression::NumberLiteral {
+                loc: *loc,
+                ty: Type::Uint(32),
+                value: bytes.len().into(),
+            }
+            .into(),
+            initializer: Some(bytes),
+        };
+    }
+
     abi_encode(loc, encoder_args, ns, vartab, cfg, false).0
 }
 
@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod array_boundary;
+pub mod call_graph;
 pub mod cfg;
+mod cfg_json;
 mod constant_folding;
 mod constructor;
 mod dead_storage;
@@ -11,6 +13,7 @@ mod events;
 mod expression;
 pub(super) mod polkadot;
 mod reaching_definitions;
+mod recursion;
 pub mod revert;
 mod solana_accounts;
 mod solana_deploy;
@@ -101,10 +104,13 @@ pub struct Options {
     pub strength_reduce: bool,
     pub vector_to_slice: bool,
     pub common_subexpression_elimination: bool,
+    pub unused_variable_elimination: bool,
     pub generate_debug_information: bool,
     pub opt_level: OptimizationLevel,
     pub log_runtime_errors: bool,
     pub log_prints: bool,
+    pub max_cyclomatic_complexity: Option<usize>,
+    pub max_call_depth: Option<usize>,
     #[cfg(feature = "wasm_opt")]
     pub wasm_opt: Option<OptimizationPasses>,
 }
@@ -117,10 +123,13 @@ impl Default for Options {
             strength_reduce: true,
             vector_to_slice: true,
             common_subexpression_elimination: true,
+            unused_variable_elimination: true,
             generate_debug_information: false,
             opt_level: OptimizationLevel::Default,
             log_runtime_errors: false,
             log_prints: true,
+            max_cyclomatic_complexity: None,
+            max_call_depth: None,
             #[cfg(feature = "wasm_opt")]
             wasm_opt: None,
         }
@@ -134,6 +143,8 @@ pub fn codegen(ns: &mut Namespace, opt: &Options) {
         return;
     }
 
+    let _span = tracing::info_span!(target: "solang::passes", "codegen").entered();
+
     let mut contracts_done = Vec::new();
 
     contracts_done.resize(ns.contracts.len(), false);
@@ -222,7 +233,11 @@ fn contract(contract_no: usize, ns: &mut Namespace, opt: &Options) {
                 &mut all_cfg,
                 ns,
                 opt,
-            )
+            );
+
+            if let Some(max) = opt.max_cyclomatic_complexity {
+                check_cyclomatic_complexity(&all_cfg[cfg_no], function_no, max, ns);
+            }
         }
 
         // generate the cfg for yul functions
@@ -253,6 +268,34 @@ fn contract(contract_no: usize, ns: &mut Namespace, opt: &Options) {
         }
 
         ns.contracts[contract_no].cfg = all_cfg;
+
+        if let Some(max_depth) = opt.max_call_depth {
+            recursion::check_recursion(contract_no, max_depth, ns);
+        }
+    }
+}
+
+/// Warn when the cfg of a function exceeds the maximum cyclomatic complexity. Functions
+/// which are inherited or called from several contracts are reported once, since the
+/// diagnostics are deduplicated after codegen.
+fn check_cyclomatic_complexity(
+    cfg: &ControlFlowGraph,
+    function_no: usize,
+    max: usize,
+    ns: &mut Namespace,
+) {
+    let complexity = cfg.cyclomatic_complexity();
+
+    if complexity > max {
+        let func = &ns.functions[function_no];
+
+        ns.diagnostics.push(Diagnostic::warning(
+            func.loc_prototype,
+            format!(
+                "function '{}' has a cyclomatic complexity of {}, which exceeds the maximum of {}",
+                func.id, complexity, max
+            ),
+        ));
     }
 }
 
@@ -466,6 +509,7 @@ pub enum Expression {
     SignedDivide {
         loc: pt::Loc,
         ty: Type,
+        overflowing: bool,
         left: Box<Expression>,
         right: Box<Expression>,
     },
@@ -736,6 +780,8 @@ impl Recurse for Expression {
             | Expression::More { left, right, .. }
             | Expression::MoreEqual { left, right, .. }
             | Expression::Multiply { left, right, .. }
+            | Expression::UnsignedModulo { left, right, .. }
+            | Expression::SignedModulo { left, right, .. }
             | Expression::NotEqual { left, right, .. }
             | Expression::ShiftLeft { left, right, .. }
             | Expression::ShiftRight { left, right, .. }
@@ -871,6 +917,63 @@ impl RetrieveType for Expression {
 }
 
 impl Expression {
+    /// The name of the expression variant, as used in the JSON output of the cfg
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Expression::Add { .. } => "Add",
+            Expression::AllocDynamicBytes { .. } => "AllocDynamicBytes",
+            Expression::ArrayLiteral { .. } => "ArrayLiteral",
+            Expression::BitwiseAnd { .. } => "BitwiseAnd",
+            Expression::BitwiseOr { .. } => "BitwiseOr",
+            Expression::BitwiseXor { .. } => "BitwiseXor",
+            Expression::BoolLiteral { .. } => "BoolLiteral",
+            Expression::Builtin { .. } => "Builtin",
+            Expression::BytesCast { .. } => "BytesCast",
+            Expression::BytesLiteral { .. } => "BytesLiteral",
+            Expression::Cast { .. } => "Cast",
+            Expression::BitwiseNot { .. } => "BitwiseNot",
+            Expression::ConstArrayLiteral { .. } => "ConstArrayLiteral",
+            Expression::UnsignedDivide { .. } => "UnsignedDivide",
+            Expression::SignedDivide { .. } => "SignedDivide",
+            Expression::Equal { .. } => "Equal",
+            Expression::FormatString { .. } => "FormatString",
+            Expression::FunctionArg { .. } => "FunctionArg",
+            Expression::GetRef { .. } => "GetRef",
+            Expression::InternalFunctionCfg { .. } => "InternalFunctionCfg",
+            Expression::Keccak256 { .. } => "Keccak256",
+            Expression::Less { .. } => "Less",
+            Expression::LessEqual { .. } => "LessEqual",
+            Expression::Load { .. } => "Load",
+            Expression::UnsignedModulo { .. } => "UnsignedModulo",
+            Expression::SignedModulo { .. } => "SignedModulo",
+            Expression::More { .. } => "More",
+            Expression::MoreEqual { .. } => "MoreEqual",
+            Expression::Multiply { .. } => "Multiply",
+            Expression::Not { .. } => "Not",
+            Expression::NotEqual { .. } => "NotEqual",
+            Expression::NumberLiteral { .. } => "NumberLiteral",
+            Expression::Poison => "Poison",
+            Expression::Power { .. } => "Power",
+            Expression::RationalNumberLiteral { .. } => "RationalNumberLiteral",
+            Expression::ReturnData { .. } => "ReturnData",
+            Expression::SignExt { .. } => "SignExt",
+            Expression::ShiftLeft { .. } => "ShiftLeft",
+            Expression::ShiftRight { .. } => "ShiftRight",
+            Expression::StorageArrayLength { .. } => "StorageArrayLength",
+            Expression::StringCompare { .. } => "StringCompare",
+            Expression::StructLiteral { .. } => "StructLiteral",
+            Expression::StructMember { .. } => "StructMember",
+            Expression::Subscript { .. } => "Subscript",
+            Expression::Subtract { .. } => "Subtract",
+            Expression::Trunc { .. } => "Trunc",
+            Expression::Negate { .. } => "Negate",
+            Expression::Undefined { .. } => "Undefined",
+            Expression::Variable { .. } => "Variable",
+            Expression::ZeroExt { .. } => "ZeroExt",
+            Expression::AdvancePointer { .. } => "AdvancePointer",
+        }
+    }
+
     /// Increment an expression by some value.
     pub(crate) fn add_u32(self, other: Expression) -> Self {
         Expression::Add {
@@ -1385,11 +1488,13 @@ impl Expression {
                 Expression::SignedDivide {
                     loc,
                     ty,
+                    overflowing,
                     left,
                     right,
                 } => Expression::SignedDivide {
                     loc: *loc,
                     ty: ty.clone(),
+                    overflowing: *overflowing,
                     left: Box::new(filter(left, ctx)),
                     right: Box::new(filter(right, ctx)),
                 },
@@ -0,0 +1,261 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::cfg::{ASTFunction, ControlFlowGraph, Instr, InternalCallTy};
+use super::Expression;
+use crate::sema::ast::{Namespace, Note};
+use crate::sema::diagnostics::Diagnostics;
+use solang_parser::{diagnostics::Diagnostic, pt};
+use std::collections::{BTreeSet, VecDeque};
+
+/// Detect internal functions which call themselves, either directly or through other functions.
+/// On targets with a strict call depth limit like Solana, such recursion may fail at runtime, so
+/// a warning is given for each cycle in the call graph. Chains of calls without recursion are
+/// reported when they are longer than the maximum call depth.
+pub(super) fn check_recursion(contract_no: usize, max_depth: usize, ns: &mut Namespace) {
+    let all_cfg = &ns.contracts[contract_no].cfg;
+
+    let graph: Vec<BTreeSet<usize>> = all_cfg.iter().map(callees).collect();
+
+    // the set of cfgs which can be reached from each cfg
+    let reachable: Vec<BTreeSet<usize>> = (0..graph.len())
+        .map(|cfg_no| reachable_from(&graph, cfg_no))
+        .collect();
+
+    let mut reported = BTreeSet::new();
+    let mut diagnostics = Diagnostics::default();
+
+    for cfg_no in 0..graph.len() {
+        if reported.contains(&cfg_no) || !reachable[cfg_no].contains(&cfg_no) {
+            continue;
+        }
+
+        // all the functions in the same cycle are reported together
+        let cycle: BTreeSet<usize> = reachable[cfg_no]
+            .iter()
+            .filter(|other| reachable[**other].contains(&cfg_no))
+            .copied()
+            .collect();
+
+        reported.extend(cycle.iter().copied());
+
+        let loc = match function_name(&all_cfg[cfg_no], ns) {
+            Some((loc, _)) => loc,
+            None => continue,
+        };
+
+        let path = cycle_path(&graph, &cycle, cfg_no)
+            .iter()
+            .filter_map(|no| function_name(&all_cfg[*no], ns))
+            .map(|(_, name)| format!("'{name}'"))
+            .collect::<Vec<String>>()
+            .join(" -> ");
+
+        let notes = cycle
+            .iter()
+            .filter(|no| **no != cfg_no)
+            .filter_map(|no| function_name(&all_cfg[*no], ns))
+            .map(|(loc, name)| Note {
+                loc,
+                message: format!("declaration of function '{name}'"),
+            })
+            .collect();
+
+        diagnostics.push(Diagnostic::warning_with_notes(
+            loc,
+            format!("recursive call cycle {path} may exceed the maximum call depth of {max_depth}"),
+            notes,
+        ));
+    }
+
+    check_call_chains(
+        &graph,
+        &reachable,
+        contract_no,
+        max_depth,
+        ns,
+        &mut diagnostics,
+    );
+
+    ns.diagnostics.extend(diagnostics);
+}
+
+/// Warn about the longest call chain starting at each function which is not called by another
+/// function, if the chain is deeper than the maximum call depth. Recursive functions are left
+/// out, since those are reported as cycles.
+fn check_call_chains(
+    graph: &[BTreeSet<usize>],
+    reachable: &[BTreeSet<usize>],
+    contract_no: usize,
+    max_depth: usize,
+    ns: &Namespace,
+    diagnostics: &mut Diagnostics,
+) {
+    let all_cfg = &ns.contracts[contract_no].cfg;
+
+    let recursive = |no: usize| reachable[no].contains(&no);
+
+    let mut depth = vec![None; graph.len()];
+
+    for cfg_no in 0..graph.len() {
+        if !recursive(cfg_no) {
+            chain_depth(graph, &recursive, cfg_no, &mut depth);
+        }
+    }
+
+    for cfg_no in 0..graph.len() {
+        if recursive(cfg_no) || depth[cfg_no].unwrap() <= max_depth {
+            continue;
+        }
+
+        // only report the chain from the outermost function
+        let called = (0..graph.len()).any(|caller| {
+            !recursive(caller)
+                && graph[caller].contains(&cfg_no)
+                && function_name(&all_cfg[caller], ns).is_some()
+        });
+
+        if called {
+            continue;
+        }
+
+        let loc = match function_name(&all_cfg[cfg_no], ns) {
+            Some((loc, _)) => loc,
+            None => continue,
+        };
+
+        let mut path = vec![cfg_no];
+
+        while let Some(next) = graph[*path.last().unwrap()]
+            .iter()
+            .filter(|no| !recursive(**no))
+            .max_by_key(|no| depth[**no])
+        {
+            path.push(*next);
+        }
+
+        let path = path
+            .iter()
+            .filter_map(|no| function_name(&all_cfg[*no], ns))
+            .map(|(_, name)| format!("'{name}'"))
+            .collect::<Vec<String>>()
+            .join(" -> ");
+
+        diagnostics.push(Diagnostic::warning(
+            loc,
+            format!(
+                "call chain {path} has a depth of {} which exceeds the maximum call depth of {max_depth}",
+                depth[cfg_no].unwrap()
+            ),
+        ));
+    }
+}
+
+/// The number of functions in the longest chain of calls starting at the given cfg. This must not
+/// be called for recursive functions, and recursive callees are not followed.
+fn chain_depth(
+    graph: &[BTreeSet<usize>],
+    recursive: &dyn Fn(usize) -> bool,
+    cfg_no: usize,
+    depth: &mut [Option<usize>],
+) -> usize {
+    if let Some(d) = depth[cfg_no] {
+        return d;
+    }
+
+    let mut max = 0;
+
+    for callee in &graph[cfg_no] {
+        if !recursive(*callee) {
+            max = max.max(chain_depth(graph, recursive, *callee, depth));
+        }
+    }
+
+    depth[cfg_no] = Some(max + 1);
+
+    max + 1
+}
+
+/// The cfgs which may be called from the given cfg, either directly or via a function pointer
+fn callees(cfg: &ControlFlowGraph) -> BTreeSet<usize> {
+    let mut callees = BTreeSet::new();
+
+    for block in &cfg.blocks {
+        for instr in &block.instr {
+            if let Instr::Call {
+                call: InternalCallTy::Static { cfg_no },
+                ..
+            } = instr
+            {
+                callees.insert(*cfg_no);
+            }
+
+            instr.recurse_expressions(&mut callees, |expr, callees| {
+                if let Expression::InternalFunctionCfg { cfg_no, .. } = expr {
+                    callees.insert(*cfg_no);
+                }
+                true
+            });
+        }
+    }
+
+    callees
+}
+
+fn reachable_from(graph: &[BTreeSet<usize>], cfg_no: usize) -> BTreeSet<usize> {
+    let mut visited = BTreeSet::new();
+    let mut todo: Vec<usize> = graph[cfg_no].iter().copied().collect();
+
+    while let Some(no) = todo.pop() {
+        if visited.insert(no) {
+            todo.extend(graph[no].iter().copied());
+        }
+    }
+
+    visited
+}
+
+/// Find the shortest path from start back to itself, only visiting the functions in the cycle
+fn cycle_path(graph: &[BTreeSet<usize>], cycle: &BTreeSet<usize>, start: usize) -> Vec<usize> {
+    let mut parent = vec![None; graph.len()];
+    let mut todo = VecDeque::from([start]);
+
+    while let Some(no) = todo.pop_front() {
+        for next in graph[no].iter().filter(|next| cycle.contains(*next)) {
+            if *next == start {
+                let mut path = vec![no];
+
+                while let Some(prev) = parent[*path.last().unwrap()] {
+                    path.push(prev);
+                }
+
+                path.reverse();
+                path.push(start);
+
+                return path;
+            }
+
+            if parent[*next].is_none() {
+                parent[*next] = Some(no);
+                todo.push_back(*next);
+            }
+        }
+    }
+
+    vec![start, start]
+}
+
+fn function_name(cfg: &ControlFlowGraph, ns: &Namespace) -> Option<(pt::Loc, String)> {
+    match cfg.function_no {
+        ASTFunction::SolidityFunction(function_no) => {
+            let func = &ns.functions[function_no];
+
+            Some((func.loc_prototype, func.id.name.clone()))
+        }
+        ASTFunction::YulFunction(function_no) => {
+            let func = &ns.yul_functions[function_no];
+
+            Some((func.loc, func.name.clone()))
+        }
+        ASTFunction::None => None,
+    }
+}
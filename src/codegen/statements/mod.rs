@@ -169,7 +169,7 @@ pub(crate) fn statement(
                 }
             }
         }
-        Statement::Expression(_, _, expr) => {
+        Statement::Expression(_, reachable, expr) => {
             match expr {
                 ast::Expression::Assign { left, right, .. } => {
                     if should_remove_assignment(left, func, opt, ns) {
@@ -212,6 +212,12 @@ pub(crate) fn statement(
             }
 
             let _ = expression(expr, cfg, contract_no, Some(func), ns, vartab, opt);
+
+            // e.g. require(false) always fails, but the code generated for it still continues
+            // with the success block, which can never be reached
+            if !reachable && expr.tys() != vec![Type::Unreachable] {
+                cfg.add(vartab, Instr::AssertFailure { encoded_args: None });
+            }
         }
         Statement::Delete(_, ty, expr) => {
             let var_expr = expression(expr, cfg, contract_no, Some(func), ns, vartab, opt);
@@ -240,35 +246,44 @@ pub(crate) fn statement(
                 },
             );
         }
-        Statement::If(_, _, cond, then_stmt, else_stmt) if else_stmt.is_empty() => {
-            if_then(
-                cond,
-                then_stmt,
-                func,
-                cfg,
-                contract_no,
-                ns,
-                vartab,
-                loops,
-                placeholder,
-                return_override,
-                opt,
-            );
+        Statement::If(_, reachable, cond, then_stmt, else_stmt) => {
+            let endif_reachable = if else_stmt.is_empty() {
+                if_then(
+                    cond,
+                    then_stmt,
+                    func,
+                    cfg,
+                    contract_no,
+                    ns,
+                    vartab,
+                    loops,
+                    placeholder,
+                    return_override,
+                    opt,
+                )
+            } else {
+                if_then_else(
+                    cond,
+                    then_stmt,
+                    else_stmt,
+                    func,
+                    cfg,
+                    contract_no,
+                    ns,
+                    vartab,
+                    loops,
+                    placeholder,
+                    return_override,
+                    opt,
+                )
+            };
+
+            // The condition is constant and the branch taken does not fall through, so
+            // the end of the if statement can never be reached
+            if endif_reachable && !reachable {
+                cfg.add(vartab, Instr::AssertFailure { encoded_args: None });
+            }
         }
-        Statement::If(_, _, cond, then_stmt, else_stmt) => if_then_else(
-            cond,
-            then_stmt,
-            else_stmt,
-            func,
-            cfg,
-            contract_no,
-            ns,
-            vartab,
-            loops,
-            placeholder,
-            return_override,
-            opt,
-        ),
         Statement::DoWhile(_, _, body_stmt, cond_expr) => {
             let body = cfg.new_basic_block("body".to_string());
             let cond = cfg.new_basic_block("conf".to_string());
@@ -622,7 +637,7 @@ pub(crate) fn statement(
     }
 }
 
-/// Generate if-then-no-else
+/// Generate if-then-no-else. Returns whether the endif block has any predecessors
 fn if_then(
     cond: &ast::Expression,
     then_stmt: &[Statement],
@@ -635,7 +650,7 @@ fn if_then(
     placeholder: Option<&Instr>,
     return_override: Option<&Instr>,
     opt: &Options,
-) {
+) -> bool {
     let cond = expression(cond, cfg, contract_no, Some(func), ns, vartab, opt);
 
     let then = cfg.new_basic_block("then".to_string());
@@ -680,9 +695,11 @@ fn if_then(
     cfg.set_phis(endif, vartab.pop_dirty_tracker());
 
     cfg.set_basic_block(endif);
+
+    true
 }
 
-/// Generate if-then-else
+/// Generate if-then-else. Returns whether the endif block has any predecessors
 fn if_then_else(
     cond: &ast::Expression,
     then_stmt: &[Statement],
@@ -696,7 +713,7 @@ fn if_then_else(
     placeholder: Option<&Instr>,
     return_override: Option<&Instr>,
     opt: &Options,
-) {
+) -> bool {
     let cond = expression(cond, cfg, contract_no, Some(func), ns, vartab, opt);
 
     let then = cfg.new_basic_block("then".to_string());
@@ -769,6 +786,8 @@ fn if_then_else(
     cfg.set_phis(endif, vartab.pop_dirty_tracker());
 
     cfg.set_basic_block(endif);
+
+    then_reachable || else_reachable
 }
 
 fn returns(
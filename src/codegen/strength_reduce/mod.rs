@@ -342,6 +342,7 @@ fn expression_reduce(expr: &Expression, vars: &Variables, ns: &mut Namespace) ->
                 ty,
                 left,
                 right,
+                ..
             } => {
                 let bits = ty.bits(ns) as usize;
 
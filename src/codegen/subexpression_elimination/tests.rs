@@ -294,6 +294,7 @@ fn complex_expression() {
     let div = Expression::SignedDivide {
         loc: Loc::Codegen,
         ty: Type::Int(8),
+        overflowing: false,
         left: Box::new(sum.clone()),
         right: Box::new(sub.clone()),
     };
@@ -472,6 +473,7 @@ fn kill() {
     let div = Expression::SignedDivide {
         loc: Loc::Codegen,
         ty: Type::Int(8),
+        overflowing: false,
         left: Box::new(sum.clone()),
         right: Box::new(sub.clone()),
     };
@@ -582,6 +584,7 @@ fn clone() {
     let div = Expression::SignedDivide {
         loc: Loc::Codegen,
         ty: Type::Int(8),
+        overflowing: false,
         left: Box::new(sum.clone()),
         right: Box::new(sub.clone()),
     };
@@ -690,6 +693,7 @@ fn intersect() {
     let div = Expression::SignedDivide {
         loc: Loc::Codegen,
         ty: Type::Int(8),
+        overflowing: false,
         left: Box::new(sum.clone()),
         right: Box::new(sub.clone()),
     };
@@ -1081,6 +1085,7 @@ fn unite_expressions() {
     let div = Expression::SignedDivide {
         loc: Loc::Codegen,
         ty: Type::Int(8),
+        overflowing: false,
         left: Box::new(sum),
         right: Box::new(sub),
     };
@@ -1,7 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 #[cfg(test)]
-use crate::{codegen, sema::ast};
+use crate::{
+    codegen, compile_to_cfg, file_resolver::FileResolver, parse_and_resolve, sema::ast, Target,
+};
+#[cfg(test)]
+use std::ffi::OsStr;
 
 #[test]
 fn test_builtin_conversion() {
@@ -121,3 +125,395 @@ fn test_builtin_conversion() {
         assert_eq!(codegen::Builtin::from(item), output[i]);
     }
 }
+
+#[test]
+fn cyclomatic_complexity() {
+    let src = r#"
+        contract c {
+            function linear(uint64 x) public pure returns (uint64) {
+                return x;
+            }
+
+            function branches(uint64 x, bool b) public pure returns (uint64) {
+                if (x == 1) {
+                    return 10;
+                }
+                if (x == 2) {
+                    return 20;
+                }
+                if (b) {
+                    return 30;
+                }
+                return 40;
+            }
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let mut ns = parse_and_resolve(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+    );
+
+    codegen::codegen(
+        &mut ns,
+        &codegen::Options {
+            max_cyclomatic_complexity: Some(3),
+            ..Default::default()
+        },
+    );
+
+    let complexity = |name: &str| {
+        ns.contracts[0]
+            .cfg
+            .iter()
+            .find(|cfg| match cfg.function_no {
+                codegen::cfg::ASTFunction::SolidityFunction(no) => ns.functions[no].id.name == name,
+                _ => false,
+            })
+            .unwrap()
+            .cyclomatic_complexity()
+    };
+
+    assert_eq!(complexity("linear"), 1);
+    assert_eq!(complexity("branches"), 4);
+
+    let warnings = ns.diagnostics.warnings();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "function 'branches' has a cyclomatic complexity of 4, which exceeds the maximum of 3"
+    );
+}
+
+#[test]
+fn mutual_recursion() {
+    let src = r#"
+        contract c {
+            function even(uint64 n) internal pure returns (bool) {
+                if (n == 0) {
+                    return true;
+                }
+                return odd(n - 1);
+            }
+
+            function odd(uint64 n) internal pure returns (bool) {
+                if (n == 0) {
+                    return false;
+                }
+                return even(n - 1);
+            }
+
+            function test(uint64 n) public pure returns (bool) {
+                return even(n);
+            }
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let mut ns = parse_and_resolve(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+    );
+
+    codegen::codegen(
+        &mut ns,
+        &codegen::Options {
+            max_call_depth: Some(64),
+            ..Default::default()
+        },
+    );
+
+    let warnings = ns.diagnostics.warnings();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "recursive call cycle 'even' -> 'odd' -> 'even' may exceed the maximum call depth of 64"
+    );
+    assert_eq!(warnings[0].notes.len(), 1);
+    assert_eq!(
+        warnings[0].notes[0].message,
+        "declaration of function 'odd'"
+    );
+}
+
+#[test]
+fn call_chain_depth() {
+    let src = r#"
+        contract c {
+            function a(uint64 n) internal pure returns (uint64) {
+                return b(n) + 1;
+            }
+
+            function b(uint64 n) internal pure returns (uint64) {
+                return c(n) + 1;
+            }
+
+            function c(uint64 n) internal pure returns (uint64) {
+                return n + 1;
+            }
+
+            function test(uint64 n) public pure returns (uint64) {
+                return a(n);
+            }
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let mut ns = parse_and_resolve(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+    );
+
+    codegen::codegen(
+        &mut ns,
+        &codegen::Options {
+            max_call_depth: Some(3),
+            ..Default::default()
+        },
+    );
+
+    let warnings = ns.diagnostics.warnings();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "call chain 'test' -> 'a' -> 'b' -> 'c' has a depth of 4 which exceeds the maximum call depth of 3"
+    );
+}
+
+#[test]
+fn call_graph() {
+    let src = r#"
+        contract c {
+            function a() public pure returns (uint64) {
+                return b() + d();
+            }
+
+            function b() internal pure returns (uint64) {
+                return 1;
+            }
+
+            function d() internal pure returns (uint64) {
+                return 2;
+            }
+        }
+
+        contract e {
+            function g() public returns (uint64) {
+                c x = new c();
+                return x.a();
+            }
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let mut ns = parse_and_resolve(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+    );
+
+    codegen::codegen(&mut ns, &codegen::Options::default());
+
+    let graph = ns.call_graph();
+
+    let edges = |from: &str| {
+        graph
+            .edges
+            .iter()
+            .filter(|edge| edge.from == from)
+            .map(|edge| (edge.to.as_str(), edge.kind))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        edges("c.a()"),
+        vec![("c.b()", "internal"), ("c.d()", "internal")]
+    );
+    assert_eq!(
+        edges("e.g()"),
+        vec![("c.a()", "external"), ("c.constructor()", "constructor")]
+    );
+
+    let node = graph.nodes.iter().find(|node| node.id == "c.b()").unwrap();
+
+    assert_eq!(node.contract, "c");
+    assert_eq!(node.name, "b");
+    assert_eq!(node.kind, "function");
+
+    let json = serde_json::to_value(&graph).unwrap();
+
+    assert_eq!(json["edges"].as_array().unwrap().len(), graph.edges.len());
+}
+
+#[test]
+fn compile_to_cfg_api() {
+    let src = r#"
+        contract c {
+            function foo(uint64 x) public pure returns (uint64) {
+                return x * 2;
+            }
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let ns = compile_to_cfg(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+        &codegen::Options::default(),
+    );
+
+    assert!(!ns.diagnostics.any_errors());
+
+    let cfg = ns.contracts[0]
+        .cfg
+        .iter()
+        .find(|cfg| match cfg.function_no {
+            codegen::cfg::ASTFunction::SolidityFunction(no) => ns.functions[no].id.name == "foo",
+            _ => false,
+        })
+        .expect("cfg for foo should exist");
+
+    assert!(!cfg.blocks.is_empty());
+
+    // the cfgs are not generated if there are errors
+    cache.set_file_contents(
+        "error.sol",
+        "contract c { function foo() public { x = 1; } }".into(),
+    );
+
+    let ns = compile_to_cfg(
+        OsStr::new("error.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+        &codegen::Options::default(),
+    );
+
+    assert!(ns.diagnostics.any_errors());
+    assert!(ns.contracts[0].cfg.is_empty());
+}
+
+#[test]
+fn constant_branch_eliminated() {
+    let src = r#"
+        contract c {
+            function foo(uint64 x) public pure returns (uint64) {
+                if (2 > 1) {
+                    return x;
+                }
+                return 0;
+            }
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let mut ns = parse_and_resolve(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+    );
+
+    codegen::codegen(&mut ns, &codegen::Options::default());
+
+    let eliminated = ns
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.message == "condition is always true, false branch eliminated")
+        .collect::<Vec<_>>();
+
+    assert_eq!(eliminated.len(), 1);
+    assert_eq!(eliminated[0].level, ast::Level::Debug);
+
+    // without constant folding, the branch is not eliminated
+    let mut ns = parse_and_resolve(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+    );
+
+    codegen::codegen(
+        &mut ns,
+        &codegen::Options {
+            constant_folding: false,
+            ..Default::default()
+        },
+    );
+
+    assert!(!ns
+        .diagnostics
+        .iter()
+        .any(|diag| diag.message.ends_with("branch eliminated")));
+}
+
+#[test]
+fn default_values() {
+    let src = r#"
+        struct S {
+            int64 a;
+            bool b;
+            bytes4 c;
+            uint8[2] d;
+        }
+
+        struct M {
+            int64 a;
+            mapping(int64 => int64) m;
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let ns = parse_and_resolve(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+    );
+
+    let s = ast::Type::Struct(ast::StructType::UserDefined(0));
+
+    match s.default(&ns) {
+        Some(codegen::Expression::StructLiteral { ty, values, .. }) => {
+            assert_eq!(ty, s);
+            assert!(values.is_empty());
+        }
+        expr => panic!("unexpected default {expr:?}"),
+    }
+
+    let array = ast::Type::Array(Box::new(s.clone()), vec![ast::ArrayLength::Fixed(3.into())]);
+
+    match array.default(&ns) {
+        Some(codegen::Expression::ArrayLiteral { ty, values, .. }) => {
+            assert_eq!(ty, array);
+            assert!(values.is_empty());
+        }
+        expr => panic!("unexpected default {expr:?}"),
+    }
+
+    match ast::Type::Bytes(4).default(&ns) {
+        Some(codegen::Expression::BytesLiteral { value, .. }) => assert_eq!(value, vec![0; 4]),
+        expr => panic!("unexpected default {expr:?}"),
+    }
+
+    // a mapping cannot be constructed, so neither can a struct which contains one
+    let m = ast::Type::Struct(ast::StructType::UserDefined(1));
+
+    assert!(m.default(&ns).is_none());
+    assert!(
+        ast::Type::Array(Box::new(m), vec![ast::ArrayLength::Fixed(2.into())])
+            .default(&ns)
+            .is_none()
+    );
+}
@@ -25,7 +25,7 @@ pub fn should_remove_assignment(
     opt: &Options,
     ns: &Namespace,
 ) -> bool {
-    if opt.opt_level == OptimizationLevel::None {
+    if opt.opt_level == OptimizationLevel::None || !opt.unused_variable_elimination {
         return false;
     }
 
@@ -67,7 +67,7 @@ pub fn should_remove_assignment(
 
 /// Checks if we should remove a variable
 pub fn should_remove_variable(pos: usize, func: &Function, opt: &Options, ns: &Namespace) -> bool {
-    if opt.opt_level == OptimizationLevel::None {
+    if opt.opt_level == OptimizationLevel::None || !opt.unused_variable_elimination {
         return false;
     }
 
@@ -292,6 +292,7 @@ fn process_arithmetic(
             let expr = Expression::SignedDivide {
                 loc: *loc,
                 ty: left.ty(),
+                overflowing: true,
                 left: Box::new(left),
                 right: Box::new(right.clone()),
             };
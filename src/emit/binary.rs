@@ -201,6 +201,8 @@ impl<'a> Binary<'a> {
             return Ok(self.code.borrow().clone());
         }
 
+        let _span = tracing::info_span!(target: "solang::passes", "llvm").entered();
+
         match self.options.opt_level.into() {
             OptimizationLevel::Default | OptimizationLevel::Aggressive => {
                 let pass_manager = PassManager::create(());
@@ -248,8 +250,14 @@ impl<'a> Binary<'a> {
             })
             .map_err(|s| s.to_string())?;
 
+        // Only the linked program is post-processed by wasm-opt. Object and assembly output is
+        // exactly what llvm produced, so that it is reproducible and can be inspected as-is.
         #[cfg(feature = "wasm_opt")]
-        if let Some(level) = self.options.wasm_opt.filter(|_| self.target.is_polkadot()) {
+        if let Some(level) = self
+            .options
+            .wasm_opt
+            .filter(|_| self.target.is_polkadot() && generate == Generate::Linked)
+        {
             let mut infile = tempdir().map_err(|e| e.to_string())?.into_path();
             infile.push("code.wasm");
             let outfile = infile.with_extension("wasmopt");
@@ -270,6 +278,62 @@ impl<'a> Binary<'a> {
         Ok(code)
     }
 
+    /// Disassemble the linked wasm code into the WebAssembly text format. The compact form
+    /// only lists the structure of the module, leaving out the function bodies.
+    pub fn wat(&self, compact: bool) -> Result<String, String> {
+        if !matches!(self.target, Target::Polkadot { .. } | Target::Soroban) {
+            return Err(format!(
+                "WebAssembly text format is not available for target {}",
+                self.target
+            ));
+        }
+
+        let code = self.code(Generate::Linked)?;
+
+        let mut printer = wasmprinter::Printer::new();
+        printer.print_skeleton(compact);
+        printer.print(&code).map_err(|err| err.to_string())
+    }
+
+    /// Generate the Solana assembly, with every `.loc` directive followed by a comment holding
+    /// the line of the source it refers to. The `.loc` directives are only present when the
+    /// binary is built with debug information.
+    pub fn annotated_assembly(&self, source: &str) -> Result<String, String> {
+        if self.target != Target::Solana {
+            return Err(format!(
+                "annotated assembly is not available for target {}",
+                self.target
+            ));
+        }
+
+        let code = self.code(Generate::Assembly)?;
+        let asm = String::from_utf8(code).map_err(|err| err.to_string())?;
+        let source: Vec<&str> = source.lines().collect();
+        let mut out = String::new();
+
+        for line in asm.lines() {
+            out.push_str(line);
+            out.push('\n');
+
+            // .loc file_no line_no column_no; the line numbers of the debug locations start at
+            // 0, which is also used for instructions without a location
+            let line_no = line
+                .trim_start()
+                .strip_prefix(".loc")
+                .and_then(|args| args.split_whitespace().nth(1))
+                .and_then(|line_no| line_no.parse::<usize>().ok())
+                .filter(|line_no| *line_no > 0);
+
+            if let Some(text) = line_no.and_then(|line_no| source.get(line_no)) {
+                out.push_str("\t# ");
+                out.push_str(text.trim());
+                out.push('\n');
+            }
+        }
+
+        Ok(out)
+    }
+
     /// Mark all functions as internal unless they're in the export_list. This helps the
     /// llvm globaldce pass eliminate unnecessary functions and reduce the wasm output.
     pub(crate) fn internalize(&self, export_list: &[&str]) {
@@ -371,7 +371,8 @@ impl ast::Contract {
         opt: &'a Options,
         contract_no: usize,
     ) -> binary::Binary {
-        binary::Binary::build(context, self, ns, opt, contract_no)
+        tracing::info_span!(target: "solang::passes", "emit")
+            .in_scope(|| binary::Binary::build(context, self, ns, opt, contract_no))
     }
 
     /// Generate the final program code for the contract
@@ -3,13 +3,17 @@
 use crate::sema::ast;
 use itertools::Itertools;
 use normalize_path::NormalizePath;
-use solang_parser::pt::Loc;
+use solang_parser::pt::{self, Loc};
+use solang_parser::{diagnostics::Diagnostic, parse};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
 use std::fs::File;
+use std::hash::{Hash, Hasher};
 use std::io::prelude::*;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 #[derive(Default)]
 pub struct FileResolver {
@@ -19,6 +23,54 @@ pub struct FileResolver {
     cached_paths: HashMap<PathBuf, usize>,
     /// The actual file contents
     files: Vec<ResolvedFile>,
+    /// Supplies file contents before the filesystem is consulted
+    provider: Option<Box<dyn FileProvider>>,
+    /// Whether files which are symbolic links may be read
+    symlink_policy: SymlinkPolicy,
+    /// Parse trees of files parsed earlier, if set
+    parse_cache: Option<ParseCache>,
+}
+
+/// The parse tree and comments of a source file
+pub type ParsedFile = (pt::SourceUnit, Vec<pt::Comment>);
+
+/// Parse trees of files which were parsed before, so that files which have not changed do not
+/// have to be parsed again, e.g. by the language server which resolves the same imports after
+/// every edit. The locations in a parse tree include the file number, so the entries are keyed
+/// by path and file number, and hold the content hash of the source which was parsed.
+///
+/// Clones of a cache share the same entries, so resolvers which run at the same time can all
+/// use and add to it.
+#[derive(Clone, Default)]
+pub struct ParseCache {
+    entries: Arc<Mutex<HashMap<(PathBuf, usize), (u64, Arc<ParsedFile>)>>>,
+    hits: Arc<AtomicUsize>,
+}
+
+impl ParseCache {
+    /// The number of times a parse tree was taken from the cache rather than parsed
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+}
+
+/// Should symbolic links be followed when reading files from the filesystem
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Symbolic links are followed
+    #[default]
+    Follow,
+    /// Files which are symbolic links are rejected with an error, e.g. for sandboxed builds
+    NoFollow,
+}
+
+/// A source of file contents other than the filesystem, for example a database or the
+/// unsaved buffers of an editor. The provider is asked for a file before the filesystem is
+/// read, so it can also override files which exist on disk.
+pub trait FileProvider: Send + Sync {
+    /// Return the contents of the file at the given path, or `None` if the provider does
+    /// not have it and the filesystem should be used instead
+    fn file_contents(&self, path: &Path) -> Option<String>;
 }
 
 /// When we resolve a file, we need to know its base compared to the import so
@@ -38,6 +90,49 @@ pub struct ResolvedFile {
 }
 
 impl FileResolver {
+    /// Reuse the parse trees in the given cache for files which have not changed, and add the
+    /// parse trees of any files parsed with this resolver to it
+    pub fn set_parse_cache(&mut self, cache: ParseCache) {
+        self.parse_cache = Some(cache);
+    }
+
+    /// Parse the contents of a file. If a parse cache is set and has the parse tree of the same
+    /// contents, then that is returned instead. Files with syntax errors are not cached.
+    pub(crate) fn parse(
+        &mut self,
+        full_path: &Path,
+        contents: &str,
+        file_no: usize,
+    ) -> Result<Arc<ParsedFile>, Vec<Diagnostic>> {
+        let Some(cache) = &self.parse_cache else {
+            return parse(contents, file_no).map(Arc::new);
+        };
+
+        let mut hasher = DefaultHasher::new();
+        contents.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let key = (full_path.to_path_buf(), file_no);
+
+        if let Some((cached_hash, parsed)) = cache.entries.lock().unwrap().get(&key) {
+            if *cached_hash == hash {
+                cache.hits.fetch_add(1, Ordering::Relaxed);
+                return Ok(parsed.clone());
+            }
+        }
+
+        // do not hold the lock while parsing, so that other resolvers are not held up
+        let parsed = Arc::new(parse(contents, file_no)?);
+
+        cache
+            .entries
+            .lock()
+            .unwrap()
+            .insert(key, (hash, parsed.clone()));
+
+        Ok(parsed)
+    }
+
     /// Add import path
     pub fn add_import_path(&mut self, path: &Path) {
         assert!(!self.import_paths.contains(&(None, path.to_path_buf())));
@@ -90,6 +185,16 @@ impl FileResolver {
         self.cached_paths.insert(pathbuf, pos);
     }
 
+    /// Set the provider which is consulted for file contents before the filesystem
+    pub fn set_file_provider(&mut self, provider: Box<dyn FileProvider>) {
+        self.provider = Some(provider);
+    }
+
+    /// Set whether symbolic links are followed when reading files from the filesystem
+    pub fn set_symlink_policy(&mut self, policy: SymlinkPolicy) {
+        self.symlink_policy = policy;
+    }
+
     /// Get the file contents of `file_no`th file if it exists
     pub fn get_contents_of_file_no(&self, file_no: usize) -> Option<Arc<str>> {
         self.files.get(file_no).map(|f| f.contents.clone())
@@ -122,6 +227,37 @@ impl FileResolver {
             return Ok(Some(file));
         }
 
+        if let Some(contents) = self
+            .provider
+            .as_ref()
+            .and_then(|provider| provider.file_contents(&cache_path))
+        {
+            let pos = self.files.len();
+
+            self.files.push(ResolvedFile {
+                path: filename.into(),
+                full_path: cache_path.clone(),
+                import_no,
+                contents: Arc::from(contents),
+            });
+
+            self.cached_paths.insert(cache_path, pos);
+
+            return Ok(Some(self.files[pos].clone()));
+        }
+
+        if self.symlink_policy == SymlinkPolicy::NoFollow
+            && path
+                .symlink_metadata()
+                .map(|metadata| metadata.file_type().is_symlink())
+                .unwrap_or(false)
+        {
+            return Err(format!(
+                "file '{}' is a symbolic link, which is not allowed",
+                path.display()
+            ));
+        }
+
         if let Ok(full_path) = path.canonicalize() {
             let file = self.load_file(filename, &full_path, import_no)?;
             return Ok(Some(file.clone()));
@@ -178,6 +314,11 @@ impl FileResolver {
     /// Walk the import path to search for a file. If no import path is set up,
     /// return. Check each import path if the file can be found in a subdirectory
     /// of that path, and return the canonicalized path.
+    ///
+    /// The order is deterministic: the longest import map matching the start of the path
+    /// is applied first, and then the import paths are searched in the order they were added.
+    /// If the file is found in more than one import path, this is an error which lists the
+    /// files in that same order.
     pub fn resolve_file(
         &mut self,
         parent: Option<&ResolvedFile>,
@@ -219,18 +360,20 @@ impl FileResolver {
             }
         }
 
-        // first check maps
-        let mut remapped = path_filename.clone();
-
-        for import_map_no in 0..self.import_paths.len() {
-            if let (Some(mapping), target) = &self.import_paths[import_map_no].clone() {
-                if let Ok(relpath) = path_filename.strip_prefix(mapping) {
-                    remapped = target.join(relpath);
-                }
-            }
-        }
+        // first check maps; if more than one map matches, the longest one wins so that the
+        // result does not depend on the order in which the maps were given
+        let path = self
+            .import_paths
+            .iter()
+            .filter_map(|(mapping, target)| {
+                let mapping = mapping.as_ref()?;
+                let relpath = path_filename.strip_prefix(mapping).ok()?;
 
-        let path = remapped;
+                Some((mapping.len(), target.join(relpath)))
+            })
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, path)| path)
+            .unwrap_or_else(|| path_filename.clone());
 
         // walk over the import paths until we find one that resolves
         for import_no in 0..self.import_paths.len() {
@@ -253,7 +396,11 @@ impl FileResolver {
         }
 
         match result.len() {
-            0 => Err(format!("file not found '{}'", path_filename.display())),
+            0 => Err(format!(
+                "file not found '{}'{}",
+                path_filename.display(),
+                self.searched_paths()
+            )),
             1 => Ok(result.pop().unwrap()),
             _ => Err(format!(
                 "found multiple files matching '{}': {}",
@@ -266,6 +413,33 @@ impl FileResolver {
         }
     }
 
+    /// List the import paths and import maps which were searched, for the diagnostic when an
+    /// import cannot be resolved
+    fn searched_paths(&self) -> String {
+        let paths = self
+            .import_paths
+            .iter()
+            .filter(|(map, _)| map.is_none())
+            .map(|(_, path)| format!("'{}'", path.display()))
+            .join(", ");
+
+        let maps = self
+            .import_paths
+            .iter()
+            .filter_map(|(map, path)| {
+                map.as_ref()
+                    .map(|map| format!("'{}={}'", map.to_string_lossy(), path.display()))
+            })
+            .join(", ");
+
+        match (paths.is_empty(), maps.is_empty()) {
+            (true, true) => String::new(),
+            (false, true) => format!("; searched import paths {paths}"),
+            (true, false) => format!("; searched import maps {maps}"),
+            (false, false) => format!("; searched import paths {paths} and import maps {maps}"),
+        }
+    }
+
     /// Get line and the target symbol's offset from loc
     pub fn get_line_and_offset_from_loc(
         &self,
@@ -19,6 +19,7 @@ use file_resolver::FileResolver;
 use sema::diagnostics;
 use solang_parser::pt;
 use std::{ffi::OsStr, fmt};
+use tiny_keccak::{Hasher, Keccak};
 
 /// The target chain you want to compile Solidity for.
 #[derive(Debug, Clone, Copy)]
@@ -111,6 +112,23 @@ impl Target {
             _ => 4,
         }
     }
+
+    /// Compute the selector for a function, given its name and signature. Solana uses the
+    /// Anchor discriminator of the name, other targets use the keccak256 hash of the signature.
+    pub fn function_selector(&self, name: &str, signature: &str) -> Vec<u8> {
+        match self {
+            Target::Solana => abi::anchor::function_discriminator(name),
+            _ => {
+                let mut hash = [0u8; 32];
+
+                let mut hasher = Keccak::v256();
+                hasher.update(signature.as_bytes());
+                hasher.finalize(&mut hash);
+
+                hash[..self.selector_length() as usize].to_vec()
+            }
+        }
+    }
 }
 
 /// Compile a solidity file to list of wasm files and their ABIs.
@@ -150,7 +168,17 @@ pub fn compile(
         if contract.instantiable {
             let code = contract.emit(&ns, opts, contract_no);
 
-            let (abistr, _) = abi::generate_abi(contract_no, &ns, &code, false, &authors, version);
+            let (abistr, _) = abi::generate_abi(
+                contract_no,
+                &ns,
+                &code,
+                false,
+                &authors,
+                version,
+                resolver,
+                false,
+                abi::ethereum::AbiFormat::default(),
+            );
 
             results.push((code, abistr));
         };
@@ -159,6 +187,29 @@ pub fn compile(
     (results, ns)
 }
 
+/// Parse and resolve the Solidity source code like `parse_and_resolve`, and then generate the control
+/// flow graphs for all the contracts. This is useful for static analysis which needs the optimized
+/// cfgs, without emitting any code.
+///
+/// The cfgs of each contract can be found in `ns.contracts[contract_no].cfg`. The optimization passes
+/// are selected by `opts`; with `codegen::Options::default()`, unused variable elimination, constant
+/// folding, vector to slice, strength reduction, dead storage and common subexpression elimination
+/// are run. When constant folding is disabled, it still runs without modifying the cfg, so that
+/// overflows and divisions by zero are reported either way. If there are any errors, the contracts
+/// do not have any cfgs.
+pub fn compile_to_cfg(
+    filename: &OsStr,
+    resolver: &mut FileResolver,
+    target: Target,
+    opts: &codegen::Options,
+) -> sema::ast::Namespace {
+    let mut ns = parse_and_resolve(filename, resolver, target);
+
+    codegen::codegen(&mut ns, opts);
+
+    ns
+}
+
 /// Parse and resolve the Solidity source code provided in src, for the target chain as specified in target.
 /// The result is a list of resolved contracts (if successful) and a list of compiler warnings, errors and
 /// informational messages like `found contact N`.
@@ -168,9 +219,23 @@ pub fn parse_and_resolve(
     filename: &OsStr,
     resolver: &mut FileResolver,
     target: Target,
+) -> sema::ast::Namespace {
+    parse_and_resolve_with_max_errors(filename, resolver, target, None)
+}
+
+/// Parse and resolve the Solidity source code like `parse_and_resolve`, but stop resolving once
+/// `max_errors` errors have been found. The namespace may then be only partially resolved, and
+/// at most `max_errors` errors are reported.
+pub fn parse_and_resolve_with_max_errors(
+    filename: &OsStr,
+    resolver: &mut FileResolver,
+    target: Target,
+    max_errors: Option<usize>,
 ) -> sema::ast::Namespace {
     let mut ns = sema::ast::Namespace::new(target);
 
+    ns.diagnostics.set_max_errors(max_errors);
+
     match resolver.resolve_file(None, filename) {
         Err(message) => {
             ns.diagnostics.push(sema::ast::Diagnostic {
@@ -182,7 +247,8 @@ pub fn parse_and_resolve(
             });
         }
         Ok(file) => {
-            sema::sema(&file, resolver, &mut ns);
+            tracing::info_span!(target: "solang::passes", "sema")
+                .in_scope(|| sema::sema(&file, resolver, &mut ns));
         }
     }
 
@@ -190,3 +256,53 @@ pub fn parse_and_resolve(
 
     ns
 }
+
+/// The reason why `lint` failed
+#[derive(Debug, PartialEq, Eq)]
+pub enum LintError {
+    /// The source code has errors, like a syntax error or a missing file. The errors are listed
+    /// along with the warnings.
+    SourceErrors(Vec<sema::ast::Diagnostic>),
+    /// The source code has no errors, but it has warnings and these were to be treated as errors
+    WarningsAsErrors(Vec<sema::ast::Diagnostic>),
+}
+
+/// Parse and resolve the Solidity source code like `parse_and_resolve`, and return only the
+/// warnings. This is a quick lint pass, for example for a pre-commit hook or the problems panel
+/// of an editor; no code is generated.
+///
+/// If the source code has any errors, like a syntax error or a missing file, the errors and the
+/// warnings are returned as `LintError::SourceErrors`. If `warnings_as_errors` is set, any
+/// warnings are returned as `LintError::WarningsAsErrors`. Note that some warnings, like those
+/// about unused variables, are only produced if the source code has no errors.
+pub fn lint(
+    filename: &OsStr,
+    resolver: &mut FileResolver,
+    target: Target,
+    warnings_as_errors: bool,
+) -> Result<Vec<sema::ast::Diagnostic>, LintError> {
+    let ns = parse_and_resolve(filename, resolver, target);
+
+    if ns.diagnostics.any_errors() {
+        return Err(LintError::SourceErrors(
+            ns.diagnostics
+                .iter()
+                .filter(|diag| {
+                    matches!(
+                        diag.level,
+                        sema::ast::Level::Error | sema::ast::Level::Warning
+                    )
+                })
+                .cloned()
+                .collect(),
+        ));
+    }
+
+    let warnings: Vec<_> = ns.diagnostics.warnings().into_iter().cloned().collect();
+
+    if warnings_as_errors && !warnings.is_empty() {
+        Err(LintError::WarningsAsErrors(warnings))
+    } else {
+        Ok(warnings)
+    }
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::symtable::Symtable;
-use crate::abi::anchor::function_discriminator;
+use crate::abi::anchor::event_discriminator;
 use crate::codegen::cfg::{ControlFlowGraph, Instr};
 use crate::diagnostics::Diagnostics;
 use crate::sema::ast::ExternalCallAccounts::{AbsentArgument, NoAccount};
@@ -9,6 +9,7 @@ use crate::sema::yul::ast::{InlineAssembly, YulFunction};
 use crate::sema::Recurse;
 use crate::{codegen, Target};
 use indexmap::IndexMap;
+use ink_env::hash::{Blake2x256, CryptoHash};
 use num_bigint::BigInt;
 use num_rational::BigRational;
 use once_cell::unsync::OnceCell;
@@ -24,7 +25,6 @@ use std::{
     path::PathBuf,
     sync::Arc,
 };
-use tiny_keccak::{Hasher, Keccak};
 
 #[derive(PartialEq, Eq, Clone, Hash, Debug)]
 pub enum Type {
@@ -186,6 +186,17 @@ impl EventDecl {
             None => self.id.to_string(),
         }
     }
+
+    /// Generate the topic (or discriminator on Solana) which identifies this event
+    pub fn topic(&self, ns: &Namespace) -> Vec<u8> {
+        if ns.target == Target::Solana {
+            event_discriminator(&self.id.name)
+        } else {
+            let mut buf = [0; 32];
+            <Blake2x256 as CryptoHash>::hash(self.signature.as_bytes(), &mut buf);
+            buf.into()
+        }
+    }
 }
 
 #[derive(Default, PartialEq, Eq, Clone, Debug)]
@@ -473,26 +484,17 @@ impl Function {
     pub fn selector(&self, ns: &Namespace, contract_no: &usize) -> Vec<u8> {
         if let Some((_, selector)) = &self.selector {
             selector.clone()
-        } else if ns.target == Target::Solana {
-            match self.ty {
-                FunctionTy::Constructor => function_discriminator("new"),
-                _ => {
-                    let discriminator_image = if self.mangled_name_contracts.contains(contract_no) {
-                        &self.mangled_name
-                    } else {
-                        &self.id.name
-                    };
-                    function_discriminator(discriminator_image.as_str())
-                }
-            }
         } else {
-            let mut res = [0u8; 32];
-
-            let mut hasher = Keccak::v256();
-            hasher.update(self.signature.as_bytes());
-            hasher.finalize(&mut res);
-
-            res[..4].to_vec()
+            // Solana discriminators are derived from the name, not the signature
+            let name = if self.ty == FunctionTy::Constructor {
+                "new"
+            } else if self.mangled_name_contracts.contains(contract_no) {
+                &self.mangled_name
+            } else {
+                &self.id.name
+            };
+
+            ns.target.function_selector(name, &self.signature)
         }
     }
 
@@ -658,6 +660,9 @@ pub struct File {
     pub path: PathBuf,
     /// Used for offset to line-column conversions
     pub line_starts: Vec<usize>,
+    /// Offsets of the multibyte characters and their number of extra bytes, so that columns
+    /// can be given in characters rather than bytes
+    pub multibyte_chars: Vec<(usize, usize)>,
     /// Indicates the file number in FileResolver.files
     pub cache_no: Option<usize>,
     /// Index into FileResolver.import_paths. This is `None` when this File was
@@ -947,6 +952,8 @@ pub enum Expression {
     Divide {
         loc: pt::Loc,
         ty: Type,
+        /// Do not check for overflow, i.e. in `unchecked {}` block
+        unchecked: bool,
         left: Box<Expression>,
         right: Box<Expression>,
     },
@@ -1850,6 +1857,7 @@ pub struct TryCatch {
 
 #[derive(Clone, Debug)]
 pub struct CatchClause {
+    pub loc: pt::Loc,
     pub param: Option<Parameter<Type>>,
     pub param_pos: Option<usize>,
     pub stmt: Vec<Statement>,
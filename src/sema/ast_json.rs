@@ -0,0 +1,491 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ast::{
+    CodeLocation, DestructureField, Expression, Function, Namespace, Parameter, Statement, Type,
+    Variable,
+};
+use super::file::PathDisplay;
+use super::Recurse;
+use num_traits::ToPrimitive;
+use serde_json::{json, Value};
+use solang_parser::pt;
+
+impl Namespace {
+    /// Serialize the resolved AST to JSON. Unlike the parse tree, every expression has its
+    /// resolved types, and variables, functions, contracts and structs are referred to by
+    /// their number in the namespace. Types are written as their name, so recursive structs
+    /// do not need to be expanded; their fields are listed once in `structs`.
+    pub fn ast_to_json(&self) -> Value {
+        let structs = self
+            .structs
+            .iter()
+            .enumerate()
+            .map(|(struct_no, decl)| {
+                json!({
+                    "id": struct_no,
+                    "name": decl.id.name,
+                    "contract": decl.contract,
+                    "loc": self.json_loc(&decl.loc),
+                    "fields": self.params_to_json(&decl.fields),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let enums = self
+            .enums
+            .iter()
+            .enumerate()
+            .map(|(enum_no, decl)| {
+                json!({
+                    "id": enum_no,
+                    "name": decl.id.name,
+                    "contract": decl.contract,
+                    "loc": self.json_loc(&decl.loc),
+                    "values": decl.values.keys().collect::<Vec<_>>(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let events = self
+            .events
+            .iter()
+            .enumerate()
+            .map(|(event_no, decl)| {
+                json!({
+                    "id": event_no,
+                    "name": decl.id.name,
+                    "loc": self.json_loc(&decl.loc),
+                    "fields": self.params_to_json(&decl.fields),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let errors = self
+            .errors
+            .iter()
+            .enumerate()
+            .map(|(error_no, decl)| {
+                json!({
+                    "id": error_no,
+                    "name": decl.name,
+                    "loc": self.json_loc(&decl.loc),
+                    "fields": self.params_to_json(&decl.fields),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let contracts = self
+            .contracts
+            .iter()
+            .enumerate()
+            .map(|(contract_no, contract)| {
+                let variables = contract
+                    .variables
+                    .iter()
+                    .enumerate()
+                    .map(|(var_no, var)| self.variable_to_json(var_no, var))
+                    .collect::<Vec<_>>();
+
+                json!({
+                    "id": contract_no,
+                    "name": contract.id.name,
+                    "ty": contract.ty.to_string(),
+                    "loc": self.json_loc(&contract.loc),
+                    "bases": contract.bases.iter().map(|base| base.contract_no).collect::<Vec<_>>(),
+                    "variables": variables,
+                    "functions": contract.functions,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let functions = self
+            .functions
+            .iter()
+            .enumerate()
+            .map(|(function_no, func)| self.function_to_json(function_no, func))
+            .collect::<Vec<_>>();
+
+        let constants = self
+            .constants
+            .iter()
+            .enumerate()
+            .map(|(var_no, var)| self.variable_to_json(var_no, var))
+            .collect::<Vec<_>>();
+
+        json!({
+            "file": format!("{}", self.files[self.top_file_no()].path.display()),
+            "structs": structs,
+            "enums": enums,
+            "events": events,
+            "errors": errors,
+            "constants": constants,
+            "contracts": contracts,
+            "functions": functions,
+        })
+    }
+
+    fn json_loc(&self, loc: &pt::Loc) -> Value {
+        match loc {
+            pt::Loc::File(..) => json!(self.loc_to_string(PathDisplay::FullPath, loc)),
+            _ => Value::Null,
+        }
+    }
+
+    fn params_to_json(&self, params: &[Parameter<Type>]) -> Value {
+        params
+            .iter()
+            .map(|param| {
+                json!({
+                    "name": param.name_as_str(),
+                    "type": param.ty.to_string(self),
+                })
+            })
+            .collect()
+    }
+
+    fn variable_to_json(&self, var_no: usize, var: &Variable) -> Value {
+        json!({
+            "id": var_no,
+            "name": var.name,
+            "type": var.ty.to_string(self),
+            "loc": self.json_loc(&var.loc),
+            "visibility": var.visibility.to_string(),
+            "constant": var.constant,
+            "immutable": var.immutable,
+            "initializer": var.initializer.as_ref().map(|expr| self.expression_to_json(expr)),
+        })
+    }
+
+    fn function_to_json(&self, function_no: usize, func: &Function) -> Value {
+        let variables = func
+            .symtable
+            .vars
+            .iter()
+            .map(|(var_no, var)| {
+                json!({
+                    "id": var_no,
+                    "name": var.id.name,
+                    "type": var.ty.to_string(self),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        json!({
+            "id": function_no,
+            "name": func.id.name,
+            "contract": func.contract_no,
+            "ty": func.ty.to_string(),
+            "signature": func.signature,
+            "loc": self.json_loc(&func.loc_prototype),
+            "visibility": func.visibility.to_string(),
+            "mutability": func.mutability.to_string(),
+            "params": self.params_to_json(&func.params),
+            "returns": self.params_to_json(&func.returns),
+            "modifiers": func.modifiers.iter().map(|expr| self.expression_to_json(expr)).collect::<Vec<_>>(),
+            "variables": variables,
+            "body": self.statements_to_json(&func.body),
+        })
+    }
+
+    fn statements_to_json(&self, stmts: &[Statement]) -> Value {
+        stmts
+            .iter()
+            .map(|stmt| self.statement_to_json(stmt))
+            .collect()
+    }
+
+    fn statement_to_json(&self, stmt: &Statement) -> Value {
+        let loc = self.json_loc(&stmt.loc());
+
+        match stmt {
+            Statement::Block {
+                unchecked,
+                statements,
+                ..
+            } => json!({
+                "kind": "Block",
+                "loc": loc,
+                "unchecked": unchecked,
+                "statements": self.statements_to_json(statements),
+            }),
+            Statement::VariableDecl(_, var_no, param, init) => json!({
+                "kind": "VariableDecl",
+                "loc": loc,
+                "var_no": var_no,
+                "name": param.name_as_str(),
+                "type": param.ty.to_string(self),
+                "initializer": init.as_ref().map(|expr| self.expression_to_json(expr)),
+            }),
+            Statement::If(_, _, cond, then_stmt, else_stmt) => json!({
+                "kind": "If",
+                "loc": loc,
+                "cond": self.expression_to_json(cond),
+                "then": self.statements_to_json(then_stmt),
+                "else": self.statements_to_json(else_stmt),
+            }),
+            Statement::While(_, _, cond, body) => json!({
+                "kind": "While",
+                "loc": loc,
+                "cond": self.expression_to_json(cond),
+                "body": self.statements_to_json(body),
+            }),
+            Statement::For {
+                init,
+                cond,
+                next,
+                body,
+                ..
+            } => json!({
+                "kind": "For",
+                "loc": loc,
+                "init": self.statements_to_json(init),
+                "cond": cond.as_ref().map(|expr| self.expression_to_json(expr)),
+                "next": next.as_ref().map(|expr| self.expression_to_json(expr)),
+                "body": self.statements_to_json(body),
+            }),
+            Statement::DoWhile(_, _, body, cond) => json!({
+                "kind": "DoWhile",
+                "loc": loc,
+                "body": self.statements_to_json(body),
+                "cond": self.expression_to_json(cond),
+            }),
+            Statement::Expression(_, _, expr) => json!({
+                "kind": "Expression",
+                "loc": loc,
+                "expr": self.expression_to_json(expr),
+            }),
+            Statement::Delete(_, ty, expr) => json!({
+                "kind": "Delete",
+                "loc": loc,
+                "type": ty.to_string(self),
+                "expr": self.expression_to_json(expr),
+            }),
+            Statement::Destructure(_, fields, expr) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| match field {
+                        DestructureField::None => Value::Null,
+                        DestructureField::Expression(expr) => json!({
+                            "expr": self.expression_to_json(expr),
+                        }),
+                        DestructureField::VariableDecl(var_no, param) => json!({
+                            "var_no": var_no,
+                            "name": param.name_as_str(),
+                            "type": param.ty.to_string(self),
+                        }),
+                    })
+                    .collect::<Vec<_>>();
+
+                json!({
+                    "kind": "Destructure",
+                    "loc": loc,
+                    "fields": fields,
+                    "expr": self.expression_to_json(expr),
+                })
+            }
+            Statement::Continue(_) => json!({ "kind": "Continue", "loc": loc }),
+            Statement::Break(_) => json!({ "kind": "Break", "loc": loc }),
+            Statement::Underscore(_) => json!({ "kind": "Underscore", "loc": loc }),
+            Statement::Return(_, expr) => json!({
+                "kind": "Return",
+                "loc": loc,
+                "expr": expr.as_ref().map(|expr| self.expression_to_json(expr)),
+            }),
+            Statement::Revert { error_no, args, .. } => json!({
+                "kind": "Revert",
+                "loc": loc,
+                "error": error_no,
+                "args": args.iter().map(|arg| self.expression_to_json(arg)).collect::<Vec<_>>(),
+            }),
+            Statement::Emit { event_no, args, .. } => json!({
+                "kind": "Emit",
+                "loc": loc,
+                "event": event_no,
+                "args": args.iter().map(|arg| self.expression_to_json(arg)).collect::<Vec<_>>(),
+            }),
+            Statement::TryCatch(_, _, try_catch) => {
+                let clauses = try_catch
+                    .errors
+                    .iter()
+                    .chain(try_catch.catch_all.iter())
+                    .map(|clause| {
+                        json!({
+                            "param": clause.param.as_ref().map(|param| json!({
+                                "var_no": clause.param_pos,
+                                "name": param.name_as_str(),
+                                "type": param.ty.to_string(self),
+                            })),
+                            "body": self.statements_to_json(&clause.stmt),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                json!({
+                    "kind": "TryCatch",
+                    "loc": loc,
+                    "expr": self.expression_to_json(&try_catch.expr),
+                    "ok": self.statements_to_json(&try_catch.ok_stmt),
+                    "catch": clauses,
+                })
+            }
+            // the Yul AST is not serialized
+            Statement::Assembly(..) => json!({ "kind": "Assembly", "loc": loc }),
+        }
+    }
+
+    fn expression_to_json(&self, expr: &Expression) -> Value {
+        let mut children = ChildExpressions {
+            ns: self,
+            root: true,
+            children: Vec::new(),
+        };
+
+        expr.recurse(&mut children, child_expression);
+
+        let mut value = json!({
+            "kind": expression_kind(expr),
+            "loc": self.json_loc(&expr.loc()),
+            "types": expr.tys().iter().map(|ty| ty.to_string(self)).collect::<Vec<_>>(),
+        });
+
+        let fields = value.as_object_mut().unwrap();
+
+        match expr {
+            Expression::BoolLiteral { value, .. } => {
+                fields.insert("value".into(), json!(value));
+            }
+            Expression::NumberLiteral { value, .. } => {
+                // large numbers do not fit in a json number
+                let value = match value.to_i64() {
+                    Some(value) => json!(value),
+                    None => json!(value.to_string()),
+                };
+                fields.insert("value".into(), value);
+            }
+            Expression::BytesLiteral { value, .. } => {
+                fields.insert("value".into(), json!(hex::encode(value)));
+            }
+            Expression::Variable { var_no, .. } => {
+                fields.insert("var_no".into(), json!(var_no));
+            }
+            Expression::ConstantVariable {
+                contract_no,
+                var_no,
+                ..
+            }
+            | Expression::StorageVariable {
+                contract_no,
+                var_no,
+                ..
+            } => {
+                fields.insert("contract_no".into(), json!(contract_no));
+                fields.insert("var_no".into(), json!(var_no));
+            }
+            Expression::InternalFunction { function_no, .. }
+            | Expression::ExternalFunction { function_no, .. } => {
+                fields.insert("function_no".into(), json!(function_no));
+            }
+            Expression::Constructor { contract_no, .. } => {
+                fields.insert("contract_no".into(), json!(contract_no));
+            }
+            Expression::Builtin { kind, .. } => {
+                fields.insert("builtin".into(), json!(format!("{kind:?}")));
+            }
+            _ => (),
+        }
+
+        if !children.children.is_empty() {
+            fields.insert("children".into(), Value::Array(children.children));
+        }
+
+        value
+    }
+}
+
+/// Collects the direct sub-expressions of an expression
+struct ChildExpressions<'a> {
+    ns: &'a Namespace,
+    root: bool,
+    children: Vec<Value>,
+}
+
+fn child_expression(expr: &Expression, cx: &mut ChildExpressions) -> bool {
+    if cx.root {
+        cx.root = false;
+        return true;
+    }
+
+    cx.children.push(cx.ns.expression_to_json(expr));
+
+    false
+}
+
+fn expression_kind(expr: &Expression) -> &'static str {
+    match expr {
+        Expression::BoolLiteral { .. } => "BoolLiteral",
+        Expression::BytesLiteral { .. } => "BytesLiteral",
+        Expression::NumberLiteral { .. } => "NumberLiteral",
+        Expression::RationalNumberLiteral { .. } => "RationalNumberLiteral",
+        Expression::StructLiteral { .. } => "StructLiteral",
+        Expression::ArrayLiteral { .. } => "ArrayLiteral",
+        Expression::ConstArrayLiteral { .. } => "ConstArrayLiteral",
+        Expression::Add { .. } => "Add",
+        Expression::Subtract { .. } => "Subtract",
+        Expression::Multiply { .. } => "Multiply",
+        Expression::Divide { .. } => "Divide",
+        Expression::Modulo { .. } => "Modulo",
+        Expression::Power { .. } => "Power",
+        Expression::BitwiseOr { .. } => "BitwiseOr",
+        Expression::BitwiseAnd { .. } => "BitwiseAnd",
+        Expression::BitwiseXor { .. } => "BitwiseXor",
+        Expression::ShiftLeft { .. } => "ShiftLeft",
+        Expression::ShiftRight { .. } => "ShiftRight",
+        Expression::Variable { .. } => "Variable",
+        Expression::ConstantVariable { .. } => "ConstantVariable",
+        Expression::StorageVariable { .. } => "StorageVariable",
+        Expression::Load { .. } => "Load",
+        Expression::GetRef { .. } => "GetRef",
+        Expression::StorageLoad { .. } => "StorageLoad",
+        Expression::ZeroExt { .. } => "ZeroExt",
+        Expression::SignExt { .. } => "SignExt",
+        Expression::Trunc { .. } => "Trunc",
+        Expression::CheckingTrunc { .. } => "CheckingTrunc",
+        Expression::Cast { .. } => "Cast",
+        Expression::BytesCast { .. } => "BytesCast",
+        Expression::PreIncrement { .. } => "PreIncrement",
+        Expression::PreDecrement { .. } => "PreDecrement",
+        Expression::PostIncrement { .. } => "PostIncrement",
+        Expression::PostDecrement { .. } => "PostDecrement",
+        Expression::Assign { .. } => "Assign",
+        Expression::More { .. } => "More",
+        Expression::Less { .. } => "Less",
+        Expression::MoreEqual { .. } => "MoreEqual",
+        Expression::LessEqual { .. } => "LessEqual",
+        Expression::Equal { .. } => "Equal",
+        Expression::NotEqual { .. } => "NotEqual",
+        Expression::Not { .. } => "Not",
+        Expression::BitwiseNot { .. } => "BitwiseNot",
+        Expression::Negate { .. } => "Negate",
+        Expression::ConditionalOperator { .. } => "ConditionalOperator",
+        Expression::Subscript { .. } => "Subscript",
+        Expression::NamedMember { .. } => "NamedMember",
+        Expression::StructMember { .. } => "StructMember",
+        Expression::AllocDynamicBytes { .. } => "AllocDynamicBytes",
+        Expression::StorageArrayLength { .. } => "StorageArrayLength",
+        Expression::StringCompare { .. } => "StringCompare",
+        Expression::Or { .. } => "Or",
+        Expression::And { .. } => "And",
+        Expression::InternalFunction { .. } => "InternalFunction",
+        Expression::ExternalFunction { .. } => "ExternalFunction",
+        Expression::InternalFunctionCall { .. } => "InternalFunctionCall",
+        Expression::ExternalFunctionCall { .. } => "ExternalFunctionCall",
+        Expression::ExternalFunctionCallRaw { .. } => "ExternalFunctionCallRaw",
+        Expression::Constructor { .. } => "Constructor",
+        Expression::FormatString { .. } => "FormatString",
+        Expression::Builtin { .. } => "Builtin",
+        Expression::List { .. } => "List",
+        Expression::UserDefinedOperator { .. } => "UserDefinedOperator",
+        Expression::EventSelector { .. } => "EventSelector",
+        Expression::TypeOperator { .. } => "TypeOperator",
+    }
+}
@@ -893,7 +893,7 @@ pub fn builtin_var(
                 diagnostics.push(Diagnostic::error(
                     *loc,
                     String::from(
-                        "Solana Cross Program Invocation (CPI) cannot transfer native value. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer",
+                        "'msg.value' is not available on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer",
                     ),
                 ));
             }
@@ -912,6 +912,39 @@ pub fn builtin_var(
     None
 }
 
+/// Some builtin variables do not exist on all targets for a reason which is worth explaining
+pub fn builtin_var_unavailable(
+    namespace: Option<&str>,
+    fname: &str,
+    ns: &Namespace,
+) -> Option<String> {
+    match (namespace, fname, &ns.target) {
+        (Some("block"), "chainid", Target::Solana) => Some(
+            "'block.chainid' is not available on Solana, as Solana clusters do not have a chain id"
+                .into(),
+        ),
+        (Some("block"), "chainid", Target::Polkadot { .. }) => Some(
+            "'block.chainid' is not available on Polkadot, as contracts cannot access a chain id or the genesis hash of the chain".into(),
+        ),
+        _ => None,
+    }
+}
+
+/// Some builtin functions do not exist on all targets for a reason which is worth explaining
+pub fn builtin_call_unavailable(
+    namespace: Option<&str>,
+    fname: &str,
+    ns: &Namespace,
+) -> Option<String> {
+    match (namespace, fname, &ns.target) {
+        (None, "selfdestruct", Target::Solana) => Some(
+            "'selfdestruct' is not available on Solana, as programs cannot be deleted by themselves. Use the program close instruction of the loader instead"
+                .into(),
+        ),
+        _ => None,
+    }
+}
+
 /// Does variable name match any builtin namespace
 pub fn builtin_namespace(namespace: &str) -> bool {
     BUILTIN_VARIABLE
@@ -1027,6 +1060,15 @@ pub(super) fn resolve_call(
                 }
             }
 
+            if ns.target == Target::EVM && func.builtin == Builtin::SelfDestruct {
+                diagnostics.push(Diagnostic::warning(
+                    *loc,
+                    String::from(
+                        "'selfdestruct' has been deprecated. Since EIP-6780, the code and storage of the contract are only removed if it was created in the same transaction",
+                    ),
+                ));
+            }
+
             diagnostics.extend(candidate_diagnostics);
 
             return Ok(Expression::Builtin {
@@ -1079,6 +1121,33 @@ pub(super) fn resolve_namespace_call(
             resolved_args.push(expr.cast(loc, &ty, true, ns, diagnostics)?);
         }
 
+        // if all the arguments are literals, the result is a literal too
+        let literals = resolved_args
+            .iter()
+            .map(|arg| match arg {
+                Expression::AllocDynamicBytes {
+                    init: Some(value), ..
+                }
+                | Expression::BytesLiteral { value, .. } => Some(value.as_slice()),
+                _ => None,
+            })
+            .collect::<Option<Vec<&[u8]>>>();
+
+        if let Some(literals) = literals {
+            let value = literals.concat();
+
+            return Ok(Expression::AllocDynamicBytes {
+                loc: *loc,
+                ty,
+                length: Box::new(Expression::NumberLiteral {
+                    loc: *loc,
+                    ty: Type::Uint(32),
+                    value: BigInt::from(value.len()),
+                }),
+                init: Some(value),
+            });
+        }
+
         return Ok(Expression::Builtin {
             loc: *loc,
             tys: vec![ty],
@@ -1469,6 +1538,7 @@ impl Namespace {
         self.files.push(File {
             path: PathBuf::from("solana"),
             line_starts: Vec::new(),
+            multibyte_chars: Vec::new(),
             cache_no: None,
             import_no: None,
         });
@@ -1665,6 +1735,7 @@ impl Namespace {
         self.files.push(File {
             path: PathBuf::from("polkadot"),
             line_starts: Vec::new(),
+            multibyte_chars: Vec::new(),
             cache_no: None,
             import_no: None,
         });
@@ -736,6 +736,51 @@ fn polkadot_requires_public_functions(contract_no: usize, ns: &mut ast::Namespac
     }
 }
 
+/// Warn about contracts which have functions, but none of them can be called: there are no public
+/// or external functions, no constructor, fallback or receive function. Polkadot already rejects
+/// such contracts, and contracts which are used as a base contract are not checked.
+pub(super) fn check_contracts_have_entry_points(ns: &mut ast::Namespace) {
+    if ns.target.is_polkadot() {
+        return;
+    }
+
+    for contract_no in 0..ns.contracts.len() {
+        let contract = &ns.contracts[contract_no];
+
+        if !contract.is_concrete()
+            || contract.functions.is_empty()
+            || ns
+                .contracts
+                .iter()
+                .any(|c| c.bases.iter().any(|base| base.contract_no == contract_no))
+        {
+            continue;
+        }
+
+        let callable = contract.all_functions.keys().any(|func_no| {
+            let func = &ns.functions[*func_no];
+
+            match func.ty {
+                pt::FunctionTy::Function => func.is_public(),
+                pt::FunctionTy::Constructor
+                | pt::FunctionTy::Fallback
+                | pt::FunctionTy::Receive => true,
+                pt::FunctionTy::Modifier => false,
+            }
+        });
+
+        if !callable {
+            ns.diagnostics.push(ast::Diagnostic::warning(
+                contract.loc,
+                format!(
+                    "contract '{}' has no public or external functions, so none of its code can be called",
+                    contract.id
+                ),
+            ));
+        }
+    }
+}
+
 /// Constructors and functions are no different pallet contracts.
 /// This function checks that all constructors and function names are unique.
 /// Overloading (mangled function or constructor names) is taken into account.
@@ -1057,6 +1102,12 @@ fn resolve_bodies(
         annotations,
     } in bodies
     {
+        // stop resolving once we have found enough errors
+        if ns.diagnostics.error_limit_reached() {
+            broken = true;
+            break;
+        }
+
         if statements::resolve_function_body(
             function,
             &annotations,
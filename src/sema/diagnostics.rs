@@ -5,6 +5,7 @@ use crate::file_resolver::FileResolver;
 use crate::standard_json::{LocJson, OutputJson};
 use codespan_reporting::{diagnostic, files, term};
 use itertools::Itertools;
+use serde_json::json;
 use solang_parser::pt::Loc;
 use std::{
     collections::HashMap,
@@ -16,9 +17,23 @@ use std::{
 pub struct Diagnostics {
     contents: Vec<Diagnostic>,
     has_error: bool,
+    max_errors: Option<usize>,
 }
 
 impl Diagnostics {
+    /// Stop resolving once this many errors have been found
+    pub fn set_max_errors(&mut self, max_errors: Option<usize>) {
+        self.max_errors = max_errors;
+    }
+
+    /// Have we found as many errors as we are allowed to report
+    pub fn error_limit_reached(&self) -> bool {
+        match self.max_errors {
+            Some(max_errors) => self.count_errors() >= max_errors,
+            None => false,
+        }
+    }
+
     pub fn any_errors(&self) -> bool {
         self.has_error
     }
@@ -51,6 +66,12 @@ impl Diagnostics {
         self.contents.extend(diagnostics.contents);
     }
 
+    /// Keep only the diagnostics for which `f` returns true
+    pub fn retain(&mut self, f: impl FnMut(&Diagnostic) -> bool) {
+        self.contents.retain(f);
+        self.has_error = self.contents.iter().any(|m| m.level == Level::Error);
+    }
+
     pub fn append(&mut self, diagnostics: &mut Vec<Diagnostic>) {
         if !self.has_error {
             self.has_error = diagnostics.iter().any(|m| m.level == Level::Error);
@@ -65,6 +86,13 @@ impl Diagnostics {
         }
     }
 
+    pub fn count_errors(&self) -> usize {
+        self.contents
+            .iter()
+            .filter(|&x| x.level == Level::Error)
+            .count()
+    }
+
     pub fn count_warnings(&self) -> usize {
         self.contents
             .iter()
@@ -125,7 +153,39 @@ impl Diagnostics {
     // of location
     pub fn sort_and_dedup(&mut self) {
         self.contents.sort();
-        self.contents.dedup();
+
+        // The same diagnostic may be found for a base contract in the context of
+        // different derived contracts, with different notes. Report it once, with all the notes.
+        self.contents.dedup_by(|next, prev| {
+            if next.loc == prev.loc
+                && next.level == prev.level
+                && next.ty == prev.ty
+                && next.message == prev.message
+            {
+                for note in next.notes.drain(..) {
+                    if !prev.notes.contains(&note) {
+                        prev.notes.push(note);
+                    }
+                }
+                true
+            } else {
+                false
+            }
+        });
+
+        // Only report the first errors if there is an error limit; warnings are kept
+        if let Some(max_errors) = self.max_errors {
+            let mut errors = 0;
+
+            self.contents.retain(|diag| {
+                if diag.level == Level::Error {
+                    errors += 1;
+                    errors <= max_errors
+                } else {
+                    true
+                }
+            });
+        }
     }
 }
 
@@ -168,12 +228,20 @@ fn convert_diagnostic(
 impl Namespace {
     /// Print the diagnostics to stdout with plain formatting
     pub fn print_diagnostics_in_plain(&self, cache: &FileResolver, debug: bool) {
+        let min_level = if debug { Level::Debug } else { Level::Info };
+
+        self.print_diagnostics_filtered(cache, min_level);
+    }
+
+    /// Print the diagnostics with a level of at least `min_level` to stdout with plain formatting,
+    /// e.g. `Level::Error` only prints the errors
+    pub fn print_diagnostics_filtered(&self, cache: &FileResolver, min_level: Level) {
         let (files, file_id) = self.convert_files(cache);
 
         let config = term::Config::default();
 
         for msg in self.diagnostics.iter() {
-            if msg.level == Level::Debug && !debug {
+            if msg.level < min_level {
                 continue;
             }
 
@@ -248,6 +316,58 @@ impl Namespace {
         json
     }
 
+    /// Serialize the diagnostics into a machine readable JSON array. Each entry has the level,
+    /// message, location and notes of the diagnostic. File locations are resolved to 1-based
+    /// line and column numbers; columns count characters, not bytes.
+    pub fn diagnostics_to_json(&self) -> serde_json::Value {
+        let diagnostics = self
+            .diagnostics
+            .iter()
+            .map(|msg| {
+                let notes = msg
+                    .notes
+                    .iter()
+                    .map(|note| {
+                        json!({
+                            "message": note.message,
+                            "location": self.loc_to_json(&note.loc),
+                        })
+                    })
+                    .collect::<Vec<_>>();
+
+                json!({
+                    "level": msg.level.to_string(),
+                    "type": format!("{:?}", msg.ty),
+                    "message": msg.message,
+                    "location": self.loc_to_json(&msg.loc),
+                    "notes": notes,
+                })
+            })
+            .collect();
+
+        serde_json::Value::Array(diagnostics)
+    }
+
+    fn loc_to_json(&self, loc: &Loc) -> serde_json::Value {
+        match loc {
+            Loc::File(file_no, ..) => {
+                let (start_line, start_column, end_line, end_column) =
+                    self.loc_to_range(loc).unwrap();
+
+                json!({
+                    "kind": "file",
+                    "file": format!("{}", self.files[*file_no]),
+                    "start": { "line": start_line, "column": start_column },
+                    "end": { "line": end_line, "column": end_column },
+                })
+            }
+            Loc::Builtin => json!({ "kind": "builtin" }),
+            Loc::Codegen => json!({ "kind": "codegen" }),
+            Loc::Implicit => json!({ "kind": "implicit" }),
+            Loc::CommandLine => json!({ "kind": "commandline" }),
+        }
+    }
+
     fn convert_files(
         &self,
         cache: &FileResolver,
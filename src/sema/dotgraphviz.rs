@@ -444,6 +444,7 @@ impl Dot {
                 ty,
                 left,
                 right,
+                ..
             } => {
                 let labels = vec![
                     String::from("divide"),
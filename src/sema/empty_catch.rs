@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ast::{Diagnostic, Namespace, Statement};
+use super::Recurse;
+
+impl Namespace {
+    /// Warn about every catch clause which catches all errors and has an empty body, like
+    /// `catch (bytes) {}`. Such a clause silently ignores any failure of the call. All functions
+    /// and modifiers are checked, including those in abstract contracts and free functions.
+    pub fn check_empty_catch(&mut self) {
+        let mut locs = Vec::new();
+
+        for func in &self.functions {
+            for stmt in &func.body {
+                stmt.recurse(&mut locs, |stmt, locs| {
+                    if let Statement::TryCatch(_, _, try_catch) = stmt {
+                        if let Some(clause) = &try_catch.catch_all {
+                            if clause.stmt.iter().all(is_empty_block) {
+                                locs.push(clause.loc);
+                            }
+                        }
+                    }
+                    true
+                });
+            }
+        }
+
+        for loc in locs {
+            self.diagnostics.push(Diagnostic::warning(
+                loc,
+                "catch clause has an empty body, so any failure of the call is silently ignored"
+                    .into(),
+            ));
+        }
+
+        self.diagnostics.sort_and_dedup();
+    }
+}
+
+fn is_empty_block(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::Block { statements, .. } => statements.iter().all(is_empty_block),
+        _ => false,
+    }
+}
@@ -13,6 +13,7 @@ use num_traits::ToPrimitive;
 use num_traits::Zero;
 use solang_parser::pt;
 use solang_parser::pt::{CodeLocation, Loc};
+use std::cmp::Ordering;
 use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Shl, Shr, Sub};
 
 /// This enum specifies the error `eval_const_number` is returning
@@ -235,6 +236,28 @@ pub fn eval_const_number(
 
             Ok((*loc, value))
         }
+        Expression::ConditionalOperator {
+            loc,
+            cond,
+            true_option,
+            false_option,
+            ..
+        } => {
+            let option = match eval_const_bool(cond, ns) {
+                Some(true) => true_option,
+                Some(false) => false_option,
+                None => {
+                    diagnostics.push(Diagnostic::error(
+                        cond.loc(),
+                        "condition is not a compile-time constant".to_string(),
+                    ));
+
+                    return Err(EvaluationError::NotAConstant);
+                }
+            };
+
+            Ok((*loc, eval_const_number(option, ns, diagnostics)?.1))
+        }
         _ => {
             diagnostics.push(Diagnostic::error(
                 expr.loc(),
@@ -298,6 +321,29 @@ pub fn eval_const_rational(
                 Ok((*loc, eval_const_rational(l, ns)?.1 % divisor))
             }
         }
+        Expression::Power { loc, base, exp, .. } => {
+            let base = eval_const_rational(base, ns)?.1;
+            let exp = eval_const_rational(exp, ns)?.1;
+
+            if !exp.is_integer() {
+                Err(Diagnostic::error(
+                    *loc,
+                    "exponent of rational power must be an integer".to_string(),
+                ))
+            } else if exp < BigRational::zero() {
+                Err(Diagnostic::error(
+                    *loc,
+                    "power cannot take negative number as exponent".to_string(),
+                ))
+            } else if let Some(exp) = exp.to_integer().to_u16() {
+                Ok((*loc, base.pow(exp as i32)))
+            } else {
+                Err(Diagnostic::error(*loc, format!("power {exp} not possible")))
+            }
+        }
+        Expression::ShiftLeft { loc, .. } | Expression::ShiftRight { loc, .. } => Err(
+            Diagnostic::error(*loc, "cannot shift a rational".to_string()),
+        ),
         Expression::NumberLiteral { loc, value, .. } => {
             Ok((*loc, BigRational::from_integer(value.clone())))
         }
@@ -333,6 +379,66 @@ pub fn eval_const_rational(
     }
 }
 
+/// Resolve a boolean expression to its value, if it is a compile-time constant. Like at runtime,
+/// the right hand side of `&&` and `||` is not evaluated if the left hand side decides the result.
+pub fn eval_const_bool(expr: &Expression, ns: &Namespace) -> Option<bool> {
+    match expr {
+        Expression::BoolLiteral { value, .. } => Some(*value),
+        Expression::Not { expr, .. } => eval_const_bool(expr, ns).map(|value| !value),
+        Expression::And { left, right, .. } => {
+            Some(eval_const_bool(left, ns)? && eval_const_bool(right, ns)?)
+        }
+        Expression::Or { left, right, .. } => {
+            Some(eval_const_bool(left, ns)? || eval_const_bool(right, ns)?)
+        }
+        Expression::More { left, right, .. } => {
+            eval_const_compare(left, right, ns).map(Ordering::is_gt)
+        }
+        Expression::Less { left, right, .. } => {
+            eval_const_compare(left, right, ns).map(Ordering::is_lt)
+        }
+        Expression::MoreEqual { left, right, .. } => {
+            eval_const_compare(left, right, ns).map(Ordering::is_ge)
+        }
+        Expression::LessEqual { left, right, .. } => {
+            eval_const_compare(left, right, ns).map(Ordering::is_le)
+        }
+        Expression::Equal { left, right, .. } => eval_const_compare(left, right, ns)
+            .map(Ordering::is_eq)
+            .or_else(|| Some(eval_const_bool(left, ns)? == eval_const_bool(right, ns)?)),
+        Expression::NotEqual { left, right, .. } => eval_const_compare(left, right, ns)
+            .map(Ordering::is_ne)
+            .or_else(|| Some(eval_const_bool(left, ns)? != eval_const_bool(right, ns)?)),
+        Expression::ConstantVariable {
+            contract_no: Some(contract_no),
+            var_no,
+            ..
+        } => eval_const_bool(
+            ns.contracts[*contract_no].variables[*var_no]
+                .initializer
+                .as_ref()?,
+            ns,
+        ),
+        Expression::ConstantVariable {
+            contract_no: None,
+            var_no,
+            ..
+        } => eval_const_bool(ns.constants[*var_no].initializer.as_ref()?, ns),
+        _ => None,
+    }
+}
+
+/// Compare two expressions, if both are compile-time constant numbers. Any errors are reported
+/// when the expressions are resolved, so they are not reported again here.
+fn eval_const_compare(left: &Expression, right: &Expression, ns: &Namespace) -> Option<Ordering> {
+    let mut diagnostics = Diagnostics::default();
+
+    let left = eval_const_number(left, ns, &mut diagnostics).ok()?.1;
+    let right = eval_const_number(right, ns, &mut diagnostics).ok()?.1;
+
+    Some(left.cmp(&right))
+}
+
 impl Expression {
     /// Check the expression for constant overflows, e.g. `uint8 a = 100 + 200;`.
     pub fn check_constant_overflow(&self, diagnostics: &mut Diagnostics) {
@@ -344,6 +450,31 @@ impl Expression {
 /// If the expression is an arithmetic operation of two number literals, overflow_check() will be called on the result.
 fn check_term_for_constant_overflow(expr: &Expression, diagnostics: &mut Diagnostics) -> bool {
     match expr {
+        // arithmetic in an `unchecked {}` block wraps around rather than overflowing, but its
+        // operands may still have errors like division by zero
+        Expression::Add {
+            unchecked: true, ..
+        }
+        | Expression::Subtract {
+            unchecked: true, ..
+        }
+        | Expression::Multiply {
+            unchecked: true, ..
+        }
+        | Expression::Power {
+            unchecked: true, ..
+        } => {
+            return true;
+        }
+        // the result of an unchecked division may wrap, but dividing by zero is still an error
+        Expression::Divide {
+            unchecked: true, ..
+        } => match eval_constants_in_expression(expr, diagnostics) {
+            (Some(_), _) | (None, false) => {
+                return false;
+            }
+            _ => {}
+        },
         Expression::Add { .. }
         | Expression::Subtract { .. }
         | Expression::Multiply { .. }
@@ -476,30 +607,29 @@ pub(crate) fn eval_constants_in_expression(
             ty,
             left,
             right,
+            ..
         } => {
             let left = eval_constants_in_expression(left, diagnostics).0;
             let right = eval_constants_in_expression(right, diagnostics).0;
 
-            if let (
-                Some(Expression::NumberLiteral { value: left, .. }),
-                Some(Expression::NumberLiteral { value: right, .. }),
-            ) = (&left, &right)
-            {
-                if right.is_zero() {
+            match (&left, &right) {
+                // the left hand side does not need to be constant for this to fail
+                (_, Some(Expression::NumberLiteral { value: right, .. })) if right.is_zero() => {
                     diagnostics.push(Diagnostic::error(*loc, "divide by zero".to_string()));
                     (None, false)
-                } else {
-                    (
-                        Some(Expression::NumberLiteral {
-                            loc: *loc,
-                            ty: ty.clone(),
-                            value: left.div(right),
-                        }),
-                        true,
-                    )
                 }
-            } else {
-                (None, true)
+                (
+                    Some(Expression::NumberLiteral { value: left, .. }),
+                    Some(Expression::NumberLiteral { value: right, .. }),
+                ) => (
+                    Some(Expression::NumberLiteral {
+                        loc: *loc,
+                        ty: ty.clone(),
+                        value: left.div(right),
+                    }),
+                    true,
+                ),
+                _ => (None, true),
             }
         }
 
@@ -512,26 +642,24 @@ pub(crate) fn eval_constants_in_expression(
             let left = eval_constants_in_expression(left, diagnostics).0;
             let right = eval_constants_in_expression(right, diagnostics).0;
 
-            if let (
-                Some(Expression::NumberLiteral { value: left, .. }),
-                Some(Expression::NumberLiteral { value: right, .. }),
-            ) = (&left, &right)
-            {
-                if right.is_zero() {
+            match (&left, &right) {
+                // the left hand side does not need to be constant for this to fail
+                (_, Some(Expression::NumberLiteral { value: right, .. })) if right.is_zero() => {
                     diagnostics.push(Diagnostic::error(*loc, "divide by zero".to_string()));
                     (None, false)
-                } else {
-                    (
-                        Some(Expression::NumberLiteral {
-                            loc: *loc,
-                            ty: ty.clone(),
-                            value: left % right,
-                        }),
-                        true,
-                    )
                 }
-            } else {
-                (None, true)
+                (
+                    Some(Expression::NumberLiteral { value: left, .. }),
+                    Some(Expression::NumberLiteral { value: right, .. }),
+                ) => (
+                    Some(Expression::NumberLiteral {
+                        loc: *loc,
+                        ty: ty.clone(),
+                        value: left % right,
+                    }),
+                    true,
+                ),
+                _ => (None, true),
             }
         }
         Expression::Power {
@@ -617,7 +745,7 @@ pub(crate) fn eval_constants_in_expression(
             ty,
             left,
             right,
-            sign: _,
+            sign,
         } => {
             let left = eval_constants_in_expression(left, diagnostics).0;
             let right = eval_constants_in_expression(right, diagnostics).0;
@@ -638,11 +766,26 @@ pub(crate) fn eval_constants_in_expression(
                     ));
                     (None, false)
                 } else {
+                    let right = right.to_u64().unwrap();
+
+                    // BigInt shr is an arithmetic shift; an unsigned shift of a negative
+                    // value must shift in zeros from its two's complement representation
+                    let value = match ty {
+                        Type::Uint(bits) | Type::Int(bits)
+                            if !*sign && left.sign() == Sign::Minus =>
+                        {
+                            let modulus = BigInt::one().shl(*bits as usize);
+
+                            (left % &modulus + &modulus).shr(right)
+                        }
+                        _ => left.shr(right),
+                    };
+
                     (
                         Some(Expression::NumberLiteral {
                             loc: *loc,
                             ty: ty.clone(),
-                            value: left.shr(right.to_u64().unwrap()),
+                            value,
                         }),
                         true,
                     )
@@ -809,10 +952,21 @@ pub(crate) fn overflow_diagnostic(result: &BigInt, ty: &Type, loc: &Loc) -> Opti
         if let Type::Uint(bits) = ty {
             // If the result sign is minus, throw an error.
             if let Sign::Minus = result.sign() {
-                return Some(Diagnostic::error(
-                *loc,
-            format!( "negative value {} does not fit into type uint{}. Cannot implicitly convert signed literal to unsigned type.",result,ty.get_type_size()),
-            ));
+                let message = format!( "negative value {} does not fit into type uint{}. Cannot implicitly convert signed literal to unsigned type.",result,ty.get_type_size());
+
+                // suggest an explicit conversion if the value fits the signed type of the same size
+                if *result >= -(BigInt::one() << (*bits as usize - 1)) {
+                    let wrapped = result + (BigInt::one() << *bits as usize);
+
+                    return Some(Diagnostic::error_with_note(
+                        *loc,
+                        message,
+                        *loc,
+                        format!("to convert to uint{bits}, use an explicit conversion 'uint{bits}(int{bits}({result}))' which gives {wrapped}, or write {wrapped} directly"),
+                    ));
+                }
+
+                return Some(Diagnostic::error(*loc, message));
             }
 
             // If bits of the result is more than bits of the type, throw and error.
@@ -820,9 +974,10 @@ pub(crate) fn overflow_diagnostic(result: &BigInt, ty: &Type, loc: &Loc) -> Opti
                 return Some(Diagnostic::error(
                     *loc,
                     format!(
-                        "value {} does not fit into type uint{}.",
+                        "value {} does not fit into type uint{} (valid range 0..={}).",
                         result,
                         ty.get_type_size(),
+                        (BigInt::one() << *bits as usize) - 1,
                     ),
                 ));
             }
@@ -834,9 +989,11 @@ pub(crate) fn overflow_diagnostic(result: &BigInt, ty: &Type, loc: &Loc) -> Opti
                 return Some(Diagnostic::error(
                     *loc,
                     format!(
-                        "value {} does not fit into type int{}.",
+                        "value {} does not fit into type int{} (valid range {}..={}).",
                         result,
                         ty.get_type_size(),
+                        -(BigInt::one() << (*bits as usize - 1)),
+                        (BigInt::one() << (*bits as usize - 1)) - 1,
                     ),
                 ));
             }
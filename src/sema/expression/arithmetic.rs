@@ -1,8 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::sema::ast::{Expression, Namespace, RetrieveType, StringLocation, Type};
+use crate::sema::ast::{Builtin, Expression, Namespace, RetrieveType, StringLocation, Type};
 use crate::sema::diagnostics::Diagnostics;
 use crate::sema::eval::eval_const_rational;
+use crate::sema::expression::assign::check_immutable_assignment;
 use crate::sema::expression::integers::{coerce, coerce_number, type_bits_and_sign};
 use crate::sema::expression::resolve_expression::expression;
 use crate::sema::expression::{user_defined_operator, ExprContext, ResolveTo};
@@ -220,6 +221,14 @@ pub(super) fn shift_left(
     let left = expression(l, context, ns, symtable, diagnostics, resolve_to)?;
     let right = expression(r, context, ns, symtable, diagnostics, ResolveTo::Unknown)?;
 
+    if left.ty().is_rational() {
+        diagnostics.push(Diagnostic::error(
+            *loc,
+            "cannot shift a rational".to_string(),
+        ));
+        return Err(());
+    }
+
     check_var_usage_expression(ns, &left, &right, symtable);
     // left hand side may be bytes/int/uint
     // right hand size may be int/uint
@@ -256,6 +265,14 @@ pub(super) fn shift_right(
     let left = expression(l, context, ns, symtable, diagnostics, resolve_to)?;
     let right = expression(r, context, ns, symtable, diagnostics, ResolveTo::Unknown)?;
 
+    if left.ty().is_rational() {
+        diagnostics.push(Diagnostic::error(
+            *loc,
+            "cannot shift a rational".to_string(),
+        ));
+        return Err(());
+    }
+
     check_var_usage_expression(ns, &left, &right, symtable);
 
     let left_type = left.ty().deref_any().clone();
@@ -411,6 +428,7 @@ pub(super) fn divide(
     Ok(Expression::Divide {
         loc: *loc,
         ty: ty.clone(),
+        unchecked: context.unchecked,
         left: Box::new(left.cast(&l.loc(), &ty, true, ns, diagnostics)?),
         right: Box::new(right.cast(&r.loc(), &ty, true, ns, diagnostics)?),
     })
@@ -523,6 +541,24 @@ pub(super) fn power(
         diagnostics,
     )?;
 
+    if ty.is_rational() {
+        let expr = Expression::Power {
+            loc: *loc,
+            ty,
+            unchecked: false,
+            base: Box::new(base),
+            exp: Box::new(exp),
+        };
+
+        return match eval_const_rational(&expr, ns) {
+            Ok(_) => Ok(expr),
+            Err(diag) => {
+                diagnostics.push(diag);
+                Err(())
+            }
+        };
+    }
+
     Ok(Expression::Power {
         loc: *loc,
         ty: ty.clone(),
@@ -532,6 +568,38 @@ pub(super) fn power(
     })
 }
 
+const TX_ORIGIN_WARNING: &str =
+    "'tx.origin' used for authorization is vulnerable to phishing, use 'msg.sender' instead";
+
+/// Comparing `tx.origin` for authorization is vulnerable to phishing: a malicious contract which
+/// is called by the owner passes the check. Comparing it with `msg.sender` is the usual way of
+/// checking that the caller is not a contract, so that does not give a warning.
+fn check_tx_origin_comparison(
+    left: &Expression,
+    right: &Expression,
+    diagnostics: &mut Diagnostics,
+) {
+    fn is_builtin(expr: &Expression, builtin: Builtin) -> bool {
+        matches!(expr, Expression::Builtin { kind, .. } if *kind == builtin)
+    }
+
+    for (origin, other) in [(left, right), (right, left)] {
+        if is_builtin(origin, Builtin::Origin) && !is_builtin(other, Builtin::Sender) {
+            diagnostics.push(Diagnostic::warning(origin.loc(), TX_ORIGIN_WARNING.into()));
+            return;
+        }
+    }
+}
+
+impl Namespace {
+    /// Remove the warnings about comparing `tx.origin`, for code which intends to authorize the
+    /// account which signed the transaction rather than the caller
+    pub fn allow_tx_origin(&mut self) {
+        self.diagnostics
+            .retain(|diagnostic| diagnostic.message != TX_ORIGIN_WARNING);
+    }
+}
+
 /// Test for equality; first check string equality, then integer equality
 pub(super) fn equal(
     loc: &pt::Loc,
@@ -546,6 +614,7 @@ pub(super) fn equal(
     let right = expression(r, context, ns, symtable, diagnostics, ResolveTo::Integer)?;
 
     check_var_usage_expression(ns, &left, &right, symtable);
+    check_tx_origin_comparison(&left, &right, diagnostics);
 
     if let Some(expr) = user_defined_operator(
         loc,
@@ -608,6 +677,7 @@ pub(super) fn not_equal(
     let right = expression(r, context, ns, symtable, diagnostics, ResolveTo::Integer)?;
 
     check_var_usage_expression(ns, &left, &right, symtable);
+    check_tx_origin_comparison(&left, &right, diagnostics);
 
     if let Some(expr) = user_defined_operator(
         loc,
@@ -878,6 +948,7 @@ pub(super) fn incr_decr(
         ResolveTo::Unknown,
     )?;
     used_variable(ns, &var, symtable);
+    check_immutable_assignment(&var, &context, ns, diagnostics)?;
     let var_ty = var.ty();
 
     match &var {
@@ -936,29 +1007,16 @@ pub(super) fn incr_decr(
                     Err(())
                 }
             },
-            Type::StorageRef(immutable, r_ty) => {
-                if *immutable {
-                    if let Some(function_no) = context.function_no {
-                        if !ns.functions[function_no].is_constructor() {
-                            diagnostics.push(Diagnostic::error(
-                                var.loc(),
-                                "cannot assign to immutable outside of constructor".to_string(),
-                            ));
-                            return Err(());
-                        }
-                    }
-                }
-                match r_ty.as_ref() {
-                    Type::Int(_) | Type::Uint(_) => Ok(op(var, r_ty.as_ref().clone())),
-                    _ => {
-                        diagnostics.push(Diagnostic::error(
-                            var.loc(),
-                            format!("assigning to incorrect type {}", r_ty.to_string(ns)),
-                        ));
-                        Err(())
-                    }
+            Type::StorageRef(_, r_ty) => match r_ty.as_ref() {
+                Type::Int(_) | Type::Uint(_) => Ok(op(var, r_ty.as_ref().clone())),
+                _ => {
+                    diagnostics.push(Diagnostic::error(
+                        var.loc(),
+                        format!("assigning to incorrect type {}", r_ty.to_string(ns)),
+                    ));
+                    Err(())
                 }
-            }
+            },
             _ => {
                 diagnostics.push(Diagnostic::error(
                     var.loc(),
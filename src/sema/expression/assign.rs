@@ -37,6 +37,7 @@ pub(super) fn assign_single(
         ResolveTo::Unknown,
     )?;
     assigned_variable(ns, &var, symtable);
+    check_immutable_assignment(&var, &context, ns, diagnostics)?;
 
     context.lvalue = false;
     let var_ty = var.ty();
@@ -80,29 +81,7 @@ pub(super) fn assign_single(
             ));
             Err(())
         }
-        Expression::StorageVariable {
-            loc,
-            ty,
-            contract_no: var_contract_no,
-            var_no,
-        } => {
-            let store_var = &ns.contracts[*var_contract_no].variables[*var_no];
-
-            if store_var.immutable {
-                if let Some(function_no) = context.function_no {
-                    if !ns.functions[function_no].is_constructor() {
-                        diagnostics.push(Diagnostic::error(
-                            *loc,
-                            format!(
-                                "cannot assign to immutable '{}' outside of constructor",
-                                store_var.name
-                            ),
-                        ));
-                        return Err(());
-                    }
-                }
-            }
-
+        Expression::StorageVariable { loc, ty, .. } => {
             let ty = ty.deref_any();
 
             Ok(Expression::Assign {
@@ -138,26 +117,12 @@ pub(super) fn assign_single(
                 left: Box::new(var),
                 right: Box::new(val.cast(&right.loc(), r_ty, true, ns, diagnostics)?),
             }),
-            Type::StorageRef(immutable, r_ty) => {
-                if *immutable {
-                    if let Some(function_no) = context.function_no {
-                        if !ns.functions[function_no].is_constructor() {
-                            diagnostics.push(Diagnostic::error(
-                                *loc,
-                                "cannot assign to immutable outside of constructor".to_string(),
-                            ));
-                            return Err(());
-                        }
-                    }
-                }
-
-                Ok(Expression::Assign {
-                    loc: *loc,
-                    ty: *r_ty.clone(),
-                    left: Box::new(var),
-                    right: Box::new(val.cast(&right.loc(), r_ty, true, ns, diagnostics)?),
-                })
-            }
+            Type::StorageRef(_, r_ty) => Ok(Expression::Assign {
+                loc: *loc,
+                ty: *r_ty.clone(),
+                left: Box::new(var),
+                right: Box::new(val.cast(&right.loc(), r_ty, true, ns, diagnostics)?),
+            }),
             _ => {
                 diagnostics.push(Diagnostic::error(
                     var.loc(),
@@ -196,6 +161,7 @@ pub(super) fn assign_expr(
         ResolveTo::Unknown,
     )?;
     assigned_variable(ns, &var, symtable);
+    check_immutable_assignment(&var, &context, ns, diagnostics)?;
     let var_ty = var.ty();
 
     let resolve_to = if matches!(
@@ -305,6 +271,7 @@ pub(super) fn assign_expr(
             pt::Expression::AssignDivide(..) => Expression::Divide {
                 loc: *loc,
                 ty: ty.clone(),
+                unchecked: context.unchecked,
                 left: Box::new(assign),
                 right: Box::new(set),
             },
@@ -405,40 +372,26 @@ pub(super) fn assign_expr(
                     Err(())
                 }
             },
-            Type::StorageRef(immutable, r_ty) => {
-                if *immutable {
-                    if let Some(function_no) = context.function_no {
-                        if !ns.functions[function_no].is_constructor() {
-                            diagnostics.push(Diagnostic::error(
-                                *loc,
-                                "cannot assign to immutable outside of constructor".to_string(),
-                            ));
-                            return Err(());
-                        }
-                    }
-                }
-
-                match r_ty.as_ref() {
-                    Type::Bytes(_) | Type::Int(_) | Type::Uint(_) => Ok(Expression::Assign {
-                        loc: *loc,
-                        ty: *r_ty.clone(),
-                        left: Box::new(var.clone()),
-                        right: Box::new(assign_operation(
-                            var.cast(loc, r_ty, true, ns, diagnostics)?,
-                            r_ty,
-                            ns,
-                            diagnostics,
-                        )?),
-                    }),
-                    _ => {
-                        diagnostics.push(Diagnostic::error(
-                            var.loc(),
-                            format!("assigning to incorrect type {}", r_ty.to_string(ns)),
-                        ));
-                        Err(())
-                    }
+            Type::StorageRef(_, r_ty) => match r_ty.as_ref() {
+                Type::Bytes(_) | Type::Int(_) | Type::Uint(_) => Ok(Expression::Assign {
+                    loc: *loc,
+                    ty: *r_ty.clone(),
+                    left: Box::new(var.clone()),
+                    right: Box::new(assign_operation(
+                        var.cast(loc, r_ty, true, ns, diagnostics)?,
+                        r_ty,
+                        ns,
+                        diagnostics,
+                    )?),
+                }),
+                _ => {
+                    diagnostics.push(Diagnostic::error(
+                        var.loc(),
+                        format!("assigning to incorrect type {}", r_ty.to_string(ns)),
+                    ));
+                    Err(())
                 }
-            }
+            },
             _ => {
                 diagnostics.push(Diagnostic::error(
                     var.loc(),
@@ -449,3 +402,49 @@ pub(super) fn assign_expr(
         },
     }
 }
+
+/// Immutable state variables can only be assigned in the constructor. This is checked for the
+/// left hand side of every kind of assignment, so they all give the same diagnostic.
+pub(crate) fn check_immutable_assignment(
+    var: &Expression,
+    context: &ExprContext,
+    ns: &Namespace,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), ()> {
+    match context.function_no {
+        Some(function_no) if !ns.functions[function_no].is_constructor() => (),
+        _ => return Ok(()),
+    }
+
+    // elements of immutable arrays and structs cannot be assigned either
+    let message = match assigned_storage_variable(var) {
+        Some((contract_no, var_no)) if ns.contracts[contract_no].variables[var_no].immutable => {
+            format!(
+                "cannot assign to immutable '{}' outside of constructor",
+                ns.contracts[contract_no].variables[var_no].name
+            )
+        }
+        _ if matches!(var.ty(), Type::StorageRef(true, _)) => {
+            "cannot assign to immutable outside of constructor".to_string()
+        }
+        _ => return Ok(()),
+    };
+
+    diagnostics.push(Diagnostic::error(var.loc(), message));
+
+    Err(())
+}
+
+/// The storage variable which an array element or struct member belongs to
+fn assigned_storage_variable(expr: &Expression) -> Option<(usize, usize)> {
+    match expr {
+        Expression::StorageVariable {
+            contract_no,
+            var_no,
+            ..
+        } => Some((*contract_no, *var_no)),
+        Expression::Subscript { array, .. } => assigned_storage_variable(array),
+        Expression::StructMember { expr, .. } => assigned_storage_variable(expr),
+        _ => None,
+    }
+}
@@ -28,13 +28,7 @@ fn constructor(
     diagnostics: &mut Diagnostics,
 ) -> Result<Expression, ()> {
     if !ns.contracts[no].instantiable {
-        diagnostics.push(Diagnostic::error(
-            *loc,
-            format!(
-                "cannot construct '{}' of type '{}'",
-                ns.contracts[no].id, ns.contracts[no].ty
-            ),
-        ));
+        diagnostics.push(not_instantiable(loc, no, ns));
 
         return Err(());
     }
@@ -214,6 +208,43 @@ pub fn match_constructor_to_args(
     }
 }
 
+/// Diagnostic for an attempt to construct a contract which cannot be instantiated. For an
+/// abstract contract, the functions which have no implementation are listed as notes.
+pub(super) fn not_instantiable(loc: &pt::Loc, contract_no: usize, ns: &Namespace) -> Diagnostic {
+    let contract = &ns.contracts[contract_no];
+
+    let mut diagnostic = Diagnostic::error(
+        *loc,
+        format!(
+            "cannot construct '{}' of type '{}'",
+            contract.id, contract.ty
+        ),
+    );
+
+    if matches!(contract.ty, pt::ContractTy::Abstract(_)) {
+        diagnostic.notes = contract
+            .all_functions
+            .keys()
+            .map(|function_no| &ns.functions[*function_no])
+            .filter(|func| {
+                func.ty == pt::FunctionTy::Function
+                    && !func.has_body
+                    && !contract.all_functions.keys().any(|other_no| {
+                        let other = &ns.functions[*other_no];
+
+                        other.has_body && other.signature == func.signature
+                    })
+            })
+            .map(|func| Note {
+                loc: func.loc_prototype,
+                message: format!("function '{}' has no body", func.id),
+            })
+            .collect();
+    }
+
+    diagnostic
+}
+
 /// check if from creates to, recursively
 pub(super) fn circular_reference(from: usize, to: usize, ns: &Namespace) -> bool {
     if ns.contracts[from].creates.contains(&to) {
@@ -264,13 +295,7 @@ pub fn constructor_named_args(
     )?;
 
     if !ns.contracts[no].instantiable {
-        diagnostics.push(Diagnostic::error(
-            *loc,
-            format!(
-                "cannot construct '{}' of type '{}'",
-                ns.contracts[no].id, ns.contracts[no].ty
-            ),
-        ));
+        diagnostics.push(not_instantiable(loc, no, ns));
 
         return Err(());
     }
@@ -728,13 +753,7 @@ pub(super) fn solana_constructor_check(
     ns: &mut Namespace,
 ) {
     if !ns.contracts[constructor_contract_no].instantiable {
-        diagnostics.push(Diagnostic::error(
-            *loc,
-            format!(
-                "cannot construct '{}' of type '{}'",
-                ns.contracts[constructor_contract_no].id, ns.contracts[constructor_contract_no].ty
-            ),
-        ));
+        diagnostics.push(not_instantiable(loc, constructor_contract_no, ns));
     }
 
     if let Some(context_contract) = context.contract_no {
@@ -19,6 +19,7 @@ use crate::sema::unused_variable::check_function_call;
 use crate::sema::{builtin, using};
 use crate::Target;
 use num_bigint::{BigInt, Sign};
+use num_traits::Zero;
 use solang_parser::diagnostics::{Diagnostic, Note};
 use solang_parser::pt;
 use solang_parser::pt::{CodeLocation, Loc, Visibility};
@@ -2382,6 +2383,10 @@ pub fn call_expr(
                     ResolveTo::Unknown,
                 )?;
 
+                if matches!(ty, pt::Expression::Type(_, pt::Type::Payable)) {
+                    check_payable_conversion(loc, &expr, ns, diagnostics)?;
+                }
+
                 expr.cast(loc, &to, false, ns, diagnostics)
             };
         }
@@ -2423,6 +2428,64 @@ pub fn call_expr(
     Ok(expr)
 }
 
+/// Only address and contract types, or an integer of the same size as an address, can be converted
+/// with payable(); payable(0) is allowed as an exception. On EVM, a contract can only be converted
+/// if it can receive value.
+fn check_payable_conversion(
+    loc: &pt::Loc,
+    expr: &Expression,
+    ns: &Namespace,
+    diagnostics: &mut Diagnostics,
+) -> Result<(), ()> {
+    match expr.ty().deref_any() {
+        Type::Address(_) => (),
+        Type::Uint(bits) if *bits as usize == ns.address_length * 8 => (),
+        Type::Contract(contract_no) => {
+            let contract = &ns.contracts[*contract_no];
+
+            let can_receive = contract.all_functions.keys().any(|function_no| {
+                let func = &ns.functions[*function_no];
+
+                func.ty == pt::FunctionTy::Receive
+                    || (func.ty == pt::FunctionTy::Fallback && func.is_payable())
+            });
+
+            if ns.target == Target::EVM && !can_receive {
+                diagnostics.push(Diagnostic::cast_error_with_note(
+                    *loc,
+                    format!(
+                        "payable conversion of contract '{}' not possible, as it has no receive function or payable fallback function",
+                        contract.id
+                    ),
+                    contract.loc,
+                    format!("definition of '{}'", contract.id),
+                ));
+                return Err(());
+            }
+        }
+        _ if matches!(expr, Expression::NumberLiteral { value, .. } if value.is_zero()) => (),
+        ty => {
+            diagnostics.push(Diagnostic::cast_error(
+                *loc,
+                format!(
+                    "payable conversion is only possible from address or contract types, not '{}'",
+                    ty.to_string(ns)
+                ),
+            ));
+            return Err(());
+        }
+    }
+
+    if ns.target == Target::Solana {
+        diagnostics.push(Diagnostic::warning(
+            *loc,
+            "'payable' has no effect on Solana, as any account can receive lamports".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 /// Resolve function call
 pub fn function_call_expr(
     loc: &pt::Loc,
@@ -2505,6 +2568,16 @@ pub fn function_call_expr(
                     return Err(());
                 }
 
+                let function_nos =
+                    available_functions(&id.name, true, context.file_no, context.contract_no, ns);
+
+                if function_nos.is_empty() {
+                    if let Some(message) = builtin::builtin_call_unavailable(None, &id.name, ns) {
+                        diagnostics.push(Diagnostic::error(id.loc, message));
+                        return Err(());
+                    }
+                }
+
                 let id_path = pt::IdentifierPath {
                     loc: id.loc,
                     identifiers: vec![id.clone()],
@@ -2515,7 +2588,7 @@ pub fn function_call_expr(
                     &id_path,
                     pt::FunctionTy::Function,
                     args,
-                    available_functions(&id.name, true, context.file_no, context.contract_no, ns),
+                    function_nos,
                     true,
                     context,
                     ns,
@@ -5,7 +5,7 @@ use crate::sema::ast::{
 };
 use crate::sema::builtin;
 use crate::sema::diagnostics::Diagnostics;
-use crate::sema::expression::constructor::circular_reference;
+use crate::sema::expression::constructor::{circular_reference, not_instantiable};
 use crate::sema::expression::function_call::function_type;
 use crate::sema::expression::integers::bigint_to_expression;
 use crate::sema::expression::resolve_expression::expression;
@@ -44,6 +44,13 @@ pub(super) fn member_access(
             });
         }
 
+        if let Some(message) = builtin::builtin_var_unavailable(Some(&namespace.name), &id.name, ns)
+        {
+            diagnostics.push(Diagnostic::error(*loc, message));
+
+            return Err(());
+        }
+
         if builtin::builtin_namespace(&namespace.name) {
             diagnostics.push(Diagnostic::error(
                 e.loc(),
@@ -775,13 +782,7 @@ fn type_name_expr(
         "creationCode" | "runtimeCode" => {
             if let Type::Contract(no) = ty {
                 if !ns.contracts[*no].instantiable {
-                    diagnostics.push(Diagnostic::error(
-                        *loc,
-                        format!(
-                            "cannot construct '{}' of type '{}'",
-                            ns.contracts[*no].id, ns.contracts[*no].ty
-                        ),
-                    ));
+                    diagnostics.push(not_instantiable(loc, *no, ns));
 
                     return Err(());
                 }
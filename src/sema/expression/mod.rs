@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod arithmetic;
-mod assign;
+pub(crate) mod assign;
 pub(crate) mod constructor;
 pub(crate) mod function_call;
 pub(crate) mod integers;
@@ -126,6 +126,14 @@ impl Expression {
         diagnostics: &mut Diagnostics,
     ) -> Result<Expression, ()> {
         let from = self.ty();
+
+        tracing::trace!(
+            from = %from.to_string(ns),
+            to = %to.to_string(ns),
+            implicit,
+            "cast"
+        );
+
         if &from == to {
             return Ok(self.clone());
         }
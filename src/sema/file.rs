@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::ast::{File, Namespace};
+use itertools::Itertools;
 use solang_parser::pt::Loc;
 use std::{fmt, path};
 
@@ -18,16 +19,20 @@ impl File {
         import_no: Option<usize>,
     ) -> Self {
         let mut line_starts = Vec::new();
+        let mut multibyte_chars = Vec::new();
 
         for (ind, c) in contents.char_indices() {
             if c == '\n' {
                 line_starts.push(ind + 1);
+            } else if c.len_utf8() > 1 {
+                multibyte_chars.push((ind, c.len_utf8() - 1));
             }
         }
 
         File {
             path,
             line_starts,
+            multibyte_chars,
             cache_no: Some(cache_no),
             import_no,
         }
@@ -35,8 +40,7 @@ impl File {
 
     /// Give a position as a human readable position
     pub fn loc_to_string(&self, display: PathDisplay, start: usize, end: usize) -> String {
-        let (from_line, from_column) = self.offset_to_line_column(start);
-        let (to_line, to_column) = self.offset_to_line_column(end);
+        let (from_line, from_column, to_line, to_column) = self.offsets_to_range(start, end);
 
         let path = match display {
             PathDisplay::None => "".to_owned(),
@@ -45,27 +49,28 @@ impl File {
         };
 
         if from_line == to_line && from_column == to_column {
-            format!("{}{}:{}", path, from_line + 1, from_column + 1)
+            format!("{path}{from_line}:{from_column}")
         } else if from_line == to_line {
-            format!(
-                "{}{}:{}-{}",
-                path,
-                from_line + 1,
-                from_column + 1,
-                to_column + 1
-            )
+            format!("{path}{from_line}:{from_column}-{to_column}")
         } else {
-            format!(
-                "{}{}:{}-{}:{}",
-                path,
-                from_line + 1,
-                from_column + 1,
-                to_line + 1,
-                to_column + 1
-            )
+            format!("{path}{from_line}:{from_column}-{to_line}:{to_column}")
         }
     }
 
+    /// Convert a range of offsets to the start line, start column, end line and end column,
+    /// all based one. Columns are counted in characters rather than bytes.
+    pub fn offsets_to_range(&self, start: usize, end: usize) -> (usize, usize, usize, usize) {
+        let (from_line, from_column) = self.offset_to_line_column(start);
+        let (to_line, to_column) = self.offset_to_line_column(end);
+
+        (
+            from_line + 1,
+            self.character_column(start, from_column) + 1,
+            to_line + 1,
+            self.character_column(end, to_column) + 1,
+        )
+    }
+
     /// Convert an offset to line and column number, based zero
     pub fn offset_to_line_column(&self, loc: usize) -> (usize, usize) {
         let line_no = self
@@ -81,6 +86,25 @@ impl File {
         (line_no, col_no)
     }
 
+    /// Convert a column in bytes to a column in characters, by discounting the extra bytes of
+    /// any multibyte characters on the line before the offset. An offset inside a multibyte
+    /// character counts that character as passed.
+    fn character_column(&self, offset: usize, column: usize) -> usize {
+        let line_start = offset - column;
+        let from = self
+            .multibyte_chars
+            .partition_point(|(pos, _)| *pos < line_start);
+        let to = self
+            .multibyte_chars
+            .partition_point(|(pos, _)| *pos < offset);
+
+        column
+            - self.multibyte_chars[from..to]
+                .iter()
+                .map(|(pos, extra)| (*extra).min(offset - pos - 1))
+                .sum::<usize>()
+    }
+
     /// Convert line + char to offset
     pub fn get_offset(&self, line_no: usize, column_no: usize) -> Option<usize> {
         if line_no == 0 {
@@ -123,6 +147,28 @@ impl Namespace {
         }
     }
 
+    /// Give a position as the start line, start column, end line and end column, all based one.
+    /// Columns are counted in characters. Returns `None` if the position is not in a file.
+    pub fn loc_to_range(&self, loc: &Loc) -> Option<(usize, usize, usize, usize)> {
+        match loc {
+            Loc::File(file_no, start, end) => {
+                Some(self.files[*file_no].offsets_to_range(*start, *end))
+            }
+            _ => None,
+        }
+    }
+
+    /// The source files which were resolved into this namespace: the top level file and all the
+    /// files it imports, directly or indirectly. Builtin files are not included.
+    pub fn source_files(&self) -> Vec<&path::Path> {
+        self.files
+            .iter()
+            .filter(|file| file.cache_no.is_some())
+            .map(|file| file.path.as_path())
+            .unique()
+            .collect()
+    }
+
     /// File number of the top level source unit which was compiled
     pub fn top_file_no(&self) -> usize {
         self.files
@@ -300,6 +300,21 @@ pub fn contract_function(
             true
         }
         pt::Visibility::Public(_) | pt::Visibility::External(_) => {
+            if let Some(pt::Mutability::Payable(loc)) = mutability {
+                if ns.target == Target::Solana
+                    && matches!(
+                        func.ty,
+                        pt::FunctionTy::Function | pt::FunctionTy::Constructor
+                    )
+                    && !ns.contracts[contract_no].is_library()
+                {
+                    ns.diagnostics.push(Diagnostic::warning(
+                        loc,
+                        "'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer".to_string(),
+                    ));
+                }
+            }
+
             // library function abi is special. Storage vars are allowed
             ns.contracts[contract_no].is_library()
         }
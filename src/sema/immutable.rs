@@ -0,0 +1,308 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::{
+    ast::{DestructureField, Diagnostic, Expression, Namespace, Statement},
+    diagnostics::Diagnostics,
+    Recurse,
+};
+use solang_parser::pt;
+use std::collections::BTreeSet;
+
+/// Check that immutable state variables are not read in the constructor before they are assigned,
+/// and that they are assigned at most once. Immutables with an initializer are assigned before the
+/// constructor runs, so they cannot be assigned again; the others must be assigned on every path
+/// through the constructor which reaches the read. An immutable which is assigned on some paths through the constructor must be
+/// assigned on all of them.
+pub fn immutable_reads(file_no: usize, ns: &mut Namespace) {
+    let mut diagnostics = Diagnostics::default();
+
+    for func in &ns.functions {
+        if func.loc_prototype.try_file_no() != Some(file_no) || !func.is_constructor() {
+            continue;
+        }
+
+        let contract_no = match func.contract_no {
+            Some(contract_no) => contract_no,
+            None => continue,
+        };
+
+        let initialized: BTreeSet<usize> = ns.contracts[contract_no]
+            .variables
+            .iter()
+            .enumerate()
+            .filter(|(_, var)| var.immutable && var.initializer.is_some())
+            .map(|(var_no, _)| var_no)
+            .collect();
+
+        let mut check = ImmutableCheck {
+            ns,
+            contract_no,
+            assigned: initialized.clone(),
+            maybe_assigned: initialized,
+            ever_assigned: BTreeSet::new(),
+            in_loop: false,
+            exits: Vec::new(),
+            diagnostics: &mut diagnostics,
+        };
+
+        if check.statements(&func.body) {
+            check.returns(&func.loc_prototype);
+        }
+
+        check.unassigned();
+    }
+
+    ns.diagnostics.extend(diagnostics);
+}
+
+struct ImmutableCheck<'a> {
+    ns: &'a Namespace,
+    contract_no: usize,
+    /// The immutables which are definitely assigned at this point
+    assigned: BTreeSet<usize>,
+    /// The immutables which are assigned on at least one path to this point
+    maybe_assigned: BTreeSet<usize>,
+    /// The immutables which are assigned anywhere in the constructor
+    ever_assigned: BTreeSet<usize>,
+    /// Are we in the body of a loop, which may be executed more than once
+    in_loop: bool,
+    /// The places where the constructor returns, with the immutables definitely assigned there
+    exits: Vec<(pt::Loc, BTreeSet<usize>)>,
+    diagnostics: &'a mut Diagnostics,
+}
+
+impl<'a> ImmutableCheck<'a> {
+    /// Walk the statements in order, and return whether the end is reachable
+    fn statements(&mut self, stmts: &[Statement]) -> bool {
+        for stmt in stmts {
+            self.statement(stmt);
+        }
+
+        stmts.last().map(|stmt| stmt.reachable()).unwrap_or(true)
+    }
+
+    fn statement(&mut self, stmt: &Statement) {
+        match stmt {
+            Statement::Block { statements, .. } => {
+                self.statements(statements);
+            }
+            Statement::VariableDecl(_, _, _, Some(expr)) => self.expression(expr),
+            Statement::If(_, _, cond, then_stmt, else_stmt) => {
+                self.expression(cond);
+
+                self.branches([then_stmt, else_stmt]);
+            }
+            Statement::While(_, _, cond, body) => {
+                self.expression(cond);
+
+                // the body might not be executed
+                let before = self.assigned.clone();
+                self.loop_body(|check| {
+                    check.statements(body);
+                });
+                self.assigned = before;
+            }
+            Statement::For {
+                init,
+                cond,
+                next,
+                body,
+                ..
+            } => {
+                self.statements(init);
+
+                if let Some(cond) = cond {
+                    self.expression(cond);
+                }
+
+                let before = self.assigned.clone();
+                self.loop_body(|check| {
+                    check.statements(body);
+                    if let Some(next) = next {
+                        check.expression(next);
+                    }
+                });
+                self.assigned = before;
+            }
+            Statement::DoWhile(_, _, body, cond) => {
+                self.loop_body(|check| {
+                    check.statements(body);
+                    check.expression(cond);
+                });
+            }
+            Statement::Expression(_, _, expr) | Statement::Delete(_, _, expr) => {
+                self.expression(expr)
+            }
+            Statement::Destructure(_, fields, expr) => {
+                self.expression(expr);
+
+                for field in fields {
+                    if let DestructureField::Expression(left) = field {
+                        self.assign(left);
+                    }
+                }
+            }
+            Statement::Return(loc, expr) => {
+                if let Some(expr) = expr {
+                    self.expression(expr);
+                }
+
+                self.returns(loc);
+            }
+            Statement::Revert { args, .. } | Statement::Emit { args, .. } => {
+                for arg in args {
+                    self.expression(arg);
+                }
+            }
+            Statement::TryCatch(_, _, try_catch) => {
+                self.expression(&try_catch.expr);
+
+                self.branches(
+                    std::iter::once(&try_catch.ok_stmt)
+                        .chain(try_catch.errors.iter().map(|clause| &clause.stmt))
+                        .chain(try_catch.catch_all.iter().map(|clause| &clause.stmt)),
+                );
+            }
+            Statement::VariableDecl(..)
+            | Statement::Continue(_)
+            | Statement::Break(_)
+            | Statement::Underscore(_)
+            | Statement::Assembly(..) => (),
+        }
+    }
+
+    fn loop_body(&mut self, f: impl FnOnce(&mut Self)) {
+        let in_loop = std::mem::replace(&mut self.in_loop, true);
+        f(self);
+        self.in_loop = in_loop;
+    }
+
+    fn returns(&mut self, loc: &pt::Loc) {
+        self.exits.push((*loc, self.assigned.clone()));
+    }
+
+    /// An immutable which is assigned anywhere in the constructor must be assigned on every
+    /// path through the constructor
+    fn unassigned(&mut self) {
+        for (loc, assigned) in &self.exits {
+            for var_no in self.ever_assigned.difference(assigned) {
+                let name = &self.ns.contracts[self.contract_no].variables[*var_no].name;
+
+                self.diagnostics.push(Diagnostic::error(
+                    *loc,
+                    format!("immutable '{name}' may be unassigned when the constructor returns"),
+                ));
+            }
+        }
+    }
+
+    /// Walk each of the branches, starting from the current state. After branching, only the
+    /// immutables assigned in all the branches which continue are definitely assigned. If no
+    /// branch continues, the code which follows is unreachable.
+    fn branches<'s>(&mut self, branches: impl IntoIterator<Item = &'s Vec<Statement>>) {
+        let before = (self.assigned.clone(), self.maybe_assigned.clone());
+        let mut after = Vec::new();
+
+        for stmts in branches {
+            (self.assigned, self.maybe_assigned) = before.clone();
+
+            if self.statements(stmts) {
+                after.push((self.assigned.clone(), self.maybe_assigned.clone()));
+            }
+        }
+
+        (self.assigned, self.maybe_assigned) = before;
+
+        if let Some((assigned, maybe_assigned)) = after.into_iter().reduce(|a, b| {
+            (
+                a.0.intersection(&b.0).copied().collect(),
+                a.1.union(&b.1).copied().collect(),
+            )
+        }) {
+            self.assigned = assigned;
+            self.maybe_assigned = maybe_assigned;
+        }
+    }
+
+    fn expression(&mut self, expr: &Expression) {
+        expr.recurse(self, check_expression);
+    }
+
+    /// The left hand side of an assignment
+    fn assign(&mut self, left: &Expression) {
+        match left {
+            Expression::StorageVariable {
+                loc,
+                contract_no,
+                var_no,
+                ..
+            } if self.is_immutable(*contract_no, *var_no) => {
+                let name = &self.ns.contracts[*contract_no].variables[*var_no].name;
+
+                if self.in_loop {
+                    // the assignment is rejected, so do not report the paths which miss it
+                    self.diagnostics.push(Diagnostic::error(
+                        *loc,
+                        format!("immutable '{name}' cannot be assigned in a loop"),
+                    ));
+                } else {
+                    if self.assigned.contains(var_no) {
+                        self.diagnostics.push(Diagnostic::error(
+                            *loc,
+                            format!("immutable '{name}' is already assigned"),
+                        ));
+                    } else if self.maybe_assigned.contains(var_no) {
+                        self.diagnostics.push(Diagnostic::error(
+                            *loc,
+                            format!("immutable '{name}' may already be assigned"),
+                        ));
+                    }
+
+                    self.ever_assigned.insert(*var_no);
+                }
+
+                self.assigned.insert(*var_no);
+                self.maybe_assigned.insert(*var_no);
+            }
+            _ => self.expression(left),
+        }
+    }
+
+    fn is_immutable(&self, contract_no: usize, var_no: usize) -> bool {
+        contract_no == self.contract_no
+            && self.ns.contracts[contract_no].variables[var_no].immutable
+    }
+}
+
+fn check_expression(expr: &Expression, check: &mut ImmutableCheck) -> bool {
+    match expr {
+        // the right hand side is evaluated before the assignment
+        Expression::Assign { left, right, .. } => {
+            check.expression(right);
+            check.assign(left);
+            false
+        }
+        Expression::StorageLoad { expr, .. } => {
+            if let Expression::StorageVariable {
+                loc,
+                contract_no,
+                var_no,
+                ..
+            } = expr.as_ref()
+            {
+                if check.is_immutable(*contract_no, *var_no) && !check.assigned.contains(var_no) {
+                    let name = &check.ns.contracts[*contract_no].variables[*var_no].name;
+
+                    check.diagnostics.push(Diagnostic::error(
+                        *loc,
+                        format!("immutable '{name}' is read before it is assigned"),
+                    ));
+                }
+
+                return false;
+            }
+            true
+        }
+        _ => true,
+    }
+}
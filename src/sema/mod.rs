@@ -12,18 +12,19 @@ use crate::file_resolver::{FileResolver, ResolvedFile};
 use num_bigint::BigInt;
 use solang_parser::{
     doccomment::{parse_doccomments, DocComment},
-    parse,
     pt::{self, CodeLocation},
 };
-use std::{ffi::OsString, str};
+use std::{collections::HashMap, ffi::OsString, str};
 
 mod address;
 pub mod ast;
+mod ast_json;
 pub mod builtin;
 pub mod builtin_structs;
 pub(crate) mod contracts;
 pub mod diagnostics;
 mod dotgraphviz;
+mod empty_catch;
 pub(crate) mod eval;
 pub(crate) mod expression;
 mod external_functions;
@@ -31,6 +32,7 @@ pub mod file;
 mod format;
 mod function_annotation;
 mod functions;
+mod immutable;
 mod mutability;
 mod namespace;
 mod pragma;
@@ -89,6 +91,7 @@ pub fn sema(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Name
         check_unused_namespace_variables(ns);
         check_unused_events(ns);
         check_unused_errors(ns);
+        contracts::check_contracts_have_entry_points(ns);
     }
 }
 
@@ -105,7 +108,10 @@ fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Nam
         file.import_no,
     ));
 
-    let (pt, comments) = match parse(&source_code, file_no) {
+    let parsed = tracing::info_span!(target: "solang::passes", "parse")
+        .in_scope(|| resolver.parse(&file.full_path, &source_code, file_no));
+
+    let parsed = match parsed {
         Ok(s) => s,
         Err(mut errors) => {
             ns.diagnostics.append(&mut errors);
@@ -114,11 +120,15 @@ fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Nam
         }
     };
 
-    let tree = collect_annotations_doccomments(&pt, &comments, ns);
+    let (pt, comments) = &*parsed;
+
+    let tree = collect_annotations_doccomments(pt, comments, ns);
 
     // first resolve all the types we can find
     let fields = types::resolve_typenames(&tree, file_no, ns);
     // resolve pragmas and imports
+    let mut imported_files: HashMap<usize, pt::Loc> = HashMap::new();
+
     for item in &tree.items {
         match &item.part {
             pt::SourceUnitPart::PragmaDirective(pragma) => {
@@ -127,7 +137,25 @@ fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Nam
             }
             pt::SourceUnitPart::ImportDirective(import) => {
                 annotions_not_allowed(&item.annotations, "import", ns);
-                resolve_import(import, Some(file), file_no, resolver, ns);
+                let import_file_no = resolve_import(import, Some(file), file_no, resolver, ns);
+
+                // importing all symbols of the same file again has no effect
+                if let (Some(import_file_no), pt::Import::Plain(_, loc)) = (import_file_no, import)
+                {
+                    if let Some(previous) = imported_files.get(&import_file_no) {
+                        ns.diagnostics.push(ast::Diagnostic::warning_with_note(
+                            *loc,
+                            format!(
+                                "file '{}' has already been imported",
+                                ns.files[import_file_no].path.display()
+                            ),
+                            *previous,
+                            "previous import".into(),
+                        ));
+                    } else {
+                        imported_files.insert(import_file_no, *loc);
+                    }
+                }
             }
             _ => (),
         }
@@ -184,6 +212,10 @@ fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Nam
 
     // now we can resolve the body of functions outside of contracts
     for (func_no, func) in resolve_bodies {
+        if ns.diagnostics.error_limit_reached() {
+            break;
+        }
+
         let _ = statements::resolve_function_body(func, &[], file_no, None, func_no, ns);
     }
 
@@ -215,16 +247,19 @@ fn sema_file(file: &ResolvedFile, resolver: &mut FileResolver, ns: &mut ast::Nam
 
     // now check state mutability for all contracts
     mutability::mutability(file_no, ns);
+
+    immutable::immutable_reads(file_no, ns);
 }
 
-/// Find import file, resolve it by calling sema and add it to the namespace
+/// Find import file, resolve it by calling sema and add it to the namespace. Returns the
+/// file number of the imported file, if it could be resolved.
 fn resolve_import(
     import: &pt::Import,
     parent: Option<&ResolvedFile>,
     file_no: usize,
     resolver: &mut FileResolver,
     ns: &mut ast::Namespace,
-) {
+) -> Option<usize> {
     let path = match import {
         pt::Import::Plain(f, _)
         | pt::Import::GlobalSymbol(f, _, _)
@@ -239,7 +274,7 @@ fn resolve_import(
                 "experimental import paths not supported".into(),
             ));
 
-            return;
+            return None;
         }
     };
 
@@ -248,7 +283,7 @@ fn resolve_import(
             filename.loc,
             "import path empty".into(),
         ));
-        return;
+        return None;
     }
 
     let (valid, bs) = unescape(
@@ -259,13 +294,13 @@ fn resolve_import(
     );
 
     if !valid {
-        return;
+        return None;
     }
 
     let os_filename = if let Some(res) = osstring_from_vec(&filename.loc, bs, ns) {
         res
     } else {
-        return;
+        return None;
     };
 
     let import_file_no = if let Some(builtin_file_no) = ns
@@ -281,7 +316,7 @@ fn resolve_import(
                 ns.diagnostics
                     .push(ast::Diagnostic::error(filename.loc, message));
 
-                return;
+                return None;
             }
             Ok(file) => {
                 if !ns.files.iter().any(|f| f.path == file.full_path) {
@@ -289,7 +324,7 @@ fn resolve_import(
 
                     // give up if we failed
                     if ns.diagnostics.any_errors() {
-                        return;
+                        return None;
                     }
                 }
 
@@ -423,6 +458,8 @@ fn resolve_import(
             );
         }
     }
+
+    Some(import_file_no)
 }
 
 /// Walk through the parse tree and collect all the annotations and doccomments for
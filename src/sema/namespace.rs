@@ -21,7 +21,7 @@ use solang_parser::{
     pt,
     pt::{CodeLocation, FunctionTy, OptionalCodeLocation},
 };
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 /// Provides context information for the `resolve_type` function.
 #[derive(PartialEq, Eq)]
@@ -1577,4 +1577,80 @@ impl Namespace {
                 .join(",")
         )
     }
+
+    /// The selector of the function, as used in the contract which declares it. This is the
+    /// keccak256 hash of the signature truncated to `Target::selector_length()` bytes, the Anchor
+    /// discriminator on Solana, or the selector given with `@selector`.
+    pub fn function_selector(&self, function_no: usize) -> Vec<u8> {
+        let func = &self.functions[function_no];
+
+        match func.contract_no {
+            Some(contract_no) => func.selector(self, &contract_no),
+            // free functions are not mangled per contract
+            None => self
+                .target
+                .function_selector(&func.id.name, &func.signature),
+        }
+    }
+
+    /// Find the events whose topic (or discriminator on Solana) matches the given topic. Since
+    /// topics are hashes, more than one event may match. Anonymous events are not emitted with
+    /// a topic, except on Solana where the discriminator is always present.
+    pub fn event_by_topic(&self, topic: &[u8]) -> Vec<usize> {
+        self.events
+            .iter()
+            .enumerate()
+            .filter(|(_, event)| {
+                (!event.anonymous || self.target == Target::Solana) && event.topic(self) == topic
+            })
+            .map(|(event_no, _)| event_no)
+            .collect()
+    }
+
+    /// The contracts the given contract may instantiate using `new`, i.e. whose code must be
+    /// available when the given contract is deployed. If `transitive` is set, the contracts
+    /// those contracts instantiate are included too. Mutual instantiation is reported as an
+    /// error with the contracts which make up the cycle, starting and ending with the same contract.
+    pub fn contract_dependencies(
+        &self,
+        contract_no: usize,
+        transitive: bool,
+    ) -> Result<BTreeSet<usize>, Vec<usize>> {
+        let mut dependencies = BTreeSet::new();
+
+        if !transitive {
+            dependencies.extend(self.contracts[contract_no].creates.iter().copied());
+
+            return Ok(dependencies);
+        }
+
+        let mut path = vec![contract_no];
+
+        self.collect_dependencies(contract_no, &mut path, &mut dependencies)?;
+
+        Ok(dependencies)
+    }
+
+    fn collect_dependencies(
+        &self,
+        contract_no: usize,
+        path: &mut Vec<usize>,
+        dependencies: &mut BTreeSet<usize>,
+    ) -> Result<(), Vec<usize>> {
+        for no in &self.contracts[contract_no].creates {
+            if let Some(pos) = path.iter().position(|n| n == no) {
+                let mut cycle = path[pos..].to_vec();
+                cycle.push(*no);
+                return Err(cycle);
+            }
+
+            if dependencies.insert(*no) {
+                path.push(*no);
+                self.collect_dependencies(*no, path, dependencies)?;
+                path.pop();
+            }
+        }
+
+        Ok(())
+    }
 }
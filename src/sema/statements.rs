@@ -3,11 +3,13 @@
 use super::ast::*;
 use super::contracts::is_base;
 use super::diagnostics::Diagnostics;
+use super::eval::eval_const_bool;
 use super::expression::{
     function_call::{available_functions, call_expr, named_call_expr},
     ExprContext, ResolveTo,
 };
 use super::symtable::Symtable;
+use crate::sema::expression::assign::check_immutable_assignment;
 use crate::sema::expression::constructor::{
     constructor_named_args, match_constructor_to_args, new,
 };
@@ -15,11 +17,13 @@ use crate::sema::expression::function_call::{
     function_call_expr, function_call_pos_args, named_function_call_expr,
 };
 use crate::sema::expression::resolve_expression::expression;
+use crate::sema::file::PathDisplay;
 use crate::sema::function_annotation::function_body_annotations;
 use crate::sema::function_annotation::{unexpected_parameter_annotation, UnresolvedAnnotation};
 use crate::sema::namespace::ResolveTypeContext;
 use crate::sema::symtable::{VariableInitializer, VariableUsage};
 use crate::sema::unused_variable::{assigned_variable, check_function_call, used_variable};
+use crate::sema::yul::builtin::available_builtins;
 use crate::sema::yul::resolve_inline_assembly;
 use crate::sema::Recurse;
 use crate::Target;
@@ -38,6 +42,13 @@ pub fn resolve_function_body(
     function_no: usize,
     ns: &mut Namespace,
 ) -> Result<(), ()> {
+    let _span = tracing::trace_span!(
+        "function",
+        name = %ns.functions[function_no].id.name,
+        function_no
+    )
+    .entered();
+
     let mut symtable = Symtable::default();
     let mut res = Vec::new();
     let mut context = ExprContext {
@@ -306,12 +317,12 @@ pub fn resolve_function_body(
     }
 
     if def.ty == pt::FunctionTy::Modifier {
-        let mut has_underscore = false;
+        let mut underscores = Vec::new();
 
         // unsure modifier has underscore
-        fn check_statement(stmt: &Statement, has_underscore: &mut bool) -> bool {
-            if stmt.is_underscore() {
-                *has_underscore = true;
+        fn check_statement(stmt: &Statement, underscores: &mut Vec<pt::Loc>) -> bool {
+            if let Statement::Underscore(loc) = stmt {
+                underscores.push(*loc);
                 false
             } else {
                 true
@@ -319,13 +330,28 @@ pub fn resolve_function_body(
         }
 
         for stmt in &mut res {
-            stmt.recurse(&mut has_underscore, check_statement);
+            stmt.recurse(&mut underscores, check_statement);
         }
 
-        if !has_underscore {
-            ns.diagnostics.push(Diagnostic::error(
-                body.loc().end_range(),
+        if underscores.is_empty() {
+            ns.diagnostics.push(Diagnostic::error_with_note(
+                ns.functions[function_no].loc_prototype,
                 "missing '_' in modifier".to_string(),
+                body.loc().begin_range(),
+                "'_' should be placed in the body of the modifier".to_string(),
+            ));
+        }
+
+        let mut repeated = None;
+
+        repeated_underscore(&res, None, &mut repeated);
+
+        if let Some((first, second)) = repeated {
+            ns.diagnostics.push(Diagnostic::warning_with_note(
+                second,
+                "'_' occurs more than once in modifier, so the body of the modified function may run more than once".to_string(),
+                first,
+                "first '_' in modifier".to_string(),
             ));
         }
     }
@@ -339,6 +365,96 @@ pub fn resolve_function_body(
     Ok(())
 }
 
+/// Walk the statements of a modifier, looking for a '_' which can run after another '_' on the
+/// same path. `seen` is the first '_' which may have run before the statements, and the first
+/// '_' which may have run once the statements complete is returned. A '_' in each branch of an
+/// if statement is fine, since only one of them runs.
+fn repeated_underscore(
+    stmts: &[Statement],
+    mut seen: Option<pt::Loc>,
+    repeated: &mut Option<(pt::Loc, pt::Loc)>,
+) -> Option<pt::Loc> {
+    for stmt in stmts {
+        match stmt {
+            Statement::Underscore(loc) => {
+                if let Some(first) = seen {
+                    repeated.get_or_insert((first, *loc));
+                }
+
+                seen = seen.or(Some(*loc));
+            }
+            Statement::Block { statements, .. } => {
+                seen = repeated_underscore(statements, seen, repeated);
+            }
+            Statement::If(_, _, _, then_stmt, else_stmt) => {
+                seen = repeated_underscore_branches([then_stmt, else_stmt], seen, repeated);
+            }
+            Statement::TryCatch(_, _, try_catch) => {
+                seen = repeated_underscore_branches(
+                    std::iter::once(&try_catch.ok_stmt)
+                        .chain(try_catch.errors.iter().map(|clause| &clause.stmt))
+                        .chain(try_catch.catch_all.iter().map(|clause| &clause.stmt)),
+                    seen,
+                    repeated,
+                );
+            }
+            Statement::For { init, body, .. } => {
+                seen = repeated_underscore(init, seen, repeated);
+                seen = seen.or(repeated_underscore(body, seen, repeated));
+            }
+            Statement::While(_, _, _, body) | Statement::DoWhile(_, _, body, _) => {
+                seen = seen.or(repeated_underscore(body, seen, repeated));
+            }
+            _ => (),
+        }
+    }
+
+    seen
+}
+
+/// Only one of the branches runs. A branch which does not continue, for example because it
+/// returns, does not affect the statements which follow.
+fn repeated_underscore_branches<'a>(
+    branches: impl IntoIterator<Item = &'a Vec<Statement>>,
+    seen: Option<pt::Loc>,
+    repeated: &mut Option<(pt::Loc, pt::Loc)>,
+) -> Option<pt::Loc> {
+    let mut after = seen;
+
+    for stmts in branches {
+        let branch_seen = repeated_underscore(stmts, seen, repeated);
+
+        if stmts.last().map_or(true, Statement::reachable) {
+            after = after.or(branch_seen);
+        }
+    }
+
+    after
+}
+
+/// The name of the statement kind, for tracing
+fn statement_kind(stmt: &pt::Statement) -> &'static str {
+    match stmt {
+        pt::Statement::Block { .. } => "Block",
+        pt::Statement::Assembly { .. } => "Assembly",
+        pt::Statement::Args(..) => "Args",
+        pt::Statement::If(..) => "If",
+        pt::Statement::While(..) => "While",
+        pt::Statement::Expression(..) => "Expression",
+        pt::Statement::VariableDefinition(..) => "VariableDefinition",
+        pt::Statement::For(..) => "For",
+        pt::Statement::DoWhile(..) => "DoWhile",
+        pt::Statement::Continue(..) => "Continue",
+        pt::Statement::Break(..) => "Break",
+        pt::Statement::Return(..) => "Return",
+        pt::Statement::Revert(..) => "Revert",
+        pt::Statement::RevertNamedArgs(..) => "RevertNamedArgs",
+        pt::Statement::Emit(..) => "Emit",
+        pt::Statement::Try(..) => "Try",
+        pt::Statement::Error(..) => "Error",
+    }
+}
+
 /// Resolve a statement
 fn statement(
     stmt: &pt::Statement,
@@ -350,6 +466,13 @@ fn statement(
 ) -> Result<bool, ()> {
     let function_no = context.function_no.unwrap();
 
+    let _span = tracing::trace_span!(
+        "statement",
+        kind = statement_kind(stmt),
+        loc = %ns.loc_to_string(PathDisplay::Filename, &stmt.loc())
+    )
+    .entered();
+
     match stmt {
         pt::Statement::VariableDefinition(loc, decl, initializer) => {
             let (var_ty, ty_loc) =
@@ -544,19 +667,28 @@ fn statement(
 
             context.enter_scope();
             let mut then_stmts = Vec::new();
-            let mut reachable =
+            let then_reachable =
                 statement(then, &mut then_stmts, context, symtable, ns, diagnostics)?;
             context.leave_scope(symtable, *loc);
 
             let mut else_stmts = Vec::new();
-            if let Some(stmts) = else_ {
+            let else_reachable = if let Some(stmts) = else_ {
                 context.enter_scope();
-                reachable |= statement(stmts, &mut else_stmts, context, symtable, ns, diagnostics)?;
-
+                let reachable =
+                    statement(stmts, &mut else_stmts, context, symtable, ns, diagnostics)?;
                 context.leave_scope(symtable, *loc);
+
+                reachable
             } else {
-                reachable = true;
-            }
+                true
+            };
+
+            // if the condition is constant, only one of the branches can be taken
+            let reachable = match eval_const_bool(&cond, ns) {
+                Some(true) => then_reachable,
+                Some(false) => else_reachable,
+                None => then_reachable || else_reachable,
+            };
 
             res.push(Statement::If(*loc, reachable, cond, then_stmts, else_stmts));
 
@@ -756,6 +888,7 @@ fn statement(
                     )?;
 
                     ret.check_constant_overflow(diagnostics);
+                    discarded_return_values(&ret, ns, diagnostics);
                     ret
                 }
                 pt::Expression::NamedFunctionCall(loc, ty, args) => {
@@ -771,6 +904,7 @@ fn statement(
                         ResolveTo::Discard,
                     )?;
                     ret.check_constant_overflow(diagnostics);
+                    discarded_return_values(&ret, ns, diagnostics);
                     ret
                 }
                 _ => {
@@ -796,7 +930,7 @@ fn statement(
                 }
             };
 
-            let reachable = expr.tys() != vec![Type::Unreachable];
+            let reachable = expr.tys() != vec![Type::Unreachable] && !always_fails(&expr, ns);
 
             res.push(Statement::Expression(*loc, reachable, expr));
 
@@ -831,10 +965,25 @@ fn statement(
             block,
         } => {
             if dialect.is_some() && dialect.as_ref().unwrap().string != "evmasm" {
-                ns.diagnostics.push(Diagnostic::error(
-                    dialect.as_ref().unwrap().loc,
-                    "only evmasm dialect is supported".to_string(),
-                ));
+                let dialect_loc = dialect.as_ref().unwrap().loc;
+                let message = "only evmasm dialect is supported".to_string();
+
+                // inline assembly is not evm assembly on other targets, so explain what is lowered
+                if matches!(ns.target, Target::Polkadot { .. } | Target::Solana) {
+                    ns.diagnostics.push(Diagnostic::error_with_note(
+                        dialect_loc,
+                        message,
+                        *loc,
+                        format!(
+                            "inline assembly is compiled for target {} rather than the EVM; \
+                            only Yul statements and these builtins can be used: {}",
+                            ns.target,
+                            available_builtins(&ns.target).join(", ")
+                        ),
+                    ));
+                } else {
+                    ns.diagnostics.push(Diagnostic::error(dialect_loc, message));
+                }
                 return Err(());
             }
 
@@ -887,7 +1036,43 @@ fn statement(
             }
             Ok(false)
         }
-        pt::Statement::Error(_) => unimplemented!(),
+        pt::Statement::Error(loc) => {
+            // the parser recovered from a syntax error here
+            diagnostics.push(Diagnostic::parser_error(
+                *loc,
+                "statement could not be parsed".into(),
+            ));
+            Err(())
+        }
+    }
+}
+
+/// Does the expression always revert, e.g. `require(false, "no")` or `assert(1 > 2)`
+fn always_fails(expr: &Expression, ns: &Namespace) -> bool {
+    matches!(
+        expr,
+        Expression::Builtin {
+            kind: Builtin::Require | Builtin::Assert,
+            args,
+            ..
+        } if eval_const_bool(&args[0], ns) == Some(false)
+    )
+}
+
+/// Warn about a call to an internal function whose return values are discarded, since this
+/// often means an assignment was forgotten. Calls via function pointers are not checked.
+fn discarded_return_values(expr: &Expression, ns: &Namespace, diagnostics: &mut Diagnostics) {
+    if let Expression::InternalFunctionCall { loc, function, .. } = expr {
+        if let Expression::InternalFunction { function_no, .. } = function.as_ref() {
+            let func = &ns.functions[*function_no];
+
+            if !func.returns.is_empty() && !func.is_public() {
+                diagnostics.push(Diagnostic::warning(
+                    *loc,
+                    format!("return value of function '{}' is discarded", func.id),
+                ));
+            }
+        }
     }
 }
 
@@ -1038,6 +1223,13 @@ fn revert_named_arg(
     if let Some(path) = path {
         let error_no = ns.resolve_error(context.file_no, context.contract_no, path, diagnostics)?;
 
+        if ns.target == Target::Solana {
+            ns.diagnostics.push(Diagnostic::error(
+                *loc,
+                format!("revert with custom errors not supported on {}", ns.target),
+            ));
+        }
+
         let error = &ns.errors[error_no];
 
         let unnamed_fields = error
@@ -1046,6 +1238,7 @@ fn revert_named_arg(
             .filter(|param| param.id.is_none())
             .count();
 
+        // the fields can not be matched by name, so do not report any errors about the names
         if unnamed_fields > 0 {
             ns.diagnostics.push(Diagnostic::error_with_note(
                 path.loc,
@@ -1056,6 +1249,25 @@ fn revert_named_arg(
                 error.loc,
                 format!("definition of '{}'", error.name),
             ));
+
+            ns.errors[error_no].used = true;
+
+            for arg in args {
+                let _ = expression(
+                    &arg.expr,
+                    context,
+                    ns,
+                    symtable,
+                    diagnostics,
+                    ResolveTo::Unknown,
+                );
+            }
+
+            return Ok(Statement::Revert {
+                loc: *loc,
+                error_no: Some(error_no),
+                args: Vec::new(),
+            });
         }
 
         let fields: HashMap<String, (usize, Type)> = error
@@ -1069,6 +1281,8 @@ fn revert_named_arg(
             .collect();
 
         let mut arguments = HashMap::new();
+        // the fields for which an argument was given, even if it could not be resolved
+        let mut given = HashSet::new();
 
         for arg in args {
             let error = &ns.errors[error_no];
@@ -1076,7 +1290,7 @@ fn revert_named_arg(
             let name = arg.name.name.as_str();
 
             if let Some((pos, ty)) = fields.get(name) {
-                if arguments.contains_key(pos) {
+                if !given.insert(*pos) {
                     diagnostics.push(Diagnostic::error(
                         arg.name.loc,
                         format!("duplicate argument with name '{}'", arg.name.name),
@@ -1135,19 +1349,14 @@ fn revert_named_arg(
             }
         }
 
-        if ns.target == Target::Solana {
-            ns.diagnostics.push(Diagnostic::error(
-                *loc,
-                format!("revert with custom errors not supported on {}", ns.target),
-            ));
-        }
-
         let mut args = Vec::new();
         let error = &ns.errors[error_no];
 
         for pos in 0..error.fields.len() {
             if let Some(arg) = arguments.remove(&pos) {
                 args.push(arg);
+            } else if given.contains(&pos) {
+                // an error has already been reported for the argument
             } else if let Some(id) = &error.fields[pos].id {
                 ns.diagnostics.push(Diagnostic::error_with_note(
                     path.loc,
@@ -1647,6 +1856,8 @@ fn destructure(
                     ResolveTo::Unknown,
                 )?;
 
+                check_immutable_assignment(&e, &context, ns, diagnostics)?;
+
                 match &e {
                     Expression::ConstantVariable {
                         contract_no: Some(contract_no),
@@ -1673,27 +1884,7 @@ fn destructure(
                         ));
                         return Err(());
                     }
-                    Expression::StorageVariable {
-                        contract_no,
-                        var_no,
-                        ..
-                    } => {
-                        let store_var = &ns.contracts[*contract_no].variables[*var_no];
-
-                        if store_var.immutable
-                            && !ns.functions[context.function_no.unwrap()].is_constructor()
-                        {
-                            diagnostics.push(Diagnostic::error(
-                                *loc,
-                                format!(
-                                    "cannot assign to immutable '{}' outside of constructor",
-                                    store_var.name
-                                ),
-                            ));
-                            return Err(());
-                        }
-                    }
-                    Expression::Variable { .. } => (),
+                    Expression::StorageVariable { .. } | Expression::Variable { .. } => (),
                     _ => match e.ty() {
                         Type::Ref(_) | Type::StorageRef(false, _) => (),
                         _ => {
@@ -2259,7 +2450,9 @@ fn try_catch(
     if ns.target == Target::Solana {
         diagnostics.push(Diagnostic::error(
             *loc,
-            "The try-catch statement is not supported on Solana. Please, go to \
+            "The try-catch statement is not supported on Solana, since a failing external \
+             call or contract creation aborts the entire transaction and the catch clause \
+             could never run. Please, go to \
              https://solang.readthedocs.io/en/latest/language/statements.html#try-catch-statement \
              for more information"
                 .to_string(),
@@ -2635,6 +2828,7 @@ fn try_catch(
                 context.leave_scope(symtable, *catch_loc);
 
                 catch_all = Some(super::ast::CatchClause {
+                    loc: *catch_loc,
                     param: catch_param,
                     param_pos: catch_param_pos,
                     stmt: catch_stmt_resolved,
@@ -2724,7 +2918,7 @@ fn try_catch(
 
                 context.leave_scope(symtable, *catch_loc);
 
-                errors_resolved.push((error_pos, error_param, error_stmt_resolved));
+                errors_resolved.push((*catch_loc, error_pos, error_param, error_stmt_resolved));
 
                 Ok(())
             }
@@ -2739,7 +2933,8 @@ fn try_catch(
             returns: params,
             errors: errors_resolved
                 .iter()
-                .map(|(pos, param, stmt)| super::ast::CatchClause {
+                .map(|(loc, pos, param, stmt)| super::ast::CatchClause {
+                    loc: *loc,
                     param: param.clone().into(),
                     param_pos: *pos,
                     stmt: stmt.clone(),
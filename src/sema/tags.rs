@@ -18,12 +18,16 @@ pub fn resolve_tags(
     ns: &mut Namespace,
 ) -> Vec<Tag> {
     let mut res: Vec<Tag> = Vec::new();
+    // the span of all the tags of the doc comment
+    let mut doc_loc: Option<pt::Loc> = None;
 
     for c in tags.iter().flat_map(DocComment::comments) {
         let tag_loc = pt::Loc::File(file_no, c.tag_offset, c.tag_offset + c.tag.len() + 1);
         let value_loc = pt::Loc::File(file_no, c.value_offset, c.value_offset + c.value.len());
         let loc = pt::Loc::File(file_no, c.tag_offset - 1, c.value_offset + c.value.len());
 
+        doc_loc.get_or_insert(loc).union(&loc);
+
         match c.tag.as_str() {
             "notice" | "author" | "title" | "dev" => {
                 add_tag(loc, &mut res, c);
@@ -202,6 +206,22 @@ pub fn resolve_tags(
         }
     }
 
+    // if the parameters of a function are documented, they should all be documented
+    if let (Some(params), Some(_), Some(doc_loc)) = (params, returns, doc_loc) {
+        if res.iter().any(|e| e.tag == "param") && !res.iter().any(|e| e.tag == "inheritdoc") {
+            for (no, param) in params.iter().enumerate() {
+                if let Some(id) = &param.id {
+                    if !res.iter().any(|e| e.tag == "param" && e.no == no) {
+                        ns.diagnostics.push(Diagnostic::warning(
+                            doc_loc,
+                            format!("function parameter '{}' has no tag '@param'", id.name),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
     res
 }
 
@@ -5,9 +5,16 @@
 mod data_account;
 
 use crate::sema::ast::{Expression, Parameter, Statement, TryCatch, Type};
+use crate::sema::diagnostics::Diagnostics;
+use crate::sema::eval::{eval_const_bool, eval_const_rational, eval_constants_in_expression};
+use crate::sema::file::PathDisplay;
+use crate::sema::statements::resolve_function_body;
 use crate::sema::yul::ast::InlineAssembly;
-use crate::{parse_and_resolve, sema::ast, FileResolver, Target};
-use solang_parser::pt::Loc;
+use crate::{lint, parse_and_resolve, sema::ast, FileResolver, LintError, Target};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use solang_parser::pt::{self, Loc};
+use std::collections::BTreeSet;
 use std::ffi::{OsStr, OsString};
 use std::path::PathBuf;
 
@@ -143,6 +150,45 @@ fn test_statement_reachable() {
     }
 }
 
+#[test]
+fn statement_error_node() {
+    let src = "contract c { function f() public {} }";
+    let mut ns = parse(src);
+    assert!(!ns.diagnostics.any_errors());
+
+    let file_no = ns.top_file_no();
+    let (pt, _) = solang_parser::parse(src, file_no).unwrap();
+
+    let mut def = match &pt.0[0] {
+        pt::SourceUnitPart::ContractDefinition(contract) => match &contract.parts[0] {
+            pt::ContractPart::FunctionDefinition(def) => def.clone(),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+
+    // as produced by the parser when it recovers from a syntax error
+    let loc = Loc::File(file_no, 33, 34);
+    def.body = Some(pt::Statement::Block {
+        loc,
+        unchecked: false,
+        statements: vec![pt::Statement::Error(loc)],
+    });
+
+    let function_no = ns
+        .functions
+        .iter()
+        .position(|func| func.id.name == "f")
+        .unwrap();
+
+    assert!(resolve_function_body(&def, &[], file_no, Some(0), function_no, &mut ns).is_err());
+
+    let errors = ns.diagnostics.errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message, "statement could not be parsed");
+    assert_eq!(errors[0].loc, loc);
+}
+
 #[test]
 fn constant_overflow_checks() {
     let file = r#"
@@ -260,58 +306,98 @@ fn constant_overflow_checks() {
     let errors = ns.diagnostics.errors();
     let warnings = ns.diagnostics.warnings();
 
-    assert_eq!(errors[0].message, "value 133 does not fit into type int8.");
+    assert_eq!(
+        errors[0].message,
+        "value 133 does not fit into type int8 (valid range -128..=127)."
+    );
     assert_eq!(errors[1].message, "negative value -1 does not fit into type uint8. Cannot implicitly convert signed literal to unsigned type.");
-    assert_eq!(errors[2].message, "value 133 does not fit into type int8.");
+    assert_eq!(
+        errors[1].notes[0].message,
+        "to convert to uint8, use an explicit conversion 'uint8(int8(-1))' which gives 255, or write 255 directly"
+    );
+    assert_eq!(
+        errors[2].message,
+        "value 133 does not fit into type int8 (valid range -128..=127)."
+    );
     assert_eq!(errors[3].message, "negative value -1 does not fit into type uint8. Cannot implicitly convert signed literal to unsigned type.");
-    assert_eq!(errors[4].message, "value 762 does not fit into type int8.");
-    assert_eq!(errors[5].message, "value 882 does not fit into type int8.");
-    assert_eq!(errors[6].message, "value 128 does not fit into type int8.");
-    assert_eq!(errors[7].message, "value 128 does not fit into type int8.");
+    assert_eq!(
+        errors[4].message,
+        "value 762 does not fit into type int8 (valid range -128..=127)."
+    );
+    assert_eq!(
+        errors[5].message,
+        "value 882 does not fit into type int8 (valid range -128..=127)."
+    );
+    assert_eq!(
+        errors[6].message,
+        "value 128 does not fit into type int8 (valid range -128..=127)."
+    );
+    assert_eq!(
+        errors[7].message,
+        "value 128 does not fit into type int8 (valid range -128..=127)."
+    );
     assert_eq!(errors[8].message, "negative value -1 does not fit into type uint8. Cannot implicitly convert signed literal to unsigned type.");
-    assert_eq!(errors[9].message, "value 129 does not fit into type int8.");
+    assert_eq!(
+        errors[9].message,
+        "value 129 does not fit into type int8 (valid range -128..=127)."
+    );
     assert_eq!(errors[10].message, "negative value -1 does not fit into type uint8. Cannot implicitly convert signed literal to unsigned type.");
-    assert_eq!(errors[11].message, "value 129 does not fit into type int8.");
+    assert_eq!(
+        errors[11].message,
+        "value 129 does not fit into type int8 (valid range -128..=127)."
+    );
     assert_eq!(
         errors[12].message,
-        "value 4294967296 does not fit into type uint32."
+        "value 4294967296 does not fit into type uint32 (valid range 0..=4294967295)."
+    );
+    assert_eq!(
+        errors[13].message,
+        "value 130 does not fit into type int8 (valid range -128..=127)."
     );
-    assert_eq!(errors[13].message, "value 130 does not fit into type int8.");
     assert_eq!(
         errors[14].message,
-        "value 300 does not fit into type uint8."
+        "value 300 does not fit into type uint8 (valid range 0..=255)."
     );
     assert_eq!(
         errors[15].message,
-        "value 301 does not fit into type uint8."
+        "value 301 does not fit into type uint8 (valid range 0..=255)."
     );
     assert_eq!(
         errors[16].message,
-        "value 4294967296 does not fit into type uint32."
+        "value 4294967296 does not fit into type uint32 (valid range 0..=4294967295)."
+    );
+    assert_eq!(
+        errors[17].message,
+        "value 240 does not fit into type int8 (valid range -128..=127)."
+    );
+    assert_eq!(
+        errors[18].message,
+        "value 245 does not fit into type int8 (valid range -128..=127)."
     );
-    assert_eq!(errors[17].message, "value 240 does not fit into type int8.");
-    assert_eq!(errors[18].message, "value 245 does not fit into type int8.");
     assert_eq!(
         errors[19].message,
-        "value 260 does not fit into type uint8."
+        "value 260 does not fit into type uint8 (valid range 0..=255)."
     );
     assert_eq!(
         errors[20].message,
-        "value 261 does not fit into type uint8."
+        "value 261 does not fit into type uint8 (valid range 0..=255)."
     );
     assert_eq!(
         errors[21].message,
-        "value 269 does not fit into type uint8."
+        "value 269 does not fit into type uint8 (valid range 0..=255)."
+    );
+    assert_eq!(
+        errors[22].message,
+        "value 155 does not fit into type int8 (valid range -128..=127)."
     );
-    assert_eq!(errors[22].message, "value 155 does not fit into type int8.");
     assert_eq!(
         errors[23].message,
-        "value 262 does not fit into type uint8."
+        "value 262 does not fit into type uint8 (valid range 0..=255)."
     );
 
     assert_eq!(
         errors[24].message,
-        "value 744 does not fit into type uint8."
+        "value 744 does not fit into type uint8 (valid range 0..=255)."
     );
     assert_eq!(errors[25].message, "divide by zero");
     assert_eq!(errors[26].message, "divide by zero");
@@ -325,6 +411,34 @@ fn constant_overflow_checks() {
     assert_eq!(warnings.len(), 0);
 }
 
+#[test]
+fn divide_by_literal_zero() {
+    let ns = parse(
+        r#"
+contract c {
+    function f(uint y) public pure returns (uint, uint) {
+        uint x = y / 0;
+        return (x, y % (1 - 1));
+    }
+}"#,
+    );
+
+    // reported by sema, no codegen was run
+    let errors = ns.diagnostics.errors();
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].message, "divide by zero");
+    assert_eq!(
+        ns.loc_to_string(PathDisplay::None, &errors[0].loc),
+        "4:18-23"
+    );
+    assert_eq!(errors[1].message, "divide by zero");
+    assert_eq!(
+        ns.loc_to_string(PathDisplay::None, &errors[1].loc),
+        "5:20-31"
+    );
+}
+
 #[test]
 fn test_types() {
     let file = r#"
@@ -391,56 +505,56 @@ fn test_types() {
 
     assert_eq!(
         errors[0].message,
-        "value 2147483648 does not fit into type int32."
+        "value 2147483648 does not fit into type int32 (valid range -2147483648..=2147483647)."
     );
     assert_eq!(
         errors[1].message,
-        "value 2147483648 does not fit into type int32."
+        "value 2147483648 does not fit into type int32 (valid range -2147483648..=2147483647)."
     );
     assert_eq!(
         errors[2].message,
-        "value 9223372036854775808 does not fit into type int64."
+        "value 9223372036854775808 does not fit into type int64 (valid range -9223372036854775808..=9223372036854775807)."
     );
     assert_eq!(
         errors[3].message,
-        "value 9223372036854775808 does not fit into type int64."
+        "value 9223372036854775808 does not fit into type int64 (valid range -9223372036854775808..=9223372036854775807)."
     );
     assert_eq!(
         errors[4].message,
-        "value 18446744073709551616 does not fit into type uint64."
+        "value 18446744073709551616 does not fit into type uint64 (valid range 0..=18446744073709551615)."
     );
     assert_eq!(
         errors[5].message,
-        "value 340282366920938463463374607431768211456 does not fit into type uint128."
+        "value 340282366920938463463374607431768211456 does not fit into type uint128 (valid range 0..=340282366920938463463374607431768211455)."
     );
     assert_eq!(errors[6].message, "negative value -1 does not fit into type uint32. Cannot implicitly convert signed literal to unsigned type.");
     assert_eq!(
         errors[7].message,
-        "value 340282366920938463463374607431768211456 does not fit into type uint128."
+        "value 340282366920938463463374607431768211456 does not fit into type uint128 (valid range 0..=340282366920938463463374607431768211455)."
     );
 
-    assert_eq!(errors[8].message, "value 3000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000 does not fit into type uint256.");
+    assert_eq!(errors[8].message, "value 3000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000 does not fit into type uint256 (valid range 0..=115792089237316195423570985008687907853269984665640564039457584007913129639935).");
 
-    assert_eq!(errors[9].message, "value 463168356949264781694283940034751631413079938662562256157830336031652518559740 does not fit into type uint256.");
+    assert_eq!(errors[9].message, "value 463168356949264781694283940034751631413079938662562256157830336031652518559740 does not fit into type uint256 (valid range 0..=115792089237316195423570985008687907853269984665640564039457584007913129639935).");
     assert_eq!(
         errors[10].message,
-        "value 450000 does not fit into type uint16."
+        "value 450000 does not fit into type uint16 (valid range 0..=65535)."
     );
     assert_eq!(
         errors[11].message,
-        "value 65546 does not fit into type uint16."
+        "value 65546 does not fit into type uint16 (valid range 0..=65535)."
     );
     assert_eq!(
         errors[12].message,
-        "value 370 does not fit into type uint8."
+        "value 370 does not fit into type uint8 (valid range 0..=255)."
     );
     assert_eq!(
         errors[13].message,
-        "value 500 does not fit into type uint8."
+        "value 500 does not fit into type uint8 (valid range 0..=255)."
     );
     assert_eq!(
         errors[14].message,
-        "value 300 does not fit into type uint8."
+        "value 300 does not fit into type uint8 (valid range 0..=255)."
     );
     assert_eq!(errors.len(), 15);
 }
@@ -655,3 +769,1098 @@ fn get_import_path() {
         assert_eq!(Some(&(None, examples.clone())), import_path);
     }
 }
+
+#[test]
+fn diagnostics_to_json() {
+    let src = "contract c {\n    function f() public { /* ü */ int x; }\n}\n";
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    let json = ns.diagnostics_to_json();
+    let diagnostics = json.as_array().unwrap();
+
+    let unused = diagnostics
+        .iter()
+        .find(|diag| diag["message"] == "local variable 'x' is unused")
+        .unwrap();
+
+    assert_eq!(unused["level"], "warning");
+    assert_eq!(unused["location"]["kind"], "file");
+    assert_eq!(unused["location"]["file"], "test.sol");
+    assert_eq!(unused["location"]["start"]["line"], 2);
+    assert_eq!(unused["location"]["start"]["column"], 39);
+    assert_eq!(unused["location"]["end"]["column"], 40);
+    assert_eq!(unused["notes"], serde_json::json!([]));
+
+    let mut cache = FileResolver::default();
+
+    let ns = parse_and_resolve(OsStr::new("missing.sol"), &mut cache, Target::EVM);
+
+    let json = ns.diagnostics_to_json();
+
+    assert_eq!(json[0]["level"], "error");
+    assert_eq!(json[0]["location"]["kind"], "commandline");
+}
+
+#[test]
+fn constant_shift_right_signedness() {
+    let ns = parse(
+        r#"
+    contract test_contract {
+        function test() public pure {
+            int8 a = -128 >> 1;
+            uint8 b = 255 >> 1;
+            int8 c = (-128 >> 1) - 65;
+            uint8 d = (255 >> 1) + 129;
+        }
+    }"#,
+    );
+
+    let errors = ns.diagnostics.errors();
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[0].message,
+        "value -129 does not fit into type int8 (valid range -128..=127)."
+    );
+    assert_eq!(
+        errors[1].message,
+        "value 256 does not fit into type uint8 (valid range 0..=255)."
+    );
+
+    let loc = Loc::Codegen;
+    let shift = |value: i64, ty: Type, sign: bool| Expression::ShiftRight {
+        loc,
+        ty: ty.clone(),
+        left: Box::new(Expression::NumberLiteral {
+            loc,
+            ty: ty.clone(),
+            value: BigInt::from(value),
+        }),
+        right: Box::new(Expression::NumberLiteral {
+            loc,
+            ty,
+            value: BigInt::from(1),
+        }),
+        sign,
+    };
+
+    let mut diagnostics = Diagnostics::default();
+
+    let folded = [
+        (shift(-128, Type::Int(8), true), BigInt::from(-64)),
+        (shift(255, Type::Uint(8), false), BigInt::from(127)),
+        // a negative value in an unsigned type is shifted as its two's complement
+        (shift(-2, Type::Uint(8), false), BigInt::from(127)),
+    ];
+
+    for (expr, expected) in folded {
+        match eval_constants_in_expression(&expr, &mut diagnostics) {
+            (Some(Expression::NumberLiteral { value, .. }), true) => assert_eq!(value, expected),
+            _ => panic!("shift not folded"),
+        }
+    }
+
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn event_by_topic() {
+    let ns = parse(
+        r#"
+    contract token {
+        event Transfer(address indexed from, address indexed to, uint256 value);
+        event Approval(address indexed owner, address indexed spender, uint256 value);
+        event Anon(address indexed from, address indexed to, uint256 value) anonymous;
+
+        function f() public {
+            emit Transfer(address(0), address(0), 1);
+        }
+    }"#,
+    );
+
+    let topic =
+        hex::decode("e9a7da5bfc2bcbf4266adfba50ac5d6fa9ba4d52df50d9359a3974c36c131ce1").unwrap();
+
+    let events = ns.event_by_topic(&topic);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(ns.events[events[0]].id.name, "Transfer");
+
+    assert!(ns.event_by_topic(&[0u8; 32]).is_empty());
+}
+
+#[test]
+fn constant_multiply_large_operands() {
+    let ns = parse(
+        r#"
+    contract test_contract {
+        function test() public pure {
+            uint256 x = 4294967296 * 4294967296;
+            uint64 y = 4294967296 * 4294967296;
+        }
+    }"#,
+    );
+
+    let errors = ns.diagnostics.errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "value 18446744073709551616 does not fit into type uint64 (valid range 0..=18446744073709551615)."
+    );
+}
+
+#[test]
+fn constant_if_condition_reachability() {
+    let ns = parse(
+        r#"
+    contract c {
+        struct S {
+            uint64 x;
+        }
+
+        S s;
+        bool constant ALWAYS = true;
+
+        function f() internal view returns (S storage) {
+            if (true) {
+                return s;
+            }
+        }
+
+        function g() internal view returns (S storage) {
+            if (!ALWAYS) {
+                revert();
+            } else {
+                return s;
+            }
+        }
+
+        function h(bool b) internal view returns (S storage) {
+            if (b) {
+                return s;
+            }
+        }
+    }"#,
+    );
+
+    let errors = ns.diagnostics.errors();
+
+    // only h() can reach the end of the function without returning
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "storage reference must be given value with a return statement"
+    );
+
+    let h = ns.functions.iter().find(|f| f.id.name == "h").unwrap();
+    assert_eq!(errors[0].loc, h.returns[0].loc);
+}
+
+#[test]
+fn function_selector_per_target() {
+    let src = r#"
+    contract token {
+        function transfer(address to, uint256 amount) public {}
+    }"#;
+
+    let signature = "transfer(address,uint256)";
+
+    assert_eq!(
+        Target::EVM.function_selector("transfer", signature),
+        hex::decode("a9059cbb").unwrap()
+    );
+    assert_eq!(
+        Target::Solana.function_selector("transfer", signature),
+        hex::decode("a334c8e78c0345ba").unwrap()
+    );
+
+    for target in [Target::EVM, Target::Solana] {
+        let mut cache = FileResolver::default();
+        cache.set_file_contents("test.sol", src.to_string());
+
+        let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, target);
+
+        let func = ns
+            .functions
+            .iter()
+            .find(|func| func.id.name == "transfer")
+            .unwrap();
+
+        assert_eq!(func.signature, signature);
+        assert_eq!(
+            func.selector(&ns, &0),
+            target.function_selector("transfer", signature)
+        );
+    }
+}
+
+#[test]
+fn namespace_function_selector() {
+    let src = r#"
+    contract token {
+        function transfer(address to, uint256 amount) public {}
+
+        @selector([1, 2, 3, 4])
+        function approve(address spender, uint256 amount) public {}
+    }
+
+    function helper(uint256 a) pure returns (uint256) {
+        return a;
+    }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    let function_no = |name: &str| {
+        ns.functions
+            .iter()
+            .position(|func| func.id.name == name)
+            .unwrap()
+    };
+
+    assert_eq!(
+        ns.function_selector(function_no("transfer")),
+        hex::decode("a9059cbb").unwrap()
+    );
+    assert_eq!(
+        ns.function_selector(function_no("approve")),
+        vec![1, 2, 3, 4]
+    );
+    assert_eq!(
+        ns.function_selector(function_no("helper")),
+        Target::EVM.function_selector("helper", "helper(uint256)")
+    );
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.replace("@selector([1, 2, 3, 4])", ""));
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::Solana);
+
+    let transfer = ns
+        .functions
+        .iter()
+        .position(|func| func.id.name == "transfer")
+        .unwrap();
+
+    assert_eq!(
+        ns.function_selector(transfer),
+        hex::decode("a334c8e78c0345ba").unwrap()
+    );
+}
+
+#[test]
+fn constant_bool_short_circuit() {
+    let ns = ast::Namespace::new(Target::EVM);
+    let loc = Loc::Codegen;
+
+    let number = |value: i64| Expression::NumberLiteral {
+        loc,
+        ty: Type::Uint(256),
+        value: BigInt::from(value),
+    };
+
+    // 1/0 > 0
+    let divide_by_zero = Expression::More {
+        loc,
+        left: Box::new(Expression::Divide {
+            loc,
+            ty: Type::Uint(256),
+            unchecked: false,
+            left: Box::new(number(1)),
+            right: Box::new(number(0)),
+        }),
+        right: Box::new(number(0)),
+    };
+
+    let and = |left: bool| Expression::And {
+        loc,
+        left: Box::new(Expression::BoolLiteral { loc, value: left }),
+        right: Box::new(divide_by_zero.clone()),
+    };
+
+    let or = |left: bool| Expression::Or {
+        loc,
+        left: Box::new(Expression::BoolLiteral { loc, value: left }),
+        right: Box::new(divide_by_zero.clone()),
+    };
+
+    // the right hand side is never evaluated
+    assert_eq!(eval_const_bool(&and(false), &ns), Some(false));
+    assert_eq!(eval_const_bool(&or(true), &ns), Some(true));
+
+    // the right hand side cannot be evaluated
+    assert_eq!(eval_const_bool(&and(true), &ns), None);
+    assert_eq!(eval_const_bool(&or(false), &ns), None);
+
+    assert!(ns.diagnostics.is_empty());
+
+    let less = Expression::Less {
+        loc,
+        left: Box::new(number(2)),
+        right: Box::new(number(3)),
+    };
+
+    assert_eq!(eval_const_bool(&less, &ns), Some(true));
+}
+
+#[test]
+fn file_provider() {
+    use crate::file_resolver::FileProvider;
+    use std::path::Path;
+
+    struct Unsaved;
+
+    impl FileProvider for Unsaved {
+        fn file_contents(&self, path: &Path) -> Option<String> {
+            if path == Path::new("lib.sol") {
+                Some("function answer() pure returns (int) { return 42; }".into())
+            } else {
+                None
+            }
+        }
+    }
+
+    let mut cache = FileResolver::default();
+    cache.set_file_provider(Box::new(Unsaved));
+    cache.set_file_contents(
+        "test.sol",
+        r#"import "lib.sol";
+        contract c {
+            function f() public pure returns (int) {
+                return answer();
+            }
+        }"#
+        .to_string(),
+    );
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    assert!(!ns.diagnostics.any_errors());
+    assert!(ns.functions.iter().any(|func| func.id.name == "answer"));
+
+    // files which the provider does not have are still looked up on the filesystem
+    let mut cache = FileResolver::default();
+    cache.set_file_provider(Box::new(Unsaved));
+    cache.set_file_contents("test.sol", r#"import "missing.sol";"#.to_string());
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    assert!(ns
+        .diagnostics
+        .iter()
+        .any(|diag| diag.message == "file not found 'missing.sol'"));
+}
+
+#[test]
+fn duplicate_import() {
+    let mut cache = FileResolver::default();
+    cache.set_file_contents(
+        "lib.sol",
+        "function answer() pure returns (int) { return 42; }".to_string(),
+    );
+    cache.set_file_contents(
+        "test.sol",
+        r#"import "lib.sol";
+        import "./lib.sol";
+        import "lib.sol";
+        contract c {
+            function f() public pure returns (int) {
+                return answer();
+            }
+        }"#
+        .to_string(),
+    );
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    assert!(!ns.diagnostics.any_errors());
+
+    let warnings = ns.diagnostics.warnings();
+
+    assert_eq!(warnings.len(), 2);
+
+    for warning in warnings {
+        assert_eq!(warning.message, "file 'lib.sol' has already been imported");
+        assert_eq!(warning.notes[0].message, "previous import");
+    }
+
+    assert_eq!(
+        ns.functions
+            .iter()
+            .filter(|func| func.id.name == "answer")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn constant_conditional_array_length() {
+    let ns = parse(
+        r#"
+        contract c {
+            uint constant N = 4;
+            uint constant X = N > 2 ? 3 : 5;
+            uint[N > 2 ? X : 5] arr;
+            uint[N == 4 && false ? 1 : 2] arr2;
+        }"#,
+    );
+
+    assert!(!ns.diagnostics.any_errors());
+
+    let length = |var_no: usize| match &ns.contracts[0].variables[var_no].ty {
+        Type::Array(_, dims) => dims.clone(),
+        ty => panic!("unexpected type {ty:?}"),
+    };
+
+    assert_eq!(length(2), vec![ast::ArrayLength::Fixed(BigInt::from(3))]);
+    assert_eq!(length(3), vec![ast::ArrayLength::Fixed(BigInt::from(2))]);
+}
+
+#[cfg(unix)]
+#[test]
+fn symlink_policy() {
+    use crate::file_resolver::SymlinkPolicy;
+
+    let dir = tempfile::tempdir().unwrap();
+    let target = dir.path().join("target.sol");
+    let link = dir.path().join("link.sol");
+
+    std::fs::write(&target, "contract c {}").unwrap();
+    std::os::unix::fs::symlink(&target, &link).unwrap();
+
+    // symbolic links are followed by default
+    let mut cache = FileResolver::default();
+
+    let ns = parse_and_resolve(link.as_os_str(), &mut cache, Target::EVM);
+
+    assert!(!ns.diagnostics.any_errors());
+    assert_eq!(ns.contracts.len(), 1);
+
+    let mut cache = FileResolver::default();
+    cache.set_symlink_policy(SymlinkPolicy::NoFollow);
+
+    let ns = parse_and_resolve(link.as_os_str(), &mut cache, Target::EVM);
+
+    assert_eq!(
+        ns.diagnostics.first_error(),
+        format!(
+            "file '{}' is a symbolic link, which is not allowed",
+            link.display()
+        )
+    );
+
+    // regular files can still be read
+    let ns = parse_and_resolve(target.as_os_str(), &mut cache, Target::EVM);
+
+    assert!(!ns.diagnostics.any_errors());
+}
+
+#[test]
+fn loc_to_string_character_columns() {
+    let file = ast::File::new(
+        PathBuf::from("test.sol"),
+        "string s = \"é\";\nstring t = \"たこ\"; uint x;",
+        0,
+        None,
+    );
+
+    // columns after multibyte characters are counted in characters, not bytes
+    assert_eq!(
+        file.loc_to_string(PathDisplay::Filename, 11, 15),
+        "test.sol:1:12-15"
+    );
+    assert_eq!(file.loc_to_string(PathDisplay::None, 38, 44), "2:18-24");
+    assert_eq!(file.loc_to_string(PathDisplay::None, 17, 17), "2:1");
+}
+
+#[test]
+fn loc_to_range() {
+    let ns = parse("// ünïcødé\r\ncontract c {\r\n    string s = \"たこ\"; uint x;\r\n}\r\n");
+
+    let var = |name: &str| {
+        ns.contracts[0]
+            .variables
+            .iter()
+            .find(|var| var.name == name)
+            .unwrap()
+            .loc
+    };
+
+    // columns are counted in characters, and carriage returns do not start a new line
+    assert_eq!(ns.loc_to_range(&var("s")), Some((3, 5, 3, 20)));
+    assert_eq!(ns.loc_to_range(&var("x")), Some((3, 22, 3, 28)));
+    assert_eq!(ns.loc_to_range(&ns.contracts[0].loc), Some((2, 1, 4, 2)));
+    assert_eq!(ns.loc_to_string(PathDisplay::None, &var("x")), "3:22-28");
+    assert_eq!(ns.loc_to_range(&Loc::Builtin), None);
+}
+
+#[test]
+fn import_resolution_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path().canonicalize().unwrap();
+
+    for sub in ["a", "a/sub", "b", "c"] {
+        std::fs::create_dir_all(dir.join(sub)).unwrap();
+    }
+
+    std::fs::write(dir.join("a/sub/x.sol"), "contract a {}").unwrap();
+    std::fs::write(dir.join("b/x.sol"), "contract b {}").unwrap();
+    std::fs::write(dir.join("b/y.sol"), "contract b {}").unwrap();
+    std::fs::write(dir.join("c/y.sol"), "contract c {}").unwrap();
+
+    // the longest matching map wins, whatever order the maps were given in
+    for maps in [["lib", "lib/sub"], ["lib/sub", "lib"]] {
+        let mut cache = FileResolver::default();
+
+        for map in maps {
+            let target = if map == "lib" { "a" } else { "b" };
+
+            cache.add_import_map(map.into(), dir.join(target));
+        }
+
+        let file = cache
+            .resolve_file(None, OsStr::new("lib/sub/x.sol"))
+            .unwrap();
+
+        assert_eq!(file.full_path, dir.join("b/x.sol"));
+    }
+
+    // a file found in more than one import path is an error, listed in the order given
+    let mut cache = FileResolver::default();
+    cache.add_import_path(&dir.join("c"));
+    cache.add_import_path(&dir.join("b"));
+
+    let err = cache.resolve_file(None, OsStr::new("y.sol")).unwrap_err();
+
+    assert_eq!(
+        err,
+        format!(
+            "found multiple files matching 'y.sol': '{}', '{}'",
+            dir.join("c/y.sol").display(),
+            dir.join("b/y.sol").display()
+        )
+    );
+}
+
+#[test]
+fn unresolved_import_lists_search_paths() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path().canonicalize().unwrap();
+
+    let mut cache = FileResolver::default();
+    cache.add_import_path(&dir.join("a"));
+    cache.add_import_path(&dir.join("b"));
+    cache.add_import_map("lib".into(), dir.join("c"));
+    cache.set_file_contents("test.sol", r#"import "missing.sol";"#.to_string());
+
+    let ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    let expected = format!(
+        "file not found 'missing.sol'; searched import paths '{}', '{}' and import maps 'lib={}'",
+        dir.join("a").display(),
+        dir.join("b").display(),
+        dir.join("c").display()
+    );
+
+    assert!(ns.diagnostics.iter().any(|diag| diag.message == expected));
+}
+
+#[test]
+fn namespace_source_files() {
+    let mut cache = FileResolver::default();
+
+    cache.set_file_contents(
+        "a.sol",
+        r#"import "b.sol"; import "c.sol"; contract a {}"#.to_string(),
+    );
+    cache.set_file_contents("b.sol", r#"import "c.sol"; contract b {}"#.to_string());
+    cache.set_file_contents("c.sol", "contract c {}".to_string());
+    cache.set_file_contents("unused.sol", "contract d {}".to_string());
+
+    let ns = parse_and_resolve(OsStr::new("a.sol"), &mut cache, Target::EVM);
+
+    assert!(!ns.diagnostics.any_errors());
+
+    // imported files are listed once, and builtins and unused files are not listed
+    let mut files = ns.source_files();
+    files.sort();
+
+    assert_eq!(
+        files,
+        vec![
+            PathBuf::from("a.sol"),
+            PathBuf::from("b.sol"),
+            PathBuf::from("c.sol")
+        ]
+    );
+}
+
+#[test]
+fn trace_resolution() {
+    #[derive(Clone, Default)]
+    struct Buffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let buffer = Buffer::default();
+    let writer = buffer.clone();
+
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_span_events(tracing_subscriber::fmt::format::FmtSpan::NEW)
+        .with_ansi(false)
+        .without_time()
+        .with_writer(move || writer.clone())
+        .finish();
+
+    let ns = tracing::subscriber::with_default(subscriber, || {
+        parse(
+            r#"
+            contract c {
+                function f(uint8 a) public pure returns (uint64) {
+                    uint64 b = a;
+                    return b;
+                }
+            }"#,
+        )
+    });
+
+    assert!(!ns.diagnostics.any_errors());
+
+    let trace = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+
+    assert!(trace.contains("function{name=f"));
+    assert!(trace.contains("statement{kind=VariableDefinition"));
+    assert!(trace.contains("statement{kind=Return"));
+    assert!(trace.contains("from=uint8 to=uint64"));
+}
+
+#[test]
+fn block_chainid() {
+    let src = r#"
+        contract c {
+            function f() public view returns (uint256) {
+                return block.chainid;
+            }
+        }"#;
+
+    let resolve = |target| {
+        let mut cache = FileResolver::default();
+        cache.set_file_contents("test.sol", src.to_string());
+
+        parse_and_resolve(OsStr::new("test.sol"), &mut cache, target)
+    };
+
+    let ns = resolve(Target::EVM);
+
+    assert!(!ns.diagnostics.any_errors());
+
+    let func = ns.functions.iter().find(|f| f.id.name == "f").unwrap();
+
+    match &func.body[0] {
+        Statement::Return(_, Some(Expression::Builtin { kind, tys, .. })) => {
+            assert_eq!(*kind, ast::Builtin::ChainId);
+            assert_eq!(tys, &vec![Type::Uint(256)]);
+        }
+        stmt => panic!("unexpected statement {stmt:?}"),
+    }
+
+    let ns = resolve(Target::Solana);
+    let errors = ns.diagnostics.errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "'block.chainid' is not available on Solana, as Solana clusters do not have a chain id"
+    );
+
+    let ns = resolve(Target::default_polkadot());
+    let errors = ns.diagnostics.errors();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].message,
+        "'block.chainid' is not available on Polkadot, as contracts cannot access a chain id or the genesis hash of the chain"
+    );
+}
+
+#[test]
+fn dedup_base_contract_diagnostics() {
+    let ns = parse(
+        r#"
+        contract A { function f() public {} }
+        contract B { function f() public {} }
+        contract D { function f() public {} }
+        contract C1 is A, B {}
+        contract C2 is D, B {}
+        "#,
+    );
+
+    let errors = ns
+        .diagnostics
+        .iter()
+        .filter(|diag| diag.message == "function 'f' with this signature already defined")
+        .collect::<Vec<_>>();
+
+    // both derived contracts find the same error in B, with a note for their other base
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].notes.len(), 2);
+    assert_eq!(
+        ns.loc_to_string(PathDisplay::None, &errors[0].loc),
+        "3:22-41"
+    );
+}
+
+#[test]
+fn concat_literals() {
+    let ns = parse(
+        r#"
+        contract C {
+            function f() public pure returns (string) {
+                return string.concat("a", "b");
+            }
+
+            function g(bytes x) public pure returns (bytes) {
+                return bytes.concat("a", x);
+            }
+        }
+        "#,
+    );
+
+    assert!(!ns.diagnostics.any_errors());
+
+    let func = ns.functions.iter().find(|f| f.id.name == "f").unwrap();
+
+    match &func.body[0] {
+        Statement::Return(
+            _,
+            Some(Expression::AllocDynamicBytes {
+                ty,
+                init: Some(value),
+                ..
+            }),
+        ) => {
+            assert_eq!(*ty, Type::String);
+            assert_eq!(value, b"ab");
+        }
+        stmt => panic!("unexpected statement {stmt:?}"),
+    }
+
+    // arguments which are not literals are concatenated at runtime
+    let func = ns.functions.iter().find(|f| f.id.name == "g").unwrap();
+
+    match &func.body[0] {
+        Statement::Return(_, Some(Expression::Builtin { kind, args, .. })) => {
+            assert_eq!(*kind, ast::Builtin::BytesConcat);
+            assert_eq!(args.len(), 2);
+        }
+        stmt => panic!("unexpected statement {stmt:?}"),
+    }
+}
+
+#[test]
+fn contract_dependencies() {
+    let mut ns = parse(
+        r#"
+        contract A {
+            function f() public {
+                new B();
+            }
+        }
+
+        contract B {
+            C c = new C();
+        }
+
+        contract C {}
+        "#,
+    );
+
+    assert!(!ns.diagnostics.any_errors());
+
+    let no = |name: &str, ns: &ast::Namespace| {
+        ns.contracts.iter().position(|c| c.id.name == name).unwrap()
+    };
+
+    let (a, b, c) = (no("A", &ns), no("B", &ns), no("C", &ns));
+
+    assert_eq!(ns.contract_dependencies(a, false), Ok([b].into()));
+    assert_eq!(ns.contract_dependencies(a, true), Ok([b, c].into()));
+    assert_eq!(ns.contract_dependencies(b, true), Ok([c].into()));
+    assert_eq!(ns.contract_dependencies(c, true), Ok(BTreeSet::new()));
+
+    // sema rejects mutual instantiation, but the graph walk should not recurse forever
+    ns.contracts[c].creates.push(a);
+
+    assert_eq!(ns.contract_dependencies(a, true), Err(vec![a, b, c, a]));
+    assert_eq!(ns.contract_dependencies(c, false), Ok([a].into()));
+}
+
+#[test]
+fn rational_power_and_shift() {
+    let loc = Loc::File(0, 0, 0);
+    let ns = ast::Namespace::new(Target::EVM);
+
+    let rational = |n: i32, d: i32| Expression::RationalNumberLiteral {
+        loc,
+        ty: Type::Rational,
+        value: BigRational::new(n.into(), d.into()),
+    };
+    let number = |n: i32| Expression::NumberLiteral {
+        loc,
+        ty: Type::Uint(8),
+        value: n.into(),
+    };
+    let power = |base, exp| Expression::Power {
+        loc,
+        ty: Type::Rational,
+        unchecked: false,
+        base: Box::new(base),
+        exp: Box::new(exp),
+    };
+
+    // 2.5 ** 2 == 6.25
+    assert_eq!(
+        eval_const_rational(&power(rational(5, 2), number(2)), &ns)
+            .unwrap()
+            .1,
+        BigRational::new(25.into(), 4.into())
+    );
+
+    assert_eq!(
+        eval_const_rational(&power(rational(5, 2), number(0)), &ns)
+            .unwrap()
+            .1,
+        BigRational::from_integer(1.into())
+    );
+
+    assert_eq!(
+        eval_const_rational(&power(rational(5, 2), rational(1, 2)), &ns)
+            .unwrap_err()
+            .message,
+        "exponent of rational power must be an integer"
+    );
+
+    assert_eq!(
+        eval_const_rational(&power(rational(5, 2), rational(-2, 1)), &ns)
+            .unwrap_err()
+            .message,
+        "power cannot take negative number as exponent"
+    );
+
+    let shift = Expression::ShiftLeft {
+        loc,
+        ty: Type::Rational,
+        left: Box::new(rational(3, 2)),
+        right: Box::new(number(1)),
+    };
+
+    assert_eq!(
+        eval_const_rational(&shift, &ns).unwrap_err().message,
+        "cannot shift a rational"
+    );
+}
+
+#[test]
+fn lint_warnings() {
+    let mut cache = FileResolver::default();
+
+    cache.set_file_contents(
+        "test.sol",
+        r#"
+        contract c {
+            function f() public pure returns (uint64) {
+                uint64 x;
+                return 2;
+            }
+        }"#
+        .to_string(),
+    );
+
+    let warnings = lint(OsStr::new("test.sol"), &mut cache, Target::EVM, false).unwrap();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].level, ast::Level::Warning);
+    assert_eq!(warnings[0].message, "local variable 'x' is unused");
+
+    assert_eq!(
+        lint(OsStr::new("test.sol"), &mut cache, Target::EVM, true),
+        Err(LintError::WarningsAsErrors(warnings))
+    );
+
+    cache.set_file_contents("clean.sol", "contract c {}".to_string());
+
+    assert_eq!(
+        lint(OsStr::new("clean.sol"), &mut cache, Target::EVM, true),
+        Ok(vec![])
+    );
+
+    cache.set_file_contents("syntax.sol", "contract c {".to_string());
+
+    let Err(LintError::SourceErrors(errors)) =
+        lint(OsStr::new("syntax.sol"), &mut cache, Target::EVM, false)
+    else {
+        panic!("syntax error not reported");
+    };
+
+    assert!(!errors.is_empty());
+    assert!(errors.iter().all(|diag| diag.level == ast::Level::Error));
+
+    let Err(LintError::SourceErrors(errors)) =
+        lint(OsStr::new("missing.sol"), &mut cache, Target::EVM, false)
+    else {
+        panic!("missing file not reported");
+    };
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].level, ast::Level::Error);
+    assert_eq!(errors[0].message, "file not found 'missing.sol'");
+}
+
+#[test]
+fn empty_catch() {
+    let src = r#"
+        abstract contract base {
+            function empty(other o) public {
+                try o.f() {
+                } catch (bytes memory) {
+                }
+            }
+        }
+
+        contract c {
+            bytes reason_;
+
+            function handled(other o) public {
+                try o.f() {
+                } catch (bytes memory reason) {
+                    reason_ = reason;
+                }
+            }
+        }
+
+        contract other {
+            function f() public {}
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let mut ns = parse_and_resolve(
+        OsStr::new("test.sol"),
+        &mut cache,
+        Target::default_polkadot(),
+    );
+
+    assert!(!ns.diagnostics.contains_message(
+        "catch clause has an empty body, so any failure of the call is silently ignored"
+    ));
+
+    ns.check_empty_catch();
+
+    let warnings = ns.diagnostics.warnings();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "catch clause has an empty body, so any failure of the call is silently ignored"
+    );
+    assert_eq!(
+        warnings[0].loc.start(),
+        src.find("catch (bytes memory) {").unwrap()
+    );
+}
+
+#[test]
+fn allow_tx_origin() {
+    let src = r#"
+        contract c {
+            address owner;
+            bool b;
+
+            function f() public {
+                require(tx.origin == owner);
+                b = tx.origin != msg.sender;
+                assert(b);
+            }
+        }"#;
+
+    let mut cache = FileResolver::default();
+    cache.set_file_contents("test.sol", src.to_string());
+
+    let mut ns = parse_and_resolve(OsStr::new("test.sol"), &mut cache, Target::EVM);
+
+    let warnings = ns.diagnostics.warnings();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        warnings[0].message,
+        "'tx.origin' used for authorization is vulnerable to phishing, use 'msg.sender' instead"
+    );
+
+    ns.allow_tx_origin();
+
+    assert!(ns.diagnostics.warnings().is_empty());
+    assert!(!ns.diagnostics.any_errors());
+}
+
+#[test]
+fn parse_cache() {
+    let main = r#"
+        import "lib.sol";
+
+        contract c {
+            function f() public pure returns (int) {
+                return X;
+            }
+        }"#;
+
+    let cache = crate::file_resolver::ParseCache::default();
+
+    let resolve = |lib: &str| {
+        let mut resolver = FileResolver::default();
+        resolver.set_file_contents("main.sol", main.to_string());
+        resolver.set_file_contents("lib.sol", lib.to_string());
+        resolver.set_parse_cache(cache.clone());
+
+        parse_and_resolve(OsStr::new("main.sol"), &mut resolver, Target::EVM)
+    };
+
+    let ns = resolve("int constant X = 1;");
+
+    assert!(!ns.diagnostics.any_errors());
+    assert_eq!(cache.hits(), 0);
+
+    // neither file has changed, so both parse trees come from the cache
+    let ns = resolve("int constant X = 1;");
+
+    assert!(!ns.diagnostics.any_errors());
+    assert_eq!(cache.hits(), 2);
+
+    // the cached parse tree of the import must not be used once it has changed
+    let ns = resolve("int constant Y = 1;");
+
+    assert!(ns.diagnostics.contains_message("'X' not found"));
+    assert_eq!(cache.hits(), 3);
+
+    let ns = resolve("int constant X = 1;");
+
+    assert!(!ns.diagnostics.any_errors());
+    assert_eq!(cache.hits(), 4);
+}
@@ -13,7 +13,7 @@ use crate::sema::expression::resolve_expression::expression;
 use crate::sema::namespace::ResolveTypeContext;
 use solang_parser::pt::CodeLocation;
 use solang_parser::pt::{self};
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
 /// Resolve a using declaration in either file scope or contract scope
 pub(crate) fn using_decl(
@@ -350,7 +350,7 @@ fn possible_functions(
     function_name: &str,
     self_expr: &Expression,
     ns: &Namespace,
-) -> HashSet<usize> {
+) -> BTreeSet<usize> {
     let mut diagnostics = Diagnostics::default();
     using
         .iter()
@@ -429,7 +429,7 @@ pub(super) fn try_resolve_using_call(
     resolve_to: ResolveTo,
 ) -> Result<Option<Expression>, ()> {
     // first collect all possible functions that could be used for using
-    // Use HashSet for deduplication.
+    // Use BTreeSet for deduplication, and so that candidates are listed in a stable order.
     // If the using directive specifies a type, the type must match the type of
     // the method call object exactly.
     let mut functions = possible_functions(&ns.using, context.file_no, &func.name, self_expr, ns);
@@ -446,6 +446,7 @@ pub(super) fn try_resolve_using_call(
 
     let mut name_matches = 0;
     let mut errors = Diagnostics::default();
+    let mut resolved_calls = Vec::new();
 
     for function_no in functions {
         let libfunc = &ns.functions[function_no];
@@ -534,31 +535,52 @@ pub(super) fn try_resolve_using_call(
             identifiers: vec![func.clone()],
         };
 
-        return Ok(Some(Expression::InternalFunctionCall {
-            loc: *loc,
-            returns,
-            function: Box::new(Expression::InternalFunction {
+        resolved_calls.push((
+            function_no,
+            Expression::InternalFunctionCall {
                 loc: *loc,
-                id: id_path,
-                ty,
-                function_no,
-                signature: None,
-            }),
-            args: cast_args,
-        }));
+                returns,
+                function: Box::new(Expression::InternalFunction {
+                    loc: *loc,
+                    id: id_path,
+                    ty,
+                    function_no,
+                    signature: None,
+                }),
+                args: cast_args,
+            },
+        ));
     }
 
-    match name_matches {
-        0 => Ok(None),
-        1 => {
-            diagnostics.extend(errors);
+    match resolved_calls.len() {
+        0 => match name_matches {
+            0 => Ok(None),
+            1 => {
+                diagnostics.extend(errors);
 
-            Err(())
-        }
+                Err(())
+            }
+            _ => {
+                diagnostics.push(Diagnostic::error(
+                    *loc,
+                    "cannot find overloaded function which matches signature".to_string(),
+                ));
+                Err(())
+            }
+        },
+        1 => Ok(resolved_calls.pop().map(|(_, expr)| expr)),
         _ => {
-            diagnostics.push(Diagnostic::error(
+            // e.g. two libraries attached with `using for *` which both provide the method
+            diagnostics.push(Diagnostic::error_with_notes(
                 *loc,
-                "cannot find overloaded function which matches signature".to_string(),
+                "function call can be resolved to multiple functions".into(),
+                resolved_calls
+                    .iter()
+                    .map(|(function_no, _)| Note {
+                        loc: ns.functions[*function_no].loc,
+                        message: "candidate function".into(),
+                    })
+                    .collect(),
             ));
             Err(())
         }
@@ -205,6 +205,15 @@ pub fn parse_builtin_keyword(keyword: &str) -> Option<&YulBuiltInFunction> {
     BUILTIN_YUL_FUNCTIONS.get(keyword)
 }
 
+/// The names of the builtin functions which can be used in inline assembly on the given target
+pub(crate) fn available_builtins(target: &Target) -> Vec<&'static str> {
+    YUL_BUILTIN
+        .iter()
+        .filter(|prototype| prototype.is_available(target))
+        .map(|prototype| prototype.name)
+        .collect()
+}
+
 impl YulBuiltInFunction {
     /// Retrieve the prototype from the enum type
     pub(crate) fn get_prototype_info(self) -> &'static YulBuiltinPrototype {
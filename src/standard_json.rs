@@ -1,10 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
-//! This module defines the json format for `solang compile --standard-json`.
+//! This module defines the json format for `solang compile --standard-json`, and the
+//! Solidity standard JSON input format for `solang compile --standard-json-input`.
 
 use crate::abi::ethereum::ABI;
-use serde::Serialize;
-use std::collections::HashMap;
+use crate::file_resolver::FileResolver;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::OsString;
+use std::fs;
+use std::path::PathBuf;
 
 #[derive(Serialize)]
 pub struct EwasmContract {
@@ -47,3 +52,79 @@ pub struct OutputJson {
     pub message: String,
     pub formattedMessage: String,
 }
+
+/// The Solidity standard JSON input, see
+/// <https://docs.soliditylang.org/en/latest/using-the-compiler.html#input-description>.
+/// Only the sources and the remappings are used; other settings like `outputSelection`
+/// are ignored.
+#[derive(Deserialize)]
+pub struct JsonInput {
+    #[serde(default)]
+    pub language: String,
+    pub sources: BTreeMap<String, JsonSource>,
+    #[serde(default)]
+    pub settings: JsonSettings,
+}
+
+/// A source file is either given by its content, or by a list of urls to read it from
+#[derive(Deserialize)]
+pub struct JsonSource {
+    pub content: Option<String>,
+    #[serde(default)]
+    pub urls: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+pub struct JsonSettings {
+    #[serde(default)]
+    pub remappings: Vec<String>,
+}
+
+impl JsonInput {
+    /// Add the sources to the file resolver, by their source unit name, and the remappings as
+    /// import maps. Only local files can be read from the urls of a source.
+    pub fn add_to_resolver(&self, resolver: &mut FileResolver) -> Result<(), String> {
+        if !self.language.is_empty() && self.language != "Solidity" {
+            return Err(format!("language '{}' is not supported", self.language));
+        }
+
+        for remapping in &self.settings.remappings {
+            let (prefix, target) = match remapping.split_once('=') {
+                Some((prefix, target)) if !prefix.is_empty() => (prefix, target),
+                _ => return Err(format!("invalid remapping '{remapping}'")),
+            };
+
+            if prefix.contains(':') {
+                return Err(format!(
+                    "remapping '{remapping}' has a context, which is not supported"
+                ));
+            }
+
+            resolver.add_import_map(OsString::from(prefix), PathBuf::from(target));
+        }
+
+        for (name, source) in &self.sources {
+            let contents = match &source.content {
+                Some(content) => content.clone(),
+                None => source
+                    .urls
+                    .iter()
+                    .filter_map(|url| {
+                        let path = url.strip_prefix("file://").unwrap_or(url);
+
+                        if path.contains("://") {
+                            None
+                        } else {
+                            fs::read_to_string(path).ok()
+                        }
+                    })
+                    .next()
+                    .ok_or_else(|| format!("cannot read source '{name}' from its urls"))?,
+            };
+
+            resolver.set_file_contents(name, contents);
+        }
+
+        Ok(())
+    }
+}
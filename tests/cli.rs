@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use assert_cmd::Command;
-use std::fs::File;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
 use tempfile::TempDir;
 
 #[test]
@@ -130,3 +131,856 @@ fn basic_compilation_from_toml() {
 
     compile_cmd.current_dir(polkadot_test).assert().success();
 }
+
+/// Compile the same source in two different directories, and check that the objects and the
+/// metadata are byte-identical
+fn assert_reproducible(target: &str, source: &str, flags: &[&str], metadata_ext: &str) {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let mut objects = Vec::new();
+    let mut metadata = Vec::new();
+
+    for dir in ["first", "second/nested"] {
+        let src_dir = tmp.path().join(dir);
+        fs::create_dir_all(&src_dir).unwrap();
+        fs::copy(source, src_dir.join("flipper.sol")).unwrap();
+
+        let output = src_dir.join("out");
+
+        for emit in ["object", "metadata"] {
+            Command::cargo_bin("solang")
+                .unwrap()
+                .args(["compile", "--target", target, "--emit", emit])
+                .args(flags)
+                .arg(src_dir.join("flipper.sol"))
+                .arg("--output")
+                .arg(&output)
+                .assert()
+                .success();
+        }
+
+        objects.push(fs::read(output.join("flipper.o")).unwrap());
+        metadata.push(fs::read(output.join(format!("flipper.{metadata_ext}"))).unwrap());
+    }
+
+    assert_eq!(objects[0], objects[1]);
+    assert_eq!(metadata[0], metadata[1]);
+}
+
+#[test]
+fn reproducible_object() {
+    assert_reproducible("solana", "examples/solana/flipper.sol", &["-g"], "json");
+}
+
+// wasm-opt only runs on the linked contract, so the object must not depend on it either
+#[cfg(feature = "wasm_opt")]
+#[test]
+fn reproducible_polkadot_object() {
+    assert_reproducible(
+        "polkadot",
+        "examples/polkadot/flipper.sol",
+        &["-g", "--wasm-opt", "z"],
+        "contract",
+    );
+}
+
+#[test]
+fn exclude_input_files() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let helpers = tmp.path().join("helpers");
+    fs::create_dir_all(&helpers).unwrap();
+
+    fs::copy(
+        "examples/solana/flipper.sol",
+        tmp.path().join("flipper.sol"),
+    )
+    .unwrap();
+    fs::write(
+        helpers.join("broken.sol"),
+        "contract broken { syntax error }",
+    )
+    .unwrap();
+
+    let output = tmp.path().join("out");
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "--target",
+            "solana",
+            "--exclude",
+            "**/helpers/*.sol",
+        ])
+        .arg(tmp.path().join("flipper.sol"))
+        .arg(helpers.join("broken.sol"))
+        .arg("--output")
+        .arg(&output)
+        .assert()
+        .success();
+
+    File::open(output.join("flipper.so")).expect("should exist");
+
+    // without the exclude pattern, the broken file is compiled and fails
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "solana"])
+        .arg(tmp.path().join("flipper.sol"))
+        .arg(helpers.join("broken.sol"))
+        .arg("--output")
+        .arg(&output)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn emit_wat() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/polkadot/flipper.sol",
+            "--target",
+            "polkadot",
+            "--emit",
+            "asm",
+            "--asm-flavor",
+            "wat",
+            "--output",
+        ])
+        .arg(tmp.path())
+        .assert()
+        .success();
+
+    let wat = fs::read_to_string(tmp.path().join("flipper.wat")).unwrap();
+
+    assert!(wat.contains("(module"));
+
+    // the compact form leaves out the function bodies
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/polkadot/flipper.sol",
+            "--target",
+            "polkadot",
+            "--emit",
+            "asm",
+            "--asm-flavor",
+            "compact",
+            "--output",
+        ])
+        .arg(tmp.path())
+        .assert()
+        .success();
+
+    let compact = fs::read_to_string(tmp.path().join("flipper.wat")).unwrap();
+
+    assert!(compact.contains("(module"));
+    assert!(wat.contains("local.get"));
+    assert!(!compact.contains("local.get"));
+    assert!(compact.len() < wat.len());
+
+    // the flavor only applies to assembly
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/polkadot/flipper.sol",
+            "--target",
+            "polkadot",
+            "--emit",
+            "object",
+            "--asm-flavor",
+            "wat",
+            "--output",
+        ])
+        .arg(tmp.path())
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    assert!(stderr.contains("error: --asm-flavor can only be used with --emit asm"));
+}
+
+#[test]
+fn emit_annotated_asm() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/solana/flipper.sol",
+            "--target",
+            "solana",
+            "--emit",
+            "asm",
+            "--asm-flavor",
+            "annotated",
+            "--output",
+        ])
+        .arg(tmp.path())
+        .assert()
+        .success();
+
+    let asm = fs::read_to_string(tmp.path().join("flipper.asm")).unwrap();
+
+    assert!(asm.contains(".loc"));
+    assert!(asm.contains("# value = !value;"));
+
+    // annotated assembly is only available for Solana, and wat only for wasm
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/polkadot/flipper.sol",
+            "--target",
+            "polkadot",
+            "--emit",
+            "asm",
+            "--asm-flavor",
+            "annotated",
+            "--output",
+        ])
+        .arg(tmp.path())
+        .assert()
+        .failure();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+
+    assert!(stdout.contains("error: annotated assembly is not available for target Polkadot"));
+
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/solana/flipper.sol",
+            "--target",
+            "solana",
+            "--emit",
+            "asm",
+            "--asm-flavor",
+            "compact",
+            "--output",
+        ])
+        .arg(tmp.path())
+        .assert()
+        .failure();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+
+    assert!(stdout.contains("error: WebAssembly text format is not available for target Solana"));
+}
+
+#[test]
+fn report_json() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let output = tmp.path().join("out");
+    let report = tmp.path().join("report.json");
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/solana/flipper.sol",
+            "--target",
+            "solana",
+            "--output",
+        ])
+        .arg(&output)
+        .arg("--report-json")
+        .arg(&report)
+        .assert()
+        .success();
+
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(report).unwrap()).unwrap();
+
+    let artifacts = report["artifacts"].as_array().unwrap();
+
+    let binary = artifacts
+        .iter()
+        .find(|artifact| artifact["path"].as_str().unwrap().ends_with("flipper.so"))
+        .expect("binary should be listed");
+
+    let contents = fs::read(output.join("flipper.so")).unwrap();
+
+    assert_eq!(binary["contract"], "flipper");
+    assert_eq!(binary["size"], contents.len());
+    assert_eq!(binary["sha256"], hex::encode(Sha256::digest(&contents)));
+
+    assert!(artifacts
+        .iter()
+        .any(|artifact| artifact["path"].as_str().unwrap().ends_with("flipper.json")));
+}
+
+#[test]
+fn max_errors() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let source = tmp.path().join("errors.sol");
+
+    fs::write(
+        &source,
+        r#"contract errors {
+            function a() public { x = 1; }
+            function b() public { y = 2; }
+            function c() public { z = 3; }
+        }"#,
+    )
+    .unwrap();
+
+    let count_errors = |max_errors: Option<&str>| {
+        let mut cmd = Command::cargo_bin("solang").unwrap();
+
+        cmd.args(["compile", "--target", "polkadot"]).arg(&source);
+
+        if let Some(max_errors) = max_errors {
+            cmd.args(["--max-errors", max_errors]);
+        }
+
+        let assert = cmd.assert().failure();
+
+        String::from_utf8_lossy(&assert.get_output().stderr)
+            .matches("error: ")
+            .count()
+    };
+
+    assert_eq!(count_errors(None), 3);
+    assert_eq!(count_errors(Some("1")), 1);
+    assert_eq!(count_errors(Some("2")), 2);
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--max-errors", "0"])
+        .arg(&source)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn include_source_in_metadata() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let source = fs::read_to_string("examples/polkadot/flipper.sol").unwrap();
+
+    let metadata = |include_source: bool| {
+        let output = tmp.path().join(format!("out_{include_source}"));
+
+        let mut cmd = Command::cargo_bin("solang").unwrap();
+
+        cmd.args([
+            "compile",
+            "examples/polkadot/flipper.sol",
+            "--target",
+            "polkadot",
+            "--output",
+        ])
+        .arg(&output);
+
+        if include_source {
+            cmd.arg("--include-source-in-metadata");
+        }
+
+        cmd.assert().success();
+
+        serde_json::from_str::<serde_json::Value>(
+            &fs::read_to_string(output.join("flipper.contract")).unwrap(),
+        )
+        .unwrap()
+    };
+
+    let without = metadata(false);
+
+    assert!(without["source"]["hash"].is_string());
+
+    let sources = without["user"]["sources"].as_object().unwrap();
+    let (path, file) = sources.iter().next().unwrap();
+
+    assert!(path.ends_with("flipper.sol"));
+    assert!(file["hash"].as_str().unwrap().starts_with("0x"));
+    assert!(file.get("content").is_none());
+
+    let with = metadata(true);
+
+    assert!(with["source"]["hash"].is_string());
+
+    let sources = with["user"]["sources"].as_object().unwrap();
+    let (path, file) = sources.iter().next().unwrap();
+
+    assert!(path.ends_with("flipper.sol"));
+    assert_eq!(file["hash"], without["user"]["sources"][path]["hash"]);
+    assert_eq!(file["content"], source);
+}
+
+#[test]
+fn output_meta() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let output = tmp.path().join("out");
+    let meta = tmp.path().join("meta");
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/polkadot/flipper.sol",
+            "--target",
+            "polkadot",
+            "--contract-authors",
+            "itchy",
+            "--version",
+            "1.2.3",
+            "--output",
+        ])
+        .arg(&output)
+        .arg("--output-meta")
+        .arg(&meta)
+        .assert()
+        .success();
+
+    assert!(output.join("flipper.wasm").exists());
+    assert!(!output.join("flipper.contract").exists());
+
+    let metadata: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(meta.join("flipper.contract")).unwrap()).unwrap();
+
+    for key in ["source", "contract", "spec", "user"] {
+        assert!(metadata.get(key).is_some(), "{key}");
+    }
+
+    assert!(metadata["source"]["compiler"]
+        .as_str()
+        .unwrap()
+        .contains(env!("CARGO_PKG_VERSION")));
+    assert_eq!(metadata["contract"]["authors"][0], "itchy");
+    assert_eq!(metadata["contract"]["version"], "1.2.3");
+    assert_eq!(metadata["user"]["target"], "Polkadot");
+    assert!(metadata["user"]["sources"]
+        .as_object()
+        .unwrap()
+        .keys()
+        .any(|path| path.ends_with("flipper.sol")));
+}
+
+#[test]
+fn emit_deps() {
+    let tmp = TempDir::new_in("tests").unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+
+    fs::write(
+        dir.join("main.sol"),
+        "import \"./lib.sol\";\ncontract main { function f() public { L.g(); } }",
+    )
+    .unwrap();
+    fs::write(
+        dir.join("lib.sol"),
+        "library L { function g() internal {} }",
+    )
+    .unwrap();
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--emit", "deps"])
+        .arg(dir.join("main.sol"))
+        .arg("--output")
+        .arg(&dir)
+        .assert()
+        .success();
+
+    let deps = fs::read_to_string(dir.join("main.d")).unwrap();
+
+    assert_eq!(
+        deps,
+        format!(
+            "{}: {} {}\n",
+            dir.join("main.wasm").display(),
+            dir.join("main.sol").display(),
+            dir.join("lib.sol").display()
+        )
+    );
+
+    // only the depfile is written
+    assert!(!dir.join("main.wasm").exists());
+}
+
+#[test]
+fn emit_ast_json() {
+    let tmp = TempDir::new_in("tests").unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+
+    fs::write(
+        dir.join("main.sol"),
+        r#"
+        struct S { S[] children; uint64 value; }
+        contract main {
+            function f(uint64 a) public pure returns (uint64) {
+                return a + 1;
+            }
+        }"#,
+    )
+    .unwrap();
+
+    Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--emit", "ast-json"])
+        .arg(dir.join("main.sol"))
+        .arg("--output")
+        .arg(&dir)
+        .assert()
+        .success();
+
+    let ast: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(dir.join("main.ast.json")).unwrap()).unwrap();
+
+    let s = &ast["structs"][0];
+    assert_eq!(s["name"], "S");
+    assert_eq!(s["fields"][0]["type"], "struct S[]");
+
+    let contract = &ast["contracts"][0];
+    assert_eq!(contract["name"], "main");
+
+    let f = &ast["functions"][contract["functions"][0].as_u64().unwrap() as usize];
+    assert_eq!(f["name"], "f");
+    assert_eq!(f["params"][0]["type"], "uint64");
+
+    let ret = &f["body"][0];
+    assert_eq!(ret["kind"], "Return");
+    assert_eq!(ret["expr"]["kind"], "Add");
+    assert_eq!(ret["expr"]["types"][0], "uint64");
+    assert_eq!(ret["expr"]["children"][0]["kind"], "Variable");
+    assert_eq!(ret["expr"]["children"][1]["value"], 1);
+
+    assert!(!dir.join("main.wasm").exists());
+}
+
+#[test]
+fn emit_metadata() {
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/polkadot/flipper.sol",
+            "--target",
+            "polkadot",
+            "--emit",
+            "metadata",
+        ])
+        .assert()
+        .success();
+
+    let metadata: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+    let compiler = metadata["source"]["compiler"].as_str().unwrap();
+
+    assert!(compiler.contains(env!("CARGO_PKG_VERSION")));
+    assert_eq!(metadata["contract"]["name"], "flipper");
+}
+
+#[test]
+fn emit_cfg_json() {
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "examples/polkadot/flipper.sol",
+            "--target",
+            "polkadot",
+            "--emit",
+            "cfg-json",
+        ])
+        .assert()
+        .success();
+
+    // one document for the whole run, with an entry per contract
+    let contracts: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+    let contracts = contracts.as_array().unwrap();
+
+    assert_eq!(contracts.len(), 1);
+
+    let cfg = &contracts[0];
+
+    assert_eq!(cfg["contract"], "flipper");
+
+    let functions = cfg["functions"].as_array().unwrap();
+
+    let flip = functions
+        .iter()
+        .find(|f| f["name"].as_str().unwrap().ends_with("::flip"))
+        .unwrap();
+
+    assert_eq!(flip["public"], true);
+
+    let blocks = flip["blocks"].as_array().unwrap();
+
+    let instrs: Vec<&serde_json::Value> = blocks
+        .iter()
+        .flat_map(|block| block["instructions"].as_array().unwrap())
+        .collect();
+
+    let kinds: Vec<&str> = instrs
+        .iter()
+        .map(|instr| instr["kind"].as_str().unwrap())
+        .collect();
+
+    assert!(kinds.contains(&"LoadStorage"));
+    assert!(kinds.contains(&"SetStorage"));
+    assert_eq!(kinds.last(), Some(&"Return"));
+
+    for block in blocks {
+        for successor in block["successors"].as_array().unwrap() {
+            assert!((successor.as_u64().unwrap() as usize) < blocks.len());
+        }
+    }
+
+    // value = !value: the loaded value is negated and stored again
+    let load = instrs
+        .iter()
+        .find(|instr| instr["kind"] == "LoadStorage")
+        .unwrap();
+
+    assert_eq!(load["ty"], "bool");
+
+    let loaded = &load["res"];
+
+    assert!(flip["vars"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|var| var["var_no"] == *loaded && var["ty"] == "bool"));
+
+    let store = instrs
+        .iter()
+        .find(|instr| instr["kind"] == "SetStorage")
+        .unwrap();
+
+    assert_eq!(store["value"]["kind"], "Variable");
+
+    let set = instrs
+        .iter()
+        .find(|instr| instr["kind"] == "Set" && instr["res"] == store["value"]["var_no"])
+        .unwrap();
+
+    assert_eq!(set["expr"]["kind"], "Not");
+    assert_eq!(set["expr"]["expr"]["kind"], "Variable");
+    assert_eq!(set["expr"]["expr"]["var_no"], *loaded);
+}
+
+#[test]
+fn no_warn_tx_origin() {
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "--target",
+            "evm",
+            "--stop-after",
+            "resolve",
+            "tests/contract_testcases/evm/tx_origin.sol",
+        ])
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    assert!(stderr.contains("'tx.origin' used for authorization is vulnerable to phishing"));
+
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args([
+            "compile",
+            "--target",
+            "evm",
+            "--stop-after",
+            "resolve",
+            "--no-warn-tx-origin",
+            "tests/contract_testcases/evm/tx_origin.sol",
+        ])
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    assert!(!stderr.contains("tx.origin"));
+}
+
+#[test]
+fn stop_after() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let source = tmp.path().join("undeclared.sol");
+
+    fs::write(
+        &source,
+        r#"contract undeclared {
+            function a() public { x = 1; }
+        }"#,
+    )
+    .unwrap();
+
+    // the source parses, so the resolve error is not found
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--stop-after", "parse"])
+        .arg(&source)
+        .assert()
+        .success();
+
+    let stdout = String::from_utf8_lossy(&assert.get_output().stdout);
+
+    assert!(stdout.contains("SourceUnit"));
+    assert!(stdout.contains("undeclared"));
+
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--stop-after", "resolve"])
+        .arg(&source)
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    assert!(stderr.contains("'x' not found"));
+
+    fs::write(&source, "contract undeclared {").unwrap();
+
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--stop-after", "parse"])
+        .arg(&source)
+        .assert()
+        .failure();
+
+    // the error must point into the source file, not the builtin file of the target
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    assert!(stderr.contains("unexpected end of file"));
+    assert!(stderr.contains("undeclared.sol:1:22"));
+}
+
+#[test]
+fn standard_json_input() {
+    let tmp = TempDir::new_in("tests").unwrap();
+
+    let library = tmp.path().join("b.sol");
+
+    fs::write(
+        &library,
+        r#"library b {
+            function g() internal pure returns (int) {
+                return 1;
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let input = serde_json::json!({
+        "language": "Solidity",
+        "sources": {
+            "contracts/a.sol": {
+                "content": r#"import "@lib/b.sol";
+                contract a {
+                    function f() public pure returns (int) {
+                        return b.g();
+                    }
+                }"#
+            },
+            "lib/b.sol": {
+                "urls": [ library.canonicalize().unwrap() ]
+            }
+        },
+        "settings": {
+            "remappings": [ "@lib/=lib/" ],
+            "outputSelection": { "*": { "*": [ "abi" ] } }
+        }
+    });
+
+    let input_file = tmp.path().join("input.json");
+
+    fs::write(&input_file, input.to_string()).unwrap();
+
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--standard-json-input"])
+        .arg(&input_file)
+        .assert()
+        .success();
+
+    let output: serde_json::Value = serde_json::from_slice(&assert.get_output().stdout).unwrap();
+
+    assert!(output["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|error| error["severity"] != "error"));
+    assert!(output["contracts"]["contracts/a.sol"]["a"]["abi"].is_array());
+
+    // a source which cannot be read
+    fs::write(
+        &input_file,
+        r#"{ "sources": { "c.sol": { "urls": [ "https://example.com/c.sol" ] } } }"#,
+    )
+    .unwrap();
+
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--standard-json-input"])
+        .arg(&input_file)
+        .assert()
+        .failure();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    assert!(stderr.contains("cannot read source 'c.sol' from its urls"));
+}
+
+#[test]
+fn time_passes() {
+    let tmp = TempDir::new_in("tests").unwrap();
+    let dir = tmp.path().canonicalize().unwrap();
+
+    fs::write(
+        dir.join("main.sol"),
+        r#"
+        contract main {
+            function f(uint64 a) public pure returns (uint64) {
+                return a + 1;
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let assert = Command::cargo_bin("solang")
+        .unwrap()
+        .args(["compile", "--target", "polkadot", "--time-passes"])
+        .arg(dir.join("main.sol"))
+        .arg("--output")
+        .arg(&dir)
+        .assert()
+        .success();
+
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+
+    let passes: Vec<&str> = stderr
+        .lines()
+        .skip_while(|line| *line != "info: time spent per pass:")
+        .skip(1)
+        .map(|line| line.split("ms").nth(1).unwrap().trim())
+        .collect();
+
+    assert_eq!(
+        &passes[..4],
+        &["sema", "parse", "codegen", "reaching_definitions"]
+    );
+    assert!(passes.contains(&"constant_folding"));
+    assert!(passes.contains(&"emit"));
+    assert!(passes.contains(&"llvm"));
+    assert_eq!(passes.last(), Some(&"total"));
+}
@@ -0,0 +1,26 @@
+// RUN: --target polkadot --emit cfg
+
+contract c {
+    // BEGIN-CHECK: c::function::constant_call
+    function constant_call() public pure returns (bytes) {
+        // CHECK: return (alloc bytes uint32 7 hex"01020304feff01")
+        return abi.encodeCall(other.foo, (-2, true));
+    }
+
+    // BEGIN-CHECK: c::function::constant_selector
+    function constant_selector() public pure returns (bytes) {
+        // CHECK: return (alloc bytes uint32 5 hex"aabbccdd05")
+        return abi.encodeWithSelector(hex"aabbccdd", uint8(5));
+    }
+
+    // BEGIN-CHECK: c::function::variable_call
+    function variable_call(int16 a) public pure returns (bytes) {
+        // NOT-CHECK: return (alloc bytes
+        return abi.encodeCall(other.foo, (a, true));
+    }
+}
+
+contract other {
+    @selector([1, 2, 3, 4])
+    function foo(int16 a, bool b) public {}
+}
@@ -0,0 +1,15 @@
+// RUN: --target polkadot --emit cfg
+
+contract c {
+    // BEGIN-CHECK: c::function::constant_args
+    function constant_args() public pure returns (bytes) {
+        // CHECK: return (alloc bytes uint32 13 hex"0101feff010208616205000000")
+        return abi.encode(uint8(1), true, int16(-2), bytes2(0x0102), "ab", uint24(5));
+    }
+
+    // BEGIN-CHECK: c::function::variable_args
+    function variable_args(uint8 a) public pure returns (bytes) {
+        // NOT-CHECK: return (alloc bytes
+        return abi.encode(a, true);
+    }
+}
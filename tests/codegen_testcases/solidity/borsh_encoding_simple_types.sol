@@ -158,7 +158,7 @@ contract EncodingTest {
 	    // CHECK: ty:uint32 %temp.67 = ((builtin ArrayLength (%temp.66)) * uint32 8)
 	    // CHECK: ty:uint32 %temp.68 = uint32 16
 	    // CHECK: ty:uint32 %temp.69 = uint32 16
-	    // CHECK: ty:bytes %abi_encoded.temp.70 = (alloc bytes len (((%temp.67 + uint32 4) + uint32 16) + uint32 16))
+	    // CHECK: ty:bytes %abi_encoded.temp.70 = (alloc bytes len (%temp.67 + uint32 36))
 	    // CHECK: ty:uint32 %temp.71 = (builtin ArrayLength (%temp.66))
 	    // CHECK: writebuffer buffer:%abi_encoded.temp.70 offset:uint32 0 value:%temp.71
 	    // CHECK: memcpy src: %temp.66, dest: (advance ptr: %abi_encoded.temp.70, by: uint32 4), bytes_len: (%temp.71 * uint32 8)
@@ -0,0 +1,39 @@
+// RUN: --target polkadot --emit cfg
+
+contract c {
+    // BEGIN-CHECK: c::c::function::all_constant
+    function all_constant(uint32 i) public pure returns (uint8) {
+        uint8[3] x = [1, 2, 3];
+        // CHECK: ty:uint8[3] %x = constant [3] [ uint8 1, uint8 2, uint8 3 ]
+        return x[i];
+    }
+
+    // BEGIN-CHECK: c::c::function::two_dimensions
+    function two_dimensions(uint32 i, uint32 j) public pure returns (bool) {
+        bool[2][2] x = [[true, false], [false, true]];
+        // CHECK: ty:bool[2][2] %x = constant [2][2] [ true, false, false, true ]
+        return x[i][j];
+    }
+
+    // BEGIN-CHECK: c::c::function::mixed
+    function mixed(uint8 a, uint32 i) public pure returns (uint8) {
+        uint8[3] x = [1, a, 3];
+        // CHECK: ty:uint8[3] %x = [3] [ uint8 1, %a, uint8 3 ]
+        return x[i];
+    }
+
+    // BEGIN-CHECK: c::c::function::written
+    function written(uint32 i) public pure returns (uint8) {
+        uint8[3] x = [1, 2, 3];
+        // CHECK: ty:uint8[3] %x = [3] [ uint8 1, uint8 2, uint8 3 ]
+        x[i] = 5;
+        return x[0];
+    }
+
+    // BEGIN-CHECK: c::c::function::returned
+    function returned() public pure returns (uint8[3]) {
+        uint8[3] x = [1, 2, 3];
+        // CHECK: ty:uint8[3] %x = [3] [ uint8 1, uint8 2, uint8 3 ]
+        return x;
+    }
+}
@@ -0,0 +1,10 @@
+// RUN: --target polkadot --emit cfg --no-constant-folding
+// sema cannot see the overflow since x is a variable; it is found by the constant folding
+// pass, which still runs in codegen without modifying the cfg
+contract c {
+	function add() public pure returns (uint8) {
+		uint8 x = 200;
+		return x + 100;
+// FAIL: value 300 does not fit into type uint8 (valid range 0..=255).
+	}
+}
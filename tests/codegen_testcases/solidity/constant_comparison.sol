@@ -0,0 +1,44 @@
+// RUN: --target polkadot --emit cfg
+
+contract c {
+    // BEGIN-CHECK: c::c::function::signed_less
+    function signed_less() public pure returns (uint8) {
+        int8 a = -1;
+        // CHECK: branch block1
+        if (a < 1) {
+            return 1;
+        }
+        return 2;
+    }
+
+    // BEGIN-CHECK: c::c::function::unsigned_more
+    function unsigned_more() public pure returns (uint8) {
+        uint8 a = 255;
+        // CHECK: branch block1
+        if (a > 1) {
+            return 1;
+        }
+        return 2;
+    }
+
+    // BEGIN-CHECK: c::c::function::equal
+    function equal() public pure returns (uint8) {
+        uint64 a = 7;
+        // CHECK: branch block2
+        if (a == 8) {
+            return 1;
+        }
+        return 2;
+    }
+
+    // BEGIN-CHECK: c::c::function::not
+    function not() public pure returns (uint8) {
+        bool b = true;
+        // CHECK: branch block2
+        if (!b) {
+            return 1;
+        }
+        // CHECK-ABSENT: branchcond
+        return 2;
+    }
+}
@@ -0,0 +1,30 @@
+// RUN: --target polkadot --emit cfg
+
+contract c {
+    // BEGIN-CHECK: c::function::add
+    function add(uint64 x) public pure returns (uint64) {
+        // CHECK: return (%x + uint64 7)
+        return (x + 3) + 4;
+    }
+
+    // BEGIN-CHECK: c::function::sub
+    function sub(int64 x) public pure returns (int64) {
+        // CHECK: return (%x - int64 7)
+        return (x - 3) - 4;
+    }
+
+    // BEGIN-CHECK: c::function::mixed
+    function mixed(uint64 x) public pure returns (uint64) {
+        // with checked arithmetic, x + 3 may overflow even though x - 1 does not
+        // CHECK: return ((%x + uint64 3) - uint64 4)
+        return (x + 3) - 4;
+    }
+
+    // BEGIN-CHECK: c::function::mixed_unchecked
+    function mixed_unchecked(uint64 x) public pure returns (uint64) {
+        unchecked {
+            // CHECK: return (overflowing %x + uint64 18446744073709551615)
+            return (x + 3) - 4;
+        }
+    }
+}
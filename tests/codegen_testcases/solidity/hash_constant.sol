@@ -0,0 +1,27 @@
+// RUN: --target polkadot --emit cfg
+
+contract c {
+    // BEGIN-CHECK: c::function::keccak
+    function keccak() public pure returns (bytes32) {
+        // CHECK: return hex"22ae6da6b482f9b1b19b0b897c3fd43884180a1c5ee361e1107a1bc635649dda"
+        return keccak256(abi.encodePacked(uint8(1), bytes1(0x02)));
+    }
+
+    // BEGIN-CHECK: c::function::sha
+    function sha() public pure returns (bytes32) {
+        // CHECK: return hex"a12871fee210fb8619291eaea194581cbd2531e4b23759d225f6806923f63222"
+        return sha256(abi.encodePacked(uint8(1), bytes1(0x02)));
+    }
+
+    // BEGIN-CHECK: c::function::ripemd
+    function ripemd() public pure returns (bytes20) {
+        // CHECK: return hex"189f7c8b1a386ffe8eed91b3830c7a7bcd1e778c"
+        return ripemd160(abi.encodePacked(uint8(1), bytes1(0x02)));
+    }
+
+    // BEGIN-CHECK: c::function::variable_args
+    function variable_args(uint8 a) public pure returns (bytes32) {
+        // NOT-CHECK: return hex"
+        return keccak256(abi.encodePacked(a, bytes1(0x02)));
+    }
+}
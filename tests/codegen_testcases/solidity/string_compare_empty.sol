@@ -0,0 +1,21 @@
+// RUN: --target polkadot --emit cfg
+
+contract c {
+    // BEGIN-CHECK: c::c::function::is_empty__string
+    function is_empty(string s) public pure returns (bool) {
+        // CHECK: return ((builtin ArrayLength (
+        return s == "";
+    }
+
+    // BEGIN-CHECK: c::c::function::not_empty__bytes
+    function not_empty(bytes b) public pure returns (bool) {
+        // CHECK: return !((builtin ArrayLength (
+        return "" != b;
+    }
+
+    // BEGIN-CHECK: c::c::function::is_foo__string
+    function is_foo(string s) public pure returns (bool) {
+        // CHECK: return (strcmp (
+        return s == "foo";
+    }
+}
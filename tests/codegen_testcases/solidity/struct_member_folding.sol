@@ -0,0 +1,29 @@
+// RUN: --target polkadot --emit cfg
+
+contract c {
+    struct S {
+        uint32 a;
+        uint32 len;
+        bool b;
+    }
+
+    // BEGIN-CHECK: c::c::function::array_length
+    function array_length() public pure returns (uint64[]) {
+        uint64[] arr = new uint64[](S(1, 3, true).len);
+        // CHECK: ty:uint64[] %arr = (alloc uint64[] len uint32 3)
+        return arr;
+    }
+
+    // BEGIN-CHECK: c::c::function::propagate
+    function propagate() public pure returns (uint32) {
+        uint32 x = S(5, 7, false).len;
+        // CHECK: return uint32 14
+        return x * 2;
+    }
+
+    // BEGIN-CHECK: c::c::function::not_constant
+    function not_constant(uint32 a) public pure returns (uint32) {
+        // CHECK: return (load (struct struct { uint32 1, %a, false } field 1))
+        return S(1, a, false).len;
+    }
+}
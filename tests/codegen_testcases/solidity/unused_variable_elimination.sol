@@ -192,7 +192,7 @@ contract c3 {
     // BEGIN-CHECK: c3::function::test15
     function test15() public returns (int) {
         int[4] memory arr = [1, 2, 3, 4];
-        // CHECK: ty:int256[4] %arr = [4] [ int256 1, int256 2, int256 3, int256 4 ]
+        // CHECK: ty:int256[4] %arr = constant [4] [ int256 1, int256 2, int256 3, int256 4 ]
         return arr[2];
     }
 
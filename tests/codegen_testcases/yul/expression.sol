@@ -9,7 +9,7 @@ contract testing {
             // CHECK: ty:uint256 %x = uint256(true)
             let x := true
 
-            // CHECK: ty:bool %ss = (uint256 0 != uint256 5)
+            // CHECK: ty:bool %ss = true
             let ss : bool := 5
 
             // CHECK: ty:uint256 %y = uint256(false)
@@ -193,7 +193,7 @@ contract testing {
                 let i := 2
                 // CHECK: branch block13
                 // CHECK: block13: # cond
-                // CHECK: branchcond (uint256 2 == uint256 0), block15, block16
+                // CHECK: branch block16
             } eq(i, 0) {
                 // CHECK: block14: # next
                 // NOT-CHECK: ty:uint256 %i.29 =
@@ -213,7 +213,7 @@ contract testing {
                 let j := 2
                 // CHECK: branch block17
                 // CHECK: block17: # cond
-                // CHECK: branchcond (uint256 2 == uint256 3), block19, block20
+                // CHECK: branch block20
             } eq(j, 3) {
                 // CHECK: block18: # next
                 j := shr(j, 2)
@@ -3,7 +3,7 @@
 use path_slash::PathExt;
 use rayon::prelude::*;
 use solang::{
-    abi::generate_abi,
+    abi::{ethereum::AbiFormat, generate_abi},
     codegen,
     file_resolver::FileResolver,
     parse_and_resolve,
@@ -107,7 +107,17 @@ fn parse_file(path: PathBuf, target: Target) -> io::Result<()> {
                     }
                 };
 
-                let _ = generate_abi(contract_no, &ns, &code, false, &["unknown".into()], "0.1.0");
+                let _ = generate_abi(
+                    contract_no,
+                    &ns,
+                    &code,
+                    false,
+                    &["unknown".into()],
+                    "0.1.0",
+                    &cache,
+                    false,
+                    AbiFormat::default(),
+                );
             }
         }
     }
@@ -0,0 +1,16 @@
+contract c {
+    function ok(int16 a) public pure returns (bytes) {
+        return abi.encodeCall(other.foo, (a, true));
+    }
+
+    function mismatch() public pure returns (bytes) {
+        return abi.encodeCall(other.foo, (true, true));
+    }
+}
+
+contract other {
+    function foo(int16 a, bool b) public pure {}
+}
+
+// ---- Expect: diagnostics ----
+// error: 7:43-47: conversion from bool to int16 not possible
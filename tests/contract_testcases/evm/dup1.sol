@@ -41,7 +41,6 @@ contract D is C {
 // error: 19:3-11: cannot find overloaded function which matches signature
 // error: 19:3-11: function expects 2 arguments, 1 provided
 // 	note 2:2-37: candidate function
-// error: 19:3-11: function expects 2 arguments, 1 provided
 // 	note 3:2-38: candidate function
 // error: 23:9-10: 'x' not found
 // error: 23:12-13: 'y' not found
@@ -40,8 +40,6 @@ contract c is e {
 // ---- Expect: diagnostics ----
 // error: 14:11-13: error 'E1' has 1 unnamed fields
 // 	note 2:7-9: definition of 'E1'
-// error: 14:23-26: error 'E1' has no field called 'foo'
-// 	note 2:7-9: definition of 'E1'
 // error: 16:11-13: missing field 'foo'
 // 	note 3:7-9: definition of 'E2'
 // error: 18:34-37: error 'E3' has no field called 'baz'
@@ -0,0 +1,18 @@
+error E(int, bool);
+error F(int bar, bool foo);
+
+contract c {
+	function f() public {
+		revert E({bar: 1, foo: true});
+	}
+
+	function g() public {
+		revert F({bar: 1, foo: x, foo: false});
+	}
+}
+
+// ---- Expect: diagnostics ----
+// error: 6:10-11: error 'E' has 2 unnamed fields
+// 	note 1:7-8: definition of 'E'
+// error: 10:26-27: 'x' not found
+// error: 10:29-32: duplicate argument with name 'foo'
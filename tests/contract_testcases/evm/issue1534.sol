@@ -20,3 +20,4 @@ contract Overloaded {
 }
 
 // ---- Expect: diagnostics ----
+// warning: 4:1-20:2: contract 'Overloaded' has no public or external functions, so none of its code can be called
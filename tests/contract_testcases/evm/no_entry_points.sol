@@ -0,0 +1,42 @@
+library L {
+    function f() internal pure returns (uint) {
+        return 1;
+    }
+}
+
+interface I {
+    function g() external;
+}
+
+contract Base {
+    function h() internal pure returns (uint) {
+        return 2;
+    }
+}
+
+contract Derived is Base {
+    function i() public pure returns (uint) {
+        return h() + L.f();
+    }
+}
+
+contract Dead {
+    function j() internal pure returns (uint) {
+        return 3;
+    }
+}
+
+contract WithConstructor {
+    event E(uint);
+
+    constructor() {
+        emit E(k());
+    }
+
+    function k() private pure returns (uint) {
+        return 4;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 23:1-27:2: contract 'Dead' has no public or external functions, so none of its code can be called
@@ -46,20 +46,16 @@ contract B is C {
 // 	note 19:2-25: previous definition of function 'test1'
 // error: 6:3-23: cannot find overloaded function which matches signature
 // 	note 2:2-29: candidate function
-// error: 6:3-23: cannot find overloaded function which matches signature
 // 	note 3:2-39: candidate function
-// error: 6:3-23: cannot find overloaded function which matches signature
 // 	note 4:2-39: candidate function
 // error: 6:3-23: function cannot be called with named arguments as 1 of its parameters do not have names
 // 	note 2:2-26: definition of foo
 // 	note 2:2-29: candidate function
 // error: 6:11-15: conversion from bool to int256 not possible
 // 	note 3:2-39: candidate function
-// error: 6:11-15: conversion from bool to int256 not possible
 // 	note 4:2-39: candidate function
 // error: 6:20-21: expected 'bool', found integer
 // 	note 3:2-39: candidate function
-// error: 6:20-21: expected 'bool', found integer
 // 	note 4:2-39: candidate function
 // error: 9:2-25: function 'test2' with this signature already defined
 // 	note 23:2-25: previous definition of function 'test2'
@@ -70,40 +66,32 @@ contract B is C {
 // error: 14:19-22: 'meh' not found
 // error: 20:3-25: cannot find overloaded function which matches signature
 // 	note 2:2-29: candidate function
-// error: 20:3-25: cannot find overloaded function which matches signature
 // 	note 3:2-39: candidate function
-// error: 20:3-25: cannot find overloaded function which matches signature
 // 	note 4:2-39: candidate function
 // error: 20:3-25: function cannot be called with named arguments as 1 of its parameters do not have names
 // 	note 2:2-26: definition of foo
 // 	note 2:2-29: candidate function
 // error: 20:13-17: conversion from bool to int256 not possible
 // 	note 3:2-39: candidate function
-// error: 20:13-17: conversion from bool to int256 not possible
 // 	note 4:2-39: candidate function
 // error: 20:22-23: expected 'bool', found integer
 // 	note 3:2-39: candidate function
-// error: 20:22-23: expected 'bool', found integer
 // 	note 4:2-39: candidate function
 // error: 24:3-25: function call can be resolved to multiple functions
 // 	note 3:2-39: candidate function
 // 	note 4:2-39: candidate function
 // error: 28:3-25: cannot find overloaded function which matches signature
 // 	note 2:2-29: candidate function
-// error: 28:3-25: cannot find overloaded function which matches signature
 // 	note 3:2-39: candidate function
-// error: 28:3-25: cannot find overloaded function which matches signature
 // 	note 4:2-39: candidate function
 // error: 28:3-25: function cannot be called with named arguments as 1 of its parameters do not have names
 // 	note 2:2-26: definition of foo
 // 	note 2:2-29: candidate function
 // error: 28:13-17: conversion from bool to int256 not possible
 // 	note 3:2-39: candidate function
-// error: 28:13-17: conversion from bool to int256 not possible
 // 	note 4:2-39: candidate function
 // error: 28:22-23: expected 'bool', found integer
 // 	note 3:2-39: candidate function
-// error: 28:22-23: expected 'bool', found integer
 // 	note 4:2-39: candidate function
 // error: 32:3-25: function call can be resolved to multiple functions
 // 	note 3:2-39: candidate function
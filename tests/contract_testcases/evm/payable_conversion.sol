@@ -0,0 +1,35 @@
+contract c {
+    function f(uint160 a) public pure returns (address payable) {
+        return payable(a);
+    }
+
+    function g(bytes20 b) public pure returns (address payable) {
+        return payable(b);
+    }
+
+    function h(address a) public pure returns (address payable, address payable) {
+        return (payable(a), payable(0));
+    }
+
+    function i(uint256 a) public pure returns (address payable) {
+        return payable(a);
+    }
+
+    function j(d a, e b) public pure returns (address payable, address payable) {
+        return (payable(a), payable(b));
+    }
+}
+
+contract d {
+    receive() external payable {}
+}
+
+contract e {
+    function foo() public {}
+}
+
+// ---- Expect: diagnostics ----
+// error: 7:16-26: payable conversion is only possible from address or contract types, not 'bytes20'
+// error: 15:16-26: payable conversion is only possible from address or contract types, not 'uint256'
+// error: 19:29-39: payable conversion of contract 'e' not possible, as it has no receive function or payable fallback function
+// 	note 27:1-29:2: definition of 'e'
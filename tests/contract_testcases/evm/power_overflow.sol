@@ -0,0 +1,16 @@
+contract c {
+    function f() public pure returns (uint8) {
+        uint8 x = 2 ** 10;
+        return x;
+    }
+
+    function g() public pure returns (uint8) {
+        unchecked {
+            uint8 x = 2 ** 10;
+            return x;
+        }
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:19-26: value 1024 does not fit into type uint8 (valid range 0..=255).
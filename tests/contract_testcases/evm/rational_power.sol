@@ -0,0 +1,17 @@
+contract c {
+    function f() public pure returns (uint) {
+        return 2.5 ** 2 * 4;
+    }
+
+    function g() public pure returns (uint) {
+        return 1.5 << 1;
+    }
+
+    function h() public pure returns (uint) {
+        return 2 ** 0.5;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 7:16-24: cannot shift a rational
+// error: 11:16-24: exponent of rational power must be an integer
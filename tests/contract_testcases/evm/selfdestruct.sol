@@ -0,0 +1,8 @@
+contract c {
+    function goaway(address payable recipient) public {
+        selfdestruct(recipient);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 3:9-21: 'selfdestruct' has been deprecated. Since EIP-6780, the code and storage of the contract are only removed if it was created in the same transaction
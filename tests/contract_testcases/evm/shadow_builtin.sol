@@ -0,0 +1,15 @@
+contract c {
+	function require(bool b) internal pure returns (bool) {
+		return b;
+	}
+
+	function f(uint msg) public pure returns (uint) {
+		uint keccak256 = 1;
+		return keccak256 + msg;
+	}
+}
+
+// ---- Expect: diagnostics ----
+// warning: 2:11-18: 'require' shadows name of a builtin
+// warning: 6:18-21: 'msg' shadows name of a builtin
+// warning: 7:8-17: 'keccak256' shadows name of a builtin
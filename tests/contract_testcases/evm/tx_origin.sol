@@ -0,0 +1,18 @@
+contract Wallet {
+    address owner;
+
+    constructor() {
+        owner = msg.sender;
+    }
+
+    function withdraw() public view {
+        require(tx.origin == owner, "not owner");
+    }
+
+    function isEOA() public view returns (bool) {
+        return tx.origin == msg.sender;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 9:17-26: 'tx.origin' used for authorization is vulnerable to phishing, use 'msg.sender' instead
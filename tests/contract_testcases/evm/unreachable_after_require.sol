@@ -0,0 +1,21 @@
+contract c {
+    function f(uint a) public pure returns (uint) {
+        require(false, "no");
+        return a;
+    }
+
+    function g(uint a) public pure returns (uint) {
+        assert(1 > 2);
+        a += 1;
+        return a;
+    }
+
+    function h(uint a) public pure returns (uint) {
+        require(a > 1);
+        return a;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 4:9-17: unreachable statement
+// warning: 9:9-15: unreachable statement
@@ -0,0 +1,26 @@
+type Foo is uint256;
+
+contract c {
+    Foo foo;
+
+    function f(uint256 a) public {
+        foo = a;
+    }
+
+    function g(uint256 a) public {
+        h(a);
+    }
+
+    function h(Foo a) public {
+        foo = a;
+    }
+
+    function i(uint256 a) public returns (uint256) {
+        foo = Foo.wrap(a);
+        return Foo.unwrap(foo);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 7:15-16: conversion from uint256 to usertype Foo not possible
+// error: 11:11-12: conversion from uint256 to usertype Foo not possible
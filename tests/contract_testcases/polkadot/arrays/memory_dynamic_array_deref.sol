@@ -8,3 +8,4 @@
         }
 // ---- Expect: diagnostics ----
 // error: 6:19-21: negative value -1 does not fit into type uint32. Cannot implicitly convert signed literal to unsigned type.
+// 	note 6:19-21: to convert to uint32, use an explicit conversion 'uint32(int32(-1))' which gives 4294967295, or write 4294967295 directly
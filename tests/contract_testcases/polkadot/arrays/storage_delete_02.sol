@@ -0,0 +1,20 @@
+contract foo {
+    uint[3] bar;
+    int64[2][4] baz;
+
+    function set() public {
+        bar = [1, 2, 3];
+        baz[3][1] = 102;
+    }
+
+    function reset() public {
+        delete bar;
+        delete baz[1];
+    }
+
+    function get() public view returns (uint[3], int64[2][4]) {
+        return (bar, baz);
+    }
+}
+
+// ---- Expect: diagnostics ----
@@ -0,0 +1,21 @@
+contract A {
+    function a(B b) public payable returns (uint) {
+        try b.b(0) returns (uint x) {
+            return x;
+        } catch Error(string reason) {
+            revert(reason);
+        } catch Panic(uint code) {
+            return code;
+        } catch (bytes raw) {
+            return raw.length;
+        }
+    }
+}
+
+contract B {
+    function b(uint div) public pure returns (uint) {
+        return 123 / div;
+    }
+}
+
+// ---- Expect: diagnostics ----
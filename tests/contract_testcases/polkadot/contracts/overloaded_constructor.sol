@@ -35,12 +35,10 @@ contract T {
 // 	note 2:2-21: candidate constructor
 // error: 11:3-10: constructor expects 2 arguments, 0 provided
 // 	note 3:2-31: candidate constructor
-// error: 11:3-10: constructor expects 2 arguments, 0 provided
 // 	note 4:2-31: candidate constructor
 // error: 15:3-14: cannot find overloaded constructor which matches signature
 // error: 15:3-14: constructor expects 2 arguments, 1 provided
 // 	note 3:2-31: candidate constructor
-// error: 15:3-14: constructor expects 2 arguments, 1 provided
 // 	note 4:2-31: candidate constructor
 // error: 15:9-13: conversion from bool to int256 not possible
 // 	note 2:2-21: candidate constructor
@@ -51,7 +49,6 @@ contract T {
 // error: 19:3-12: constructor expects 2 arguments, 0 provided
 // 	note 3:2-29: definition of constructor
 // 	note 3:2-31: candidate constructor
-// error: 19:3-12: constructor expects 2 arguments, 0 provided
 // 	note 4:2-29: definition of constructor
 // 	note 4:2-31: candidate constructor
 // error: 23:3-25: can be resolved to multiple constructors
@@ -46,7 +46,6 @@ contract R {
 // error: 18:3-16: missing argument 'a' to constructor
 // 	note 2:2-23: definition of constructor
 // 	note 2:2-25: candidate constructor
-// error: 18:3-16: missing argument 'a' to constructor
 // 	note 3:2-23: definition of constructor
 // 	note 3:2-25: candidate constructor
 // error: 28:3-16: cannot find matching constructor
@@ -0,0 +1,21 @@
+contract c {
+    function get() internal pure returns (int) {
+        return 1;
+    }
+
+    function set() internal pure {}
+
+    function pub() public pure returns (int) {
+        return 2;
+    }
+
+    function test() public pure returns (int) {
+        get();
+        set();
+        pub();
+        return get();
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 13:9-14: return value of function 'get' is discarded
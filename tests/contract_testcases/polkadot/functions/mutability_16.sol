@@ -0,0 +1,21 @@
+contract c {
+    function get() public returns (uint128) {
+        return msg.value;
+    }
+
+    function get_view() public view returns (uint128) {
+        return msg.value;
+    }
+
+    function get_payable() public payable returns (uint128) {
+        return msg.value;
+    }
+
+    function get_internal() internal view returns (uint128) {
+        return msg.value;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:16-25: function declared 'nonpayable' but this expression accesses value sent, which is only allowed for payable functions
+// error: 7:16-25: function declared 'view' but this expression accesses value sent, which is only allowed for payable functions
@@ -0,0 +1,17 @@
+abstract contract Shape {
+    function area() public virtual returns (uint);
+
+    function name() public pure returns (string memory) {
+        return "shape";
+    }
+}
+
+contract Factory {
+    function make() public returns (Shape) {
+        return new Shape();
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 11:16-27: cannot construct 'Shape' of type 'abstract contract'
+// 	note 2:5-50: function 'area' has no body
@@ -31,3 +31,4 @@ contract C {
 // error: 13:21-24: 'feh' not expected, did you mean 'global'?
 // error: 14:9-13: function cannot be used since first argument is 'int256' rather than the required 'uint256'
 // 	note 4:10-14: definition of 'foo3'
+// warning: 20:3-12: return value of function 'foo4' is discarded
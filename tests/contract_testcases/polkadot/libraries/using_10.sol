@@ -0,0 +1,26 @@
+library A {
+    function inc(uint256 x) internal pure returns (uint256) { return x + 1; }
+}
+
+library B {
+    function inc(uint256 x) internal pure returns (uint256) { return x + 2; }
+    function dec(uint256 x) internal pure returns (uint256) { return x - 1; }
+}
+
+contract c {
+    using A for *;
+    using B for *;
+
+    function f(uint256 x) public pure returns (uint256) {
+        return x.inc();
+    }
+
+    function g(uint256 x) public pure returns (uint256) {
+        return x.dec();
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 15:16-23: function call can be resolved to multiple functions
+// 	note 2:5-78: candidate function
+// 	note 6:5-78: candidate function
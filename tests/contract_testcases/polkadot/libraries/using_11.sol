@@ -0,0 +1,26 @@
+struct S {
+    int64 f1;
+}
+
+library L {
+    function get(S memory s) internal pure returns (int64) {
+        return s.f1;
+    }
+}
+
+using L for S global;
+
+contract a {
+    function f(S memory s) public pure returns (int64) {
+        return s.get();
+    }
+}
+
+contract b {
+    function g() public pure returns (int64) {
+        S memory s = S(1);
+        return s.get();
+    }
+}
+
+// ---- Expect: diagnostics ----
@@ -3,5 +3,6 @@
             modifier foo() public {}
         }
 // ---- Expect: diagnostics ----
+// error: 3:13-34: missing '_' in modifier
+// 	note 3:35: '_' should be placed in the body of the modifier
 // error: 3:28-34: 'public': modifiers can not have visibility
-// error: 3:37: missing '_' in modifier
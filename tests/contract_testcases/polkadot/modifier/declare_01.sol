@@ -3,5 +3,6 @@
             modifier foo() internal {}
         }
 // ---- Expect: diagnostics ----
+// error: 3:13-36: missing '_' in modifier
+// 	note 3:37: '_' should be placed in the body of the modifier
 // error: 3:28-36: 'internal': modifiers can not have visibility
-// error: 3:39: missing '_' in modifier
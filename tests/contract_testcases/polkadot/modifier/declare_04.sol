@@ -3,5 +3,6 @@
             modifier foo bar {}
         }
 // ---- Expect: diagnostics ----
+// error: 3:13-29: missing '_' in modifier
+// 	note 3:30: '_' should be placed in the body of the modifier
 // error: 3:26-29: function modifiers or base contracts are not allowed on modifier
-// error: 3:32: missing '_' in modifier
@@ -3,4 +3,5 @@
             modifier foo() {}
         }
 // ---- Expect: diagnostics ----
-// error: 3:30: missing '_' in modifier
+// error: 3:13-27: missing '_' in modifier
+// 	note 3:28: '_' should be placed in the body of the modifier
@@ -0,0 +1,35 @@
+
+        abstract contract c {
+            modifier foo() {
+                _;
+                _;
+            }
+
+            modifier branches(bool b) {
+                if (b) {
+                    _;
+                } else {
+                    _;
+                }
+            }
+
+            modifier early_return(bool b) {
+                if (b) {
+                    _;
+                    return;
+                }
+                _;
+            }
+
+            modifier after_branch(bool b) {
+                if (b) {
+                    _;
+                }
+                _;
+            }
+        }
+// ---- Expect: diagnostics ----
+// warning: 5:17-18: '_' occurs more than once in modifier, so the body of the modified function may run more than once
+// 	note 4:17-18: first '_' in modifier
+// warning: 28:17-18: '_' occurs more than once in modifier, so the body of the modified function may run more than once
+// 	note 26:21-22: first '_' in modifier
@@ -2,4 +2,4 @@ abstract contract test {
             int64 foo = 1844674_4073709551616;
         }
 // ---- Expect: diagnostics ----
-// error: 2:25-46: value 18446744073709551616 does not fit into type int64.
+// error: 2:25-46: value 18446744073709551616 does not fit into type int64 (valid range -9223372036854775808..=9223372036854775807).
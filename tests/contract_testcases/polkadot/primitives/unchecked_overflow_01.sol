@@ -0,0 +1,9 @@
+contract c {
+    function checked() public pure returns (int8) {
+        int8 x = 127 + 6;
+        return x;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:18-25: value 133 does not fit into type int8 (valid range -128..=127).
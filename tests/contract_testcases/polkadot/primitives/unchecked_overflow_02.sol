@@ -0,0 +1,17 @@
+contract c {
+    function wrapped() public pure returns (int8) {
+        unchecked {
+            int8 x = 127 + 6;
+            uint8 y = 3 - 4;
+            return x + int8(y);
+        }
+    }
+
+    function divided() public pure returns (int8) {
+        unchecked {
+            return type(int8).min / -1;
+        }
+    }
+}
+
+// ---- Expect: diagnostics ----
@@ -0,0 +1,10 @@
+contract c {
+    function f(uint64 y) public pure returns (uint64 x) {
+        unchecked {
+            x = y / 0 + 1;
+        }
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 4:17-22: divide by zero
@@ -5,4 +5,4 @@
             }
         }
 // ---- Expect: diagnostics ----
-// error: 4:33-37: \x escape should be followed by two hex digits
+// error: 4:33-36: \x escape should be followed by two hex digits
@@ -5,4 +5,4 @@
             }
         }
 // ---- Expect: diagnostics ----
-// error: 4:33-37: \u escape should be followed by four hex digits
+// error: 4:33-36: \u escape should be followed by four hex digits
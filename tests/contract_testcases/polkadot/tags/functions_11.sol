@@ -0,0 +1,16 @@
+contract c {
+    /// @param a the first
+    /// @param d
+    function foo(int a, int b) public pure returns (int) {
+        return a + b;
+    }
+
+    /// @notice parameters do not have to be documented
+    function bar(int a) public pure returns (int) {
+        return a;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 2:9-3:17: function parameter 'b' has no tag '@param'
+// error: 3:16-17: function parameter named 'd' not found
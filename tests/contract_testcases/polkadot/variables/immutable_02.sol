@@ -7,4 +7,4 @@ contract x {
         }
         
 // ---- Expect: diagnostics ----
-// error: 5:17-18: cannot assign to immutable outside of constructor
+// error: 5:17-18: cannot assign to immutable 'y' outside of constructor
@@ -0,0 +1,43 @@
+contract x {
+    int public immutable y;
+    int public immutable z;
+    int public immutable w = 1;
+    int public v;
+
+    constructor(bool c) {
+        v = y;
+        y = 2;
+        v = y + w;
+
+        if (c) {
+            z = 1;
+        } else {
+            v = 3;
+        }
+
+        v = z;
+        z = z + 1;
+    }
+}
+
+contract b {
+    int public immutable y;
+
+    constructor(bool c) {
+        if (c) {
+            y = 1;
+        } else {
+            revert();
+        }
+
+        int a = y;
+        (y, a) = (y + a, 1);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 8:13-14: immutable 'y' is read before it is assigned
+// error: 18:13-14: immutable 'z' is read before it is assigned
+// error: 19:9-10: immutable 'z' may already be assigned
+// error: 19:13-14: immutable 'z' is read before it is assigned
+// error: 34:10-11: immutable 'y' is already assigned
@@ -0,0 +1,38 @@
+contract x {
+    int public immutable y = 1;
+    int[] public immutable z;
+
+    constructor() {
+        y += 1;
+        z.push(1);
+        z[0] = 2;
+    }
+
+    function add() public {
+        y += 1;
+    }
+
+    function shift() public {
+        y <<= 2;
+    }
+
+    function element() public {
+        z[0] = 2;
+    }
+
+    function element_add() public {
+        z[0] *= 2;
+    }
+
+    function destructure() public {
+        (y, ) = (1, 2);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 6:9-10: immutable 'y' is already assigned
+// error: 12:9-10: cannot assign to immutable 'y' outside of constructor
+// error: 16:9-10: cannot assign to immutable 'y' outside of constructor
+// error: 20:9-13: cannot assign to immutable 'z' outside of constructor
+// error: 24:9-13: cannot assign to immutable 'z' outside of constructor
+// error: 28:10-11: cannot assign to immutable 'y' outside of constructor
@@ -0,0 +1,66 @@
+contract both {
+    int public immutable x;
+
+    constructor(bool c) {
+        if (c) {
+            x = 1;
+        } else {
+            x = 2;
+        }
+    }
+}
+
+contract one {
+    int public immutable x;
+
+    constructor(bool c) {
+        if (c) {
+            x = 1;
+        }
+    }
+}
+
+contract twice {
+    int public immutable x;
+
+    constructor() {
+        x = 1;
+        x = 2;
+    }
+}
+
+contract early_return {
+    int public immutable x;
+
+    constructor(bool c) {
+        if (c) {
+            return;
+        }
+        x = 1;
+    }
+}
+
+contract looped {
+    int public immutable x;
+
+    constructor(int n) {
+        for (int i = 0; i < n; i++) {
+            x = i;
+        }
+    }
+}
+
+contract initialized {
+    int public immutable w = 1;
+
+    constructor() {
+        w = 2;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 16:5-24: immutable 'x' may be unassigned when the constructor returns
+// error: 28:9-10: immutable 'x' is already assigned
+// error: 37:13-19: immutable 'x' may be unassigned when the constructor returns
+// error: 48:13-14: immutable 'x' cannot be assigned in a loop
+// error: 57:9-10: immutable 'w' is already assigned
@@ -46,3 +46,4 @@ contract hatchling {
 // 	note 12:9-32: other call
 // error: 18:9-31: contract 'hatchling' is called more than once in this function, so automatic account collection cannot happen. Please, provide the necessary accounts using the {accounts:..} call argument
 // 	note 17:9-27: other call
+// warning: 27:28-35: 'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
@@ -46,4 +46,6 @@ contract MyContract {
 
 
 // ---- Expect: diagnostics ----
+// warning: 18:16-23: 'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
+// warning: 34:13-35: 'payable' has no effect on Solana, as any account can receive lamports
 // error: 34:13-57: method 'transfer' not available on Solana. Use the lamports field from the AccountInfo struct directly to operate on balances.
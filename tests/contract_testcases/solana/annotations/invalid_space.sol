@@ -12,4 +12,4 @@ contract Test2 {
 
 // ---- Expect: diagnostics ----
 // error: 6:12-17: Solana's runtime does not permit accounts larger than 10 MB
-// error: codegen: value 92233720368547758208 does not fit into type uint64.
\ No newline at end of file
+// error: codegen: value 92233720368547758208 does not fit into type uint64 (valid range 0..=18446744073709551615).
\ No newline at end of file
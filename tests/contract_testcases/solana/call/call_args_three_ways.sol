@@ -25,3 +25,5 @@ contract D {
 // error: 10:10-18: Solana Cross Program Invocation (CPI) cannot transfer native value. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
 // error: 11:12-20: Solana Cross Program Invocation (CPI) cannot transfer native value. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
 // error: 12:11-19: Solana Cross Program Invocation (CPI) cannot transfer native value. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
+// warning: 18:16-23: 'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
+// warning: 19:18-25: 'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
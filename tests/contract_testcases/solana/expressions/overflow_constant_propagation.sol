@@ -67,10 +67,11 @@ contract foo {
     }
 }
 // ---- Expect: diagnostics ----
-// error: 6:20-25: value 4294967296 does not fit into type uint32.
-// error: 11:21-28: value 65792 does not fit into type uint16.
-// error: 16:21-29: value 100000 does not fit into type uint16.
+// error: 6:20-25: value 4294967296 does not fit into type uint32 (valid range 0..=4294967295).
+// error: 11:21-28: value 65792 does not fit into type uint16 (valid range 0..=65535).
+// error: 16:21-29: value 100000 does not fit into type uint16 (valid range 0..=65535).
 // error: 21:21-28: negative value -1 does not fit into type uint16. Cannot implicitly convert signed literal to unsigned type.
-// error: 25:20-23: value 32768 does not fit into type int16.
+// 	note 21:21-28: to convert to uint16, use an explicit conversion 'uint16(int16(-1))' which gives 65535, or write 65535 directly
+// error: 25:20-23: value 32768 does not fit into type int16 (valid range -32768..=32767).
 // error: 60:26-32: power 2147483648 not possible
 // error: 65:26-32: value is too large to fit into type uint256
\ No newline at end of file
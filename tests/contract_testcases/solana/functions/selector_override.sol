@@ -33,5 +33,5 @@ contract TooLarge {
 // error: 8:2-20: overriding selector not permitted on fallback
 // error: 10:2-25: overriding selector only permitted on 'public' or 'external' function, not 'internal'
 // error: 12:2-25: overriding selector only permitted on 'public' or 'external' function, not 'private'
-// error: 17:25-28: value 256 does not fit into type uint8.
-// error: 22:34-39: value 2744 does not fit into type uint8.
+// error: 17:25-28: value 256 does not fit into type uint8 (valid range 0..=255).
+// error: 22:34-39: value 2744 does not fit into type uint8 (valid range 0..=255).
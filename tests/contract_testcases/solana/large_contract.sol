@@ -896,6 +896,8 @@ contract ServiceRegistrySolana {
 
 // ---- Expect: diagnostics ----
 // warning: 204:5-209:14: function can be declared 'pure'
+// warning: 349:62-69: 'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
+// warning: 385:16-23: 'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
 // warning: 500:22-26: function parameter 'data' is unused
 // warning: 525:26-40: local variable 'agentInstances' is unused
 // warning: 867:31-35: function parameter 'data' is unused
\ No newline at end of file
@@ -16,7 +16,7 @@ contract C {
 }
 
 // ---- Expect: diagnostics ----
-// error: 2:26-31: value 1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000 does not fit into type int256.
+// error: 2:26-31: value 1000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000 does not fit into type int256 (valid range -57896044618658097711785492504343953926634992332820282019728792003956564819968..=57896044618658097711785492504343953926634992332820282019728792003956564819967).
 // error: 3:26-34: exponent '-65535' too large
 // error: 4:26-33: exponent '65537' too large
 // error: 7:12-19: exponent '65537' too large
@@ -12,3 +12,4 @@ contract c {
 }
 
 // ---- Expect: diagnostics ----
+// warning: 1:1-12:2: contract 'c' has no public or external functions, so none of its code can be called
@@ -0,0 +1,13 @@
+contract c {
+    function f(address a) public pure returns (address payable) {
+        return payable(a);
+    }
+
+    function g(bytes32 b) public pure returns (address payable) {
+        return payable(b);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 3:16-26: 'payable' has no effect on Solana, as any account can receive lamports
+// error: 7:16-26: payable conversion is only possible from address or contract types, not 'bytes32'
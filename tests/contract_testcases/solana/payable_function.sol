@@ -0,0 +1,12 @@
+contract c {
+    constructor() payable {}
+
+    function deposit() public payable returns (uint64) {
+        return msg.value;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// warning: 2:19-26: 'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
+// warning: 4:31-38: 'payable' has no effect on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
+// error: 5:16-25: 'msg.value' is not available on Solana, as native value cannot be sent with a call. See https://solang.readthedocs.io/en/latest/language/functions.html#value_transfer
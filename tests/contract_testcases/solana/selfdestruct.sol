@@ -0,0 +1,8 @@
+contract c {
+    function goaway(address payable recipient) public {
+        selfdestruct(recipient);
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:9-21: 'selfdestruct' is not available on Solana, as programs cannot be deleted by themselves. Use the program close instruction of the loader instead
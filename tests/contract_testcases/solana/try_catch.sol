@@ -0,0 +1,18 @@
+contract Caller {
+    function test(Other o) external returns (int64) {
+        try o.foo() returns (int64 x) {
+            return x;
+        } catch (bytes) {
+            return -1;
+        }
+    }
+}
+
+contract Other {
+    function foo() external pure returns (int64) {
+        return 1;
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:9-7:10: The try-catch statement is not supported on Solana, since a failing external call or contract creation aborts the entire transaction and the catch clause could never run. Please, go to https://solang.readthedocs.io/en/latest/language/statements.html#try-catch-statement for more information
@@ -16,3 +16,4 @@ contract c {
 }
 
 // ---- Expect: diagnostics ----
+// warning: 13:3-13: return value of function 'member' is discarded
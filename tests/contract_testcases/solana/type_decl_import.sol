@@ -10,5 +10,6 @@ contract d {
 }
 
 // ---- Expect: diagnostics ----
+// warning: 5:30-43: 'payable' has no effect on Solana, as any account can receive lamports
 // error: 8:3-8: accounts are required for calling a contract. You can either provide the accounts with the {accounts: ...} call argument or change this function's visibility to external
 // warning: 7:2-33: function can be declared 'pure'
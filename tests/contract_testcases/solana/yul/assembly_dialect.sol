@@ -0,0 +1,11 @@
+contract c {
+    function f() public pure returns (uint x) {
+        assembly "ewasm" {
+            x := 1
+        }
+    }
+}
+
+// ---- Expect: diagnostics ----
+// error: 3:18-25: only evmasm dialect is supported
+// 	note 3:9-5:10: inline assembly is compiled for target Solana rather than the EVM; only Yul statements and these builtins can be used: add, sub, mul, div, sdiv, mod, smod, exp, not, lt, gt, slt, sgt, eq, iszero, and, or, xor, byte, shl, shr, sar, addmod, mulmod, address, selfdestruct, invalid, origin, timestamp, number
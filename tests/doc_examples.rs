@@ -54,6 +54,7 @@ fn try_compile(path: &str, target: Target) -> Result<(), Diagnostics> {
             strength_reduce: true,
             vector_to_slice: true,
             common_subexpression_elimination: true,
+            unused_variable_elimination: true,
             opt_level: OptimizationLevel::Default,
             ..Default::default()
         },
@@ -78,6 +78,32 @@ fn abi_encode() {
     runtime.heap_verify();
 }
 
+#[test]
+fn abi_encode_constant() {
+    let mut runtime = build_solidity(
+        r#"
+        contract bar {
+            function test() public {
+                // all arguments are constant, so this is encoded at compile time
+                bytes c = abi.encode(uint8(1), true, int16(-2), bytes2(0x0102), "ab", uint24(5));
+
+                uint8 a = 1;
+                bool b = true;
+                int16 d = -2;
+                bytes2 e = 0x0102;
+                string f = "ab";
+                uint24 g = 5;
+
+                assert(c == abi.encode(a, b, d, e, f, g));
+                assert(c == hex"0101feff010208616205000000");
+            }
+        }"#,
+    );
+
+    runtime.function("test", Vec::new());
+    runtime.heap_verify();
+}
+
 #[test]
 fn abi_encode_packed() {
     let mut runtime = build_solidity(
@@ -120,6 +146,29 @@ fn abi_encode_packed() {
     runtime.function("test3", Vec::new());
 }
 
+#[test]
+fn hash_constant_packed() {
+    let mut runtime = build_solidity(
+        r#"
+        contract bar {
+            function test(uint8 a, bytes1 b) public {
+                // all arguments are constant, so these are hashed at compile time
+                bytes32 k = keccak256(abi.encodePacked(uint8(1), bytes1(0x02)));
+                bytes32 s = sha256(abi.encodePacked(uint8(1), bytes1(0x02)));
+                bytes20 r = ripemd160(abi.encodePacked(uint8(1), bytes1(0x02)));
+
+                assert(k == keccak256(abi.encodePacked(a, b)));
+                assert(s == sha256(abi.encodePacked(a, b)));
+                assert(r == ripemd160(abi.encodePacked(a, b)));
+
+                assert(k == hex"22ae6da6b482f9b1b19b0b897c3fd43884180a1c5ee361e1107a1bc635649dda");
+            }
+        }"#,
+    );
+
+    runtime.function("test", (1u8, [2u8; 1]).encode());
+}
+
 #[test]
 fn abi_encode_with_selector() {
     let mut runtime = build_solidity(
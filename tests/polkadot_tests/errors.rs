@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::build_solidity;
+use crate::{build_solidity, build_solidity_with_options};
 use parity_scale_codec::{Decode, Encode};
 use primitive_types::U256;
 use solang::{
@@ -102,6 +102,36 @@ fn require() {
     );
 }
 
+#[test]
+fn runtime_errors_release() {
+    // in release mode, the revert data is returned but runtime errors are not logged
+    let mut runtime = build_solidity_with_options(
+        r#"contract RuntimeErrors {
+        function math_overflow(int8 num) public pure returns (int8) {
+            int8 ovf = num + 120;
+            return ovf;
+        }
+
+        function require_test(int8 num) public pure returns (int8) {
+            require(num > 10, "sesa");
+            return 0;
+        }
+    }"#,
+        false,
+    );
+
+    runtime.function_expect_failure("math_overflow", 10u8.encode());
+    assert!(!runtime.debug_buffer().contains("runtime_error"));
+    assert_eq!(runtime.output(), PanicData::from(MathOverflow).encode());
+
+    runtime.function_expect_failure("require_test", 9u8.encode());
+    assert!(!runtime.debug_buffer().contains("runtime_error"));
+    assert_eq!(
+        runtime.output(),
+        ErrorData::from("sesa".to_string()).encode()
+    );
+}
+
 #[test]
 fn require_without_message() {
     let mut runtime = build_solidity(
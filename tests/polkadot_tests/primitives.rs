@@ -431,3 +431,47 @@ fn mul() {
         (10000000000u64, 0u64, 0u64, 0u64).encode()
     );
 }
+
+#[test]
+fn user_defined_value_type() {
+    let mut runtime = build_solidity(
+        r#"
+        type Amount is uint64;
+
+        function add(Amount a, Amount b) pure returns (Amount) {
+            return Amount.wrap(Amount.unwrap(a) + Amount.unwrap(b));
+        }
+
+        using {add as +} for Amount global;
+
+        contract c {
+            Amount total;
+
+            function deposit(Amount a) public returns (uint64) {
+                total = total + a;
+                return Amount.unwrap(total);
+            }
+
+            function get() public view returns (Amount) {
+                return total;
+            }
+
+            function round_trip(uint64 v) public pure returns (uint64) {
+                return Amount.unwrap(Amount.wrap(v));
+            }
+        }"#,
+    );
+
+    // user defined value types are encoded like their underlying type
+    runtime.function("deposit", 5u64.encode());
+    assert_eq!(runtime.output(), 5u64.encode());
+
+    runtime.function("deposit", 7u64.encode());
+    assert_eq!(runtime.output(), 12u64.encode());
+
+    runtime.function("get", Vec::new());
+    assert_eq!(runtime.output(), 12u64.encode());
+
+    runtime.function("round_trip", 0xdead_cafe_u64.encode());
+    assert_eq!(runtime.output(), 0xdead_cafe_u64.encode());
+}
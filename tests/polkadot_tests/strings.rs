@@ -172,6 +172,21 @@ fn string_concat() {
     );
 
     runtime.function("test", Vec::new());
+
+    let mut runtime = build_solidity(
+        r#"
+        contract foo {
+            function test(bytes b, string s) public {
+                assert(bytes.concat("a", "b") == hex"6162");
+                assert(string.concat("a", "b") == "ab");
+
+                assert(bytes.concat(b, "cd", b) == hex"fe6364fe");
+                assert(string.concat("ab", s, "cd") == "ab-cd");
+            }
+        }"#,
+    );
+
+    runtime.function("test", (vec![0xfeu8], String::from("-")).encode());
 }
 
 #[test]
@@ -29,6 +29,7 @@ static NO_OPTIMIZATIONS: Lazy<Options> = Lazy::new(|| Options {
     strength_reduce: false,
     vector_to_slice: false,
     common_subexpression_elimination: false,
+    unused_variable_elimination: false,
     ..Default::default()
 });
 
@@ -1,7 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{build_solidity, BorshToken};
+use crate::{build_solidity, BorshToken, VirtualMachineBuilder};
 use num_bigint::BigInt;
+use solang::codegen::{OptimizationLevel, Options};
 
 #[test]
 fn runtime_errors() {
@@ -327,3 +328,63 @@ contract calle_contract {
     );
     assert!(vm.return_data.is_none());
 }
+
+#[test]
+fn runtime_errors_release() {
+    // --release disables logging runtime errors and prints
+    let mut vm = VirtualMachineBuilder::new(
+        r#"
+contract RuntimeErrors {
+    function math_overflow(int8 num) public returns (int8) {
+        print("math_overflow");
+        int8 ovf = num + 120;
+        return ovf;
+    }
+
+    function require_test(int256 num) public returns (int8) {
+        require(num > 10, "sesa");
+        return 0;
+    }
+
+    function i_will_revert() public {
+        revert();
+    }
+}"#,
+    )
+    .opts(Options {
+        opt_level: OptimizationLevel::Default,
+        log_runtime_errors: false,
+        log_prints: false,
+        ..Default::default()
+    })
+    .build();
+
+    vm.set_program(0);
+    let data_account = vm.initialize_data_account();
+    vm.function("new")
+        .accounts(vec![("dataAccount", data_account)])
+        .call();
+    vm.logs.clear();
+
+    vm.function("math_overflow")
+        .arguments(&[BorshToken::Int {
+            width: 8,
+            value: BigInt::from(10u8),
+        }])
+        .must_fail();
+
+    assert_eq!(vm.logs, "");
+
+    vm.function("require_test")
+        .arguments(&[BorshToken::Int {
+            width: 256,
+            value: BigInt::from(9u8),
+        }])
+        .must_fail();
+
+    assert_eq!(vm.logs, "");
+
+    vm.function("i_will_revert").must_fail();
+
+    assert_eq!(vm.logs, "");
+}
@@ -21,10 +21,13 @@ fn parse_and_codegen(src: &'static str) -> Namespace {
         strength_reduce: false,
         vector_to_slice: false,
         common_subexpression_elimination: false,
+        unused_variable_elimination: true,
         opt_level: OptimizationLevel::Default,
         generate_debug_information: false,
         log_runtime_errors: false,
         log_prints: true,
+        max_cyclomatic_complexity: None,
+        max_call_depth: None,
         #[cfg(feature = "wasm_opt")]
         wasm_opt: None,
     };
@@ -739,3 +742,38 @@ fn try_catch() {
         "Variable read before being defined"
     );
 }
+
+#[test]
+fn modulo() {
+    let file = r#"
+    contract test {
+        function unsigned(uint x) public pure returns (uint) {
+            uint u;
+            if (x > 0) {
+                u = 2;
+            }
+            return x % u;
+        }
+
+        function signed(int x) public pure returns (int) {
+            int i;
+            if (x > 0) {
+                i = 2;
+            }
+            return i % x;
+        }
+    }
+    "#;
+    let ns = parse_and_codegen(file);
+    let errors = ns.diagnostics.errors();
+    assert!(contains_error_message_and_notes(
+        &errors,
+        "Variable 'u' is undefined",
+        1
+    ));
+    assert!(contains_error_message_and_notes(
+        &errors,
+        "Variable 'i' is undefined",
+        1
+    ));
+}
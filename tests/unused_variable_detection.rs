@@ -689,7 +689,10 @@ fn array_push_pop() {
     }
     "#;
     let ns = parse(file);
-    assert_eq!(ns.diagnostics.count_warnings(), 0);
+    assert_eq!(ns.diagnostics.count_warnings(), 1);
+    assert!(ns.diagnostics.warning_contains(
+        "contract 'Test1' has no public or external functions, so none of its code can be called"
+    ));
 
     let file = r#"
       contract Test1 {
@@ -702,7 +705,7 @@ fn array_push_pop() {
     }
     "#;
     let ns = parse(file);
-    assert_eq!(ns.diagnostics.count_warnings(), 2);
+    assert_eq!(ns.diagnostics.count_warnings(), 3);
     assert!(ns
         .diagnostics
         .warning_contains("function parameter 'arr1' is unused"));
@@ -126,6 +126,13 @@ suite('Extension Test Suite', function () {
   test('Testing for Completion', async () => {
     await testcompletion(compldoc1);
   });
+
+  // Tests for document symbols
+  this.timeout(20000);
+  const symboldoc1 = getDocUri('symbols.sol');
+  test('Testing for DocumentSymbols', async () => {
+    await testdocumentsymbols(symboldoc1);
+  });
 });
 
 function toRange(lineno1: number, charno1: number, lineno2: number, charno2: number) {
@@ -662,3 +669,39 @@ async function testdiagnos(docUri: vscode.Uri, expecteddiag: vscode.Diagnostic[]
     console.error('the diagnostics are incorrect', actualDiagnostics);
   }
 }
+
+async function testdocumentsymbols(docUri: vscode.Uri) {
+  await activate(docUri);
+
+  const symbols = (await vscode.commands.executeCommand(
+    'vscode.executeDocumentSymbolProvider',
+    docUri
+  )) as vscode.DocumentSymbol[];
+
+  const outline = (list: vscode.DocumentSymbol[]) => list.map(symbol => [symbol.name, symbol.kind]);
+
+  assert.deepStrictEqual(outline(symbols), [
+    ['helper', vscode.SymbolKind.Function],
+    ['Lib', vscode.SymbolKind.Module],
+    ['Outline', vscode.SymbolKind.Class],
+  ]);
+
+  assert.deepStrictEqual(outline(symbols[1].children), [['twice', vscode.SymbolKind.Function]]);
+
+  assert.deepStrictEqual(outline(symbols[2].children), [
+    ['Point', vscode.SymbolKind.Struct],
+    ['Moved', vscode.SymbolKind.Event],
+    ['count', vscode.SymbolKind.Field],
+    ['onlyOnce', vscode.SymbolKind.Function],
+    ['constructor', vscode.SymbolKind.Constructor],
+    ['move', vscode.SymbolKind.Function],
+  ]);
+
+  assert.deepStrictEqual(outline(symbols[2].children[0].children), [
+    ['x', vscode.SymbolKind.Field],
+    ['y', vscode.SymbolKind.Field],
+  ]);
+
+  // the selection range is the name of the symbol
+  assert.deepStrictEqual(symbols[2].selectionRange, toRange(10, 9, 10, 16));
+}
@@ -0,0 +1,30 @@
+function helper(uint64 a) pure returns (uint64) {
+    return a + 1;
+}
+
+library Lib {
+    function twice(uint64 a) internal pure returns (uint64) {
+        return a * 2;
+    }
+}
+
+contract Outline {
+    struct Point {
+        uint64 x;
+        uint64 y;
+    }
+
+    event Moved(uint64 x, uint64 y);
+
+    uint64 count;
+
+    modifier onlyOnce() {
+        _;
+    }
+
+    constructor() {}
+
+    function move(uint64 x, uint64 y) public onlyOnce {
+        emit Moved(x, y);
+    }
+}